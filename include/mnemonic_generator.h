@@ -0,0 +1,36 @@
+#ifndef MNEMONIC_GENERATOR_H
+#define MNEMONIC_GENERATOR_H
+
+/*
+ * C interface to the mnemonic-generator crate.
+ *
+ * Build with `cargo build --release --features ffi` and link against the
+ * resulting cdylib (libmnemonic_generator.so / .dylib / .dll).
+ */
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct MnemonicGenerator MnemonicGenerator;
+
+/* Creates a generator with the default word lists. Release with mnemonic_free. */
+MnemonicGenerator *mnemonic_new(void);
+
+/* Generates a mnemonic, or returns NULL on failure. Release with mnemonic_string_free. */
+char *mnemonic_generate(const MnemonicGenerator *generator);
+
+/* Generates a mnemonic joined by `separator`, or returns NULL on failure. */
+char *mnemonic_generate_with_separator(const MnemonicGenerator *generator, const char *separator);
+
+/* Releases a generator. NULL is ignored. */
+void mnemonic_free(MnemonicGenerator *generator);
+
+/* Releases a string returned by a generate function. NULL is ignored. */
+void mnemonic_string_free(char *mnemonic);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* MNEMONIC_GENERATOR_H */
@@ -0,0 +1,167 @@
+//! Procedural macros for `mnemonic-generator`.
+//!
+//! Use them through the `macros` feature of the main crate, which re-exports
+//! everything defined here.
+
+use std::collections::hash_map::RandomState;
+use std::env;
+use std::hash::{BuildHasher, Hasher};
+
+use mnemonic_generator_words as words;
+use proc_macro::{Literal, TokenStream, TokenTree};
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Expands to a string literal holding a mnemonic picked at compile time from
+/// the default word lists.
+///
+/// Without arguments a new name is picked on every build. Pass the name of an
+/// environment variable to derive the name from its value instead, so builds
+/// with the same value embed the same name. If the variable is unset, a random
+/// name is picked.
+///
+/// Cargo does not track environment variables read by procedural macros;
+/// emit `cargo:rerun-if-env-changed` from a build script if the name must
+/// follow changes to the variable.
+///
+/// # Examples
+///
+/// ```ignore
+/// use mnemonic_generator::mnemonic;
+///
+/// const BUILD_NAME: &str = mnemonic!("GIT_COMMIT");
+/// ```
+#[proc_macro]
+pub fn mnemonic(input: TokenStream) -> TokenStream {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let seed = match tokens.as_slice() {
+        [] => None,
+        [TokenTree::Literal(literal)] => match parse_str_literal(&literal.to_string()) {
+            Some(var) => env::var(var).ok(),
+            None => return compile_error("expected the name of an environment variable"),
+        },
+        _ => return compile_error("expected at most one string literal"),
+    };
+
+    let hash = match seed {
+        Some(seed) => {
+            let mut hasher = words::Fnv1aHasher::default();
+            hasher.write(seed.as_bytes());
+            hasher.finish()
+        }
+        None => RandomState::new().build_hasher().finish(),
+    };
+    let left = words::LEFT_WORDS[(hash % words::LEFT_WORDS.len() as u64) as usize];
+    let right = words::RIGHT_WORDS
+        [(hash / words::LEFT_WORDS.len() as u64 % words::RIGHT_WORDS.len() as u64) as usize];
+
+    TokenTree::Literal(Literal::string(&format!("{}_{}", left, right))).into()
+}
+
+/// Implements `mnemonic_generator::Named` for a type.
+///
+/// By default the name is derived from the value's `Hash` implementation, so
+/// equal values always share a name. Mark a `OnceLock<String>` field with
+/// `#[mnemonic_name]` to instead draw a random name the first time it is
+/// requested and keep it for the lifetime of the value.
+///
+/// # Examples
+///
+/// ```ignore
+/// use std::sync::OnceLock;
+///
+/// use mnemonic_generator::{MnemonicNamed, Named};
+///
+/// #[derive(Hash, MnemonicNamed)]
+/// struct Job {
+///     id: u64,
+/// }
+///
+/// #[derive(Default, MnemonicNamed)]
+/// struct Worker {
+///     #[mnemonic_name]
+///     name: OnceLock<String>,
+/// }
+///
+/// assert_eq!(Job { id: 7 }.name(), Job { id: 7 }.name());
+/// let worker = Worker::default();
+/// assert_eq!(worker.name(), worker.name());
+/// ```
+#[proc_macro_derive(MnemonicNamed, attributes(mnemonic_name))]
+pub fn derive_mnemonic_named(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+
+    let marked: Vec<_> = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .filter(|field| {
+                    field
+                        .attrs
+                        .iter()
+                        .any(|attr| attr.path().is_ident("mnemonic_name"))
+                })
+                .collect(),
+            _ => Vec::new(),
+        },
+        _ => Vec::new(),
+    };
+
+    let (body, hashed) = match marked.as_slice() {
+        [] => (
+            quote! {
+                ::std::borrow::Cow::Owned(::mnemonic_generator::name_for(self))
+            },
+            true,
+        ),
+        [field] => {
+            let field = &field.ident;
+            let body = quote! {
+                ::std::borrow::Cow::Borrowed(
+                    self.#field.get_or_init(::mnemonic_generator::generate).as_str()
+                )
+            };
+            (body, false)
+        }
+        [_, extra, ..] => {
+            return syn::Error::new_spanned(extra, "only one field may be marked #[mnemonic_name]")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    if hashed {
+        input
+            .generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(Self: ::std::hash::Hash));
+    }
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::mnemonic_generator::Named for #ident #ty_generics #where_clause {
+            fn name(&self) -> ::std::borrow::Cow<'_, str> {
+                #body
+            }
+        }
+    }
+    .into()
+}
+
+/// Returns the contents of a plain string literal without escapes.
+fn parse_str_literal(literal: &str) -> Option<&str> {
+    literal
+        .strip_prefix('"')?
+        .strip_suffix('"')
+        .filter(|contents| !contents.contains('\\'))
+}
+
+/// Expands to a `compile_error!` invocation with the given message.
+fn compile_error(message: &str) -> TokenStream {
+    format!("compile_error!({:?})", message)
+        .parse()
+        .expect("compile_error! invocation is valid Rust")
+}
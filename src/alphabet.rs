@@ -0,0 +1,106 @@
+/// The characters used for random or check suffixes such as the digits of
+/// [`MnemonicGenerator::username`](crate::MnemonicGenerator::username).
+///
+/// Names that are read aloud and typed back in should avoid characters that
+/// are easily mistaken for one another, such as `0` and `O` or `1`, `l`, and
+/// `I`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SuffixAlphabet {
+    /// The digits `0` to `9`.
+    Digits,
+    /// The digits `2` to `9`, without `0` and `1`.
+    UnambiguousDigits,
+    /// Crockford's base32: digits and lowercase letters without `i`, `l`,
+    /// `o`, and `u`.
+    Crockford,
+    /// Crockford's base32 without `0` and `1`, so that no character resembles
+    /// a letter.
+    UnambiguousCrockford,
+}
+
+impl SuffixAlphabet {
+    /// Returns the characters of the alphabet in ascending order.
+    pub fn chars(self) -> &'static str {
+        match self {
+            Self::Digits => "0123456789",
+            Self::UnambiguousDigits => "23456789",
+            Self::Crockford => "0123456789abcdefghjkmnpqrstvwxyz",
+            Self::UnambiguousCrockford => "23456789abcdefghjkmnpqrstvwxyz",
+        }
+    }
+
+    /// Returns the character at `idx`, which must be less than the size of the
+    /// alphabet.
+    pub(crate) fn char_at(self, idx: usize) -> &'static str {
+        &self.chars()[idx..=idx]
+    }
+
+    /// Returns the number of characters in the alphabet.
+    pub(crate) fn len(self) -> usize {
+        self.chars().len()
+    }
+
+    /// Returns the index of `c` in the alphabet, ignoring case.
+    ///
+    /// As Crockford's base32 allows, [`SuffixAlphabet::Crockford`] reads `i`
+    /// and `l` as `1` and `o` as `0`, so characters copied by hand still
+    /// decode.
+    pub(crate) fn position(self, c: char) -> Option<usize> {
+        let c = match (self, c.to_ascii_lowercase()) {
+            (Self::Crockford, 'i' | 'l') => '1',
+            (Self::Crockford, 'o') => '0',
+            (_, c) => c,
+        };
+        self.chars().find(c)
+    }
+
+    /// Returns the name used for the alphabet in saved state.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Digits => "digits",
+            Self::UnambiguousDigits => "unambiguous-digits",
+            Self::Crockford => "crockford",
+            Self::UnambiguousCrockford => "unambiguous-crockford",
+        }
+    }
+
+    /// Returns the alphabet with the given [`SuffixAlphabet::name`].
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        [
+            Self::Digits,
+            Self::UnambiguousDigits,
+            Self::Crockford,
+            Self::UnambiguousCrockford,
+        ]
+        .into_iter()
+        .find(|alphabet| alphabet.name() == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unambiguous_alphabets_avoid_confusables() {
+        for alphabet in [
+            SuffixAlphabet::UnambiguousDigits,
+            SuffixAlphabet::UnambiguousCrockford,
+        ] {
+            assert!(!alphabet.chars().contains(['0', '1', 'i', 'l', 'o']));
+        }
+        assert_eq!(SuffixAlphabet::Crockford.len(), 32);
+        assert_eq!(SuffixAlphabet::Crockford.char_at(31), "z");
+    }
+
+    #[test]
+    fn crockford_reads_look_alike_letters_as_digits() {
+        let crockford = SuffixAlphabet::Crockford;
+        assert_eq!(crockford.position('I'), Some(1));
+        assert_eq!(crockford.position('l'), Some(1));
+        assert_eq!(crockford.position('O'), Some(0));
+        assert_eq!(crockford.position('K'), crockford.position('k'));
+        assert_eq!(SuffixAlphabet::UnambiguousCrockford.position('o'), None);
+        assert_eq!(SuffixAlphabet::from_name("crockford"), Some(crockford));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{MnemonicError, MnemonicGenerator};
+
+/// How [`MnemonicGenerator::try_with_ascii_words`] treats words containing
+/// characters outside ASCII.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AsciiPolicy {
+    /// Fail with `MnemonicError::NonAsciiWord`.
+    Reject,
+    /// Replace accented Latin letters with their base letters (`é` becomes
+    /// `e`, `ß` becomes `ss`), failing with `MnemonicError::Untransliterable`
+    /// for any other character.
+    Transliterate,
+}
+
+impl MnemonicGenerator {
+    /// Creates a `MnemonicGenerator` whose words are guaranteed to be ASCII,
+    /// for target systems that only accept ASCII identifiers.
+    ///
+    /// Words are converted according to `policy` and then validated as by
+    /// [`MnemonicGenerator::try_with_words`], so words that become equal
+    /// through transliteration are deduplicated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::NonAsciiWord` under [`AsciiPolicy::Reject`] or
+    /// `MnemonicError::Untransliterable` under [`AsciiPolicy::Transliterate`]
+    /// for the first word that cannot be used, or the errors of
+    /// [`MnemonicGenerator::try_with_words`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::{AsciiPolicy, MnemonicError, MnemonicGenerator};
+    ///
+    /// let generator = MnemonicGenerator::try_with_ascii_words(
+    ///     ["fröhlich"],
+    ///     ["Gödel"],
+    ///     AsciiPolicy::Transliterate,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(generator.generate().unwrap(), "frohlich_Godel");
+    ///
+    /// let result = MnemonicGenerator::try_with_ascii_words(["fröhlich"], ["godel"], AsciiPolicy::Reject);
+    /// assert!(matches!(result, Err(MnemonicError::NonAsciiWord(_))));
+    /// ```
+    pub fn try_with_ascii_words<L, R>(
+        left_words: L,
+        right_words: R,
+        policy: AsciiPolicy,
+    ) -> Result<Self, MnemonicError>
+    where
+        L: IntoIterator,
+        L::Item: Into<String>,
+        R: IntoIterator,
+        R::Item: Into<String>,
+    {
+        let convert = |word: String| to_ascii(word, policy);
+        let left = left_words
+            .into_iter()
+            .map(|word| convert(word.into()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let right = right_words
+            .into_iter()
+            .map(|word| convert(word.into()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::try_with_words(left, right)
+    }
+}
+
+/// Converts a word to ASCII according to `policy`.
+fn to_ascii(word: String, policy: AsciiPolicy) -> Result<String, MnemonicError> {
+    if word.is_ascii() {
+        return Ok(word);
+    }
+    if policy == AsciiPolicy::Reject {
+        return Err(MnemonicError::NonAsciiWord(word));
+    }
+
+    let mut ascii = String::with_capacity(word.len());
+    for c in word.chars() {
+        if c.is_ascii() {
+            ascii.push(c);
+            continue;
+        }
+        let lower = c.to_lowercase().next().unwrap_or(c);
+        let Some(replacement) = transliterate(lower) else {
+            return Err(MnemonicError::Untransliterable { word, character: c });
+        };
+        if c.is_uppercase() {
+            let mut chars = replacement.chars();
+            ascii.extend(chars.next().map(|first| first.to_ascii_uppercase()));
+            ascii.push_str(chars.as_str());
+        } else {
+            ascii.push_str(replacement);
+        }
+    }
+    Ok(ascii)
+}
+
+/// Returns the ASCII spelling of a lowercase Latin letter with diacritics.
+fn transliterate(c: char) -> Option<&'static str> {
+    Some(match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ș' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' | 'ț' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transliterates_and_deduplicates() {
+        let generator = MnemonicGenerator::try_with_ascii_words(
+            ["Straße", "strasse", "Ærøskøbing"],
+            ["Erdős"],
+            AsciiPolicy::Transliterate,
+        )
+        .unwrap();
+        let left: Vec<&str> = generator.left_words().collect();
+        assert_eq!(left, ["Strasse", "Aeroskobing"]);
+        assert_eq!(generator.right_words().next(), Some("Erdos"));
+    }
+
+    #[test]
+    fn reports_characters_without_transliteration() {
+        let result = MnemonicGenerator::try_with_ascii_words(
+            ["brave"],
+            ["湯川"],
+            AsciiPolicy::Transliterate,
+        );
+        assert!(matches!(
+            result,
+            Err(MnemonicError::Untransliterable {
+                character: '湯',
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+use std::borrow::Cow;
+use std::io;
+
+use crate::distance::levenshtein;
+use crate::{MnemonicError, MnemonicGenerator, MAX_FILTER_ATTEMPTS};
+
+/// The output format of [`MnemonicGenerator::write_batch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BatchFormat {
+    /// One name per line.
+    Plain,
+    /// One JSON object per line, as in `{"name":"hopeful_turing"}`.
+    JsonLines,
+    /// A CSV file with a single `name` column and a header row.
+    Csv,
+}
+
+impl MnemonicGenerator {
+    /// Generates `n` mnemonics using the default separator.
+    ///
+    /// Names are drawn independently, so the batch may contain duplicates. Use
+    /// [`MnemonicGenerator::generate_batch_with_min_distance`] to keep names
+    /// apart.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate`]
+    pub fn generate_batch(&self, n: usize) -> Result<Vec<String>, MnemonicError> {
+        self.generate_batch_with_min_distance(n, 0)
+    }
+
+    /// Generates `n` mnemonics that differ pairwise by an edit distance of at
+    /// least `min_distance` characters.
+    ///
+    /// Keeps names that are easy to confuse at a glance, like `bold_bohr` and
+    /// `bold_bose`, out of the same batch. A `min_distance` of 1 yields distinct
+    /// names and 0 disables the check. Each name is re-rolled until it is far
+    /// enough from every name already in the batch, so cost grows
+    /// quadratically with `n`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::AllCandidatesRejected` if no candidate far enough
+    /// from the rest of the batch is found within a bounded number of attempts,
+    /// or any error of [`MnemonicGenerator::generate`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::new();
+    /// let names = generator.generate_batch_with_min_distance(20, 3).unwrap();
+    /// assert_eq!(names.len(), 20);
+    /// # }
+    /// ```
+    pub fn generate_batch_with_min_distance(
+        &self,
+        n: usize,
+        min_distance: usize,
+    ) -> Result<Vec<String>, MnemonicError> {
+        let separator = self.default_separator();
+        let mut rng = crate::rng::default_rng();
+        let mut batch: Vec<String> = Vec::with_capacity(n);
+
+        while batch.len() < n {
+            let mut attempts = 0;
+            let name = loop {
+                let pick = self.choose(&mut rng, separator)?;
+                let name = self.render(pick, separator);
+                if batch
+                    .iter()
+                    .all(|other| levenshtein(&name, other) >= min_distance)
+                {
+                    break name;
+                }
+                self.observe(|observer| observer.on_retry());
+                attempts += 1;
+                if attempts == MAX_FILTER_ATTEMPTS {
+                    self.observe(|observer| observer.on_exhausted(attempts));
+                    return Err(MnemonicError::AllCandidatesRejected);
+                }
+            };
+            self.observe(|observer| observer.on_generated(&name));
+            batch.push(name);
+        }
+
+        Ok(batch)
+    }
+
+    /// Writes `n` mnemonics using the default separator to `writer`, one per
+    /// line in the given format.
+    ///
+    /// Names are drawn independently like [`MnemonicGenerator::generate_batch`]
+    /// but written as they are generated, so memory use does not grow with
+    /// `n`. Wrap unbuffered writers such as files in a `BufWriter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::Io` if writing fails, or the same errors as
+    /// [`MnemonicGenerator::generate`]. Names written before the error are
+    /// left in `writer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::{BatchFormat, MnemonicGenerator};
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"]);
+    /// let mut out = Vec::new();
+    /// generator.write_batch(2, &mut out, BatchFormat::Csv).unwrap();
+    /// assert_eq!(out, b"name\nhopeful_turing\nhopeful_turing\n");
+    /// ```
+    pub fn write_batch(
+        &self,
+        n: usize,
+        writer: &mut impl io::Write,
+        format: BatchFormat,
+    ) -> Result<(), MnemonicError> {
+        let separator = self.default_separator();
+        let mut rng = crate::rng::default_rng();
+        if format == BatchFormat::Csv {
+            writer.write_all(b"name\n")?;
+        }
+
+        for _ in 0..n {
+            let pick = self.choose(&mut rng, separator)?;
+            let name = self.render(pick, separator);
+            self.observe(|observer| observer.on_generated(&name));
+            match format {
+                BatchFormat::Plain => writeln!(writer, "{}", name)?,
+                BatchFormat::JsonLines => {
+                    writeln!(writer, "{{\"name\":\"{}\"}}", json_escape(&name))?
+                }
+                BatchFormat::Csv => writeln!(writer, "{}", csv_field(&name))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Escapes a string for use inside a JSON string literal.
+fn json_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Quotes a CSV field if it contains a delimiter, quote, or line break.
+fn csv_field(s: &str) -> Cow<'_, str> {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\"")).into()
+    } else {
+        s.into()
+    }
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use crate::distance::levenshtein;
+    use crate::{BatchFormat, MnemonicError, MnemonicGenerator};
+
+    #[test]
+    fn batch_names_keep_minimum_distance() {
+        let names = MnemonicGenerator::new()
+            .generate_batch_with_min_distance(50, 4)
+            .unwrap();
+        for (i, a) in names.iter().enumerate() {
+            for b in &names[i + 1..] {
+                assert!(levenshtein(a, b) >= 4, "{} and {} are too close", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn writes_each_format() {
+        let generator = MnemonicGenerator::with_words(["say \"hi\","], ["turing"]);
+        let write = |format| {
+            let mut out = Vec::new();
+            generator.write_batch(2, &mut out, format).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert_eq!(write(BatchFormat::Plain), "say \"hi\",_turing\n".repeat(2));
+        assert_eq!(
+            write(BatchFormat::JsonLines),
+            "{\"name\":\"say \\\"hi\\\",_turing\"}\n".repeat(2)
+        );
+        assert_eq!(
+            write(BatchFormat::Csv),
+            "name\n".to_string() + &"\"say \"\"hi\"\",_turing\"\n".repeat(2)
+        );
+    }
+
+    #[test]
+    fn fails_when_batch_cannot_be_spread() {
+        let generator = MnemonicGenerator::with_words(["bold"], ["bohr", "bose"]);
+        assert!(matches!(
+            generator.generate_batch_with_min_distance(2, 3),
+            Err(MnemonicError::AllCandidatesRejected)
+        ));
+        assert_eq!(generator.generate_batch(5).unwrap().len(), 5);
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+
+use crate::word_list::WordList;
+use crate::{MnemonicError, MnemonicGenerator};
+
+/// Word frequencies, duplicates, and lengths of a batch of names, computed by
+/// [`MnemonicGenerator::batch_stats`].
+///
+/// Meant for auditing whether filters, profiles, or selection strategies skew
+/// the distribution of generated names: with uniform selection and no
+/// filters, every word is expected to appear about equally often.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::MnemonicGenerator;
+///
+/// let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "bohr"]);
+/// let stats = generator
+///     .batch_stats(["hopeful_turing", "brave_turing", "hopeful_turing"])
+///     .unwrap();
+/// assert_eq!(stats.len(), 3);
+/// assert_eq!(stats.right_counts()["turing"], 3);
+/// assert_eq!(stats.right_counts()["bohr"], 0);
+/// assert_eq!(stats.duplicates()[&("hopeful".to_string(), "turing".to_string())], 2);
+/// assert_eq!(stats.length_histogram()[&14], 2);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchStats {
+    len: usize,
+    left_counts: BTreeMap<String, usize>,
+    right_counts: BTreeMap<String, usize>,
+    pairs: BTreeMap<(String, String), usize>,
+    length_histogram: BTreeMap<usize, usize>,
+}
+
+impl BatchStats {
+    /// Returns the number of names in the batch.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the batch was empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns how often each left-hand word appears, including words that
+    /// never do.
+    pub fn left_counts(&self) -> &BTreeMap<String, usize> {
+        &self.left_counts
+    }
+
+    /// Returns how often each right-hand word appears, including words that
+    /// never do.
+    pub fn right_counts(&self) -> &BTreeMap<String, usize> {
+        &self.right_counts
+    }
+
+    /// Returns the pairs of words that appear more than once, with the number
+    /// of names using them.
+    ///
+    /// Names differing only in their prefix, suffix, or timestamp share a pair.
+    pub fn duplicates(&self) -> BTreeMap<(String, String), usize> {
+        self.pairs
+            .iter()
+            .filter(|(_, &count)| count > 1)
+            .map(|(pair, &count)| (pair.clone(), count))
+            .collect()
+    }
+
+    /// Returns the number of names of each length in characters.
+    pub fn length_histogram(&self) -> &BTreeMap<usize, usize> {
+        &self.length_histogram
+    }
+}
+
+impl MnemonicGenerator {
+    /// Computes word frequencies, duplicate pairs, and a length histogram of
+    /// a batch of names generated by this generator.
+    ///
+    /// Each name is parsed with [`MnemonicGenerator::parse`], so the batch may
+    /// come from any of the generating methods that use the default
+    /// separator, or from storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::parse`] for the first
+    /// name that does not parse
+    pub fn batch_stats<I>(&self, names: I) -> Result<BatchStats, MnemonicError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let zeros = |words: &WordList| words.iter().map(|word| (word.to_string(), 0)).collect();
+        let mut stats = BatchStats {
+            left_counts: zeros(&self.words.left),
+            right_counts: zeros(&self.words.right),
+            ..BatchStats::default()
+        };
+
+        for name in names {
+            let mnemonic = self.parse(name.as_ref())?;
+            let (left, right) = (mnemonic.left(), mnemonic.right());
+            stats.len += 1;
+            *stats.left_counts.entry(left.to_string()).or_default() += 1;
+            *stats.right_counts.entry(right.to_string()).or_default() += 1;
+            *stats
+                .pairs
+                .entry((left.to_string(), right.to_string()))
+                .or_default() += 1;
+            *stats
+                .length_histogram
+                .entry(mnemonic.as_str().chars().count())
+                .or_default() += 1;
+        }
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_a_generated_batch() {
+        let generator = MnemonicGenerator::with_words(["a", "b"], ["x", "y", "z"]);
+        let names = generator.generate_batch(600).unwrap();
+        let stats = generator.batch_stats(&names).unwrap();
+        assert_eq!(stats.len(), 600);
+        assert_eq!(stats.left_counts().values().sum::<usize>(), 600);
+        assert!(stats.right_counts().values().all(|&count| count > 100));
+        assert_eq!(stats.duplicates().len(), 6);
+        assert_eq!(stats.length_histogram().len(), 1);
+        assert_eq!(stats.length_histogram()[&3], 600);
+    }
+
+    #[test]
+    fn rejects_foreign_names() {
+        let generator = MnemonicGenerator::with_words(["a"], ["x"]);
+        assert!(generator
+            .batch_stats(Vec::<String>::new())
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            generator.batch_stats(["a_x", "a_nobody"]),
+            Err(MnemonicError::InvalidMnemonic { position: 2, .. })
+        ));
+    }
+}
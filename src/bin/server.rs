@@ -0,0 +1,243 @@
+//! HTTP naming service, enabled by the `server` feature.
+//!
+//! Every name handed out is unique for the lifetime of the process.
+//!
+//! - `GET /name` returns one name as `{"name": "..."}`
+//! - `POST /names?count=N` returns up to 1000 names as a JSON array, fewer
+//!   if the namespace runs out partway
+//! - `GET /validate?name=...` returns `{"valid": bool, "reason": ..., "suggestions": [...]}`
+//!
+//! Configured through environment variables:
+//!
+//! - `MNEMONIC_ADDR`: address to listen on, `127.0.0.1:8080` by default
+//! - `MNEMONIC_PROFILE`: one of `kubernetes`, `hostname`, `s3`, or `github`
+
+use std::env;
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use mnemonic_generator::{DrainingGenerator, MnemonicError, MnemonicGenerator, NamingProfile};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+/// The largest number of names a single `POST /names` request may ask for.
+const MAX_COUNT: usize = 1000;
+
+/// The number of suggestions returned for an invalid name.
+const SUGGESTIONS: usize = 3;
+
+struct AppState {
+    /// Hands out each name once. Held only while names are drawn.
+    names: Mutex<DrainingGenerator>,
+    /// A copy of the generator for validation, which needs no lock.
+    generator: MnemonicGenerator,
+    profile: Option<NamingProfile>,
+}
+
+type Shared = Arc<AppState>;
+
+type Response<T> = Result<Json<T>, (StatusCode, String)>;
+
+#[derive(Serialize)]
+struct NameResponse {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct CountQuery {
+    count: Option<usize>,
+}
+
+#[derive(Deserialize)]
+struct ValidateQuery {
+    name: String,
+}
+
+#[derive(Serialize)]
+struct ValidateResponse {
+    valid: bool,
+    reason: Option<String>,
+    suggestions: Vec<String>,
+}
+
+#[tokio::main]
+async fn main() {
+    let profile = match env::var("MNEMONIC_PROFILE").ok().as_deref() {
+        None => None,
+        Some("kubernetes") => Some(NamingProfile::kubernetes_label()),
+        Some("hostname") => Some(NamingProfile::rfc1123_hostname()),
+        Some("s3") => Some(NamingProfile::s3_bucket()),
+        Some("github") => Some(NamingProfile::github_repo()),
+        Some(other) => {
+            eprintln!("unknown MNEMONIC_PROFILE {:?}", other);
+            std::process::exit(2);
+        }
+    };
+    let generator = match &profile {
+        Some(profile) => MnemonicGenerator::new()
+            .with_profile(profile.clone())
+            .expect("built-in profiles accept their own separator"),
+        None => MnemonicGenerator::new(),
+    };
+    let app = app(generator, profile);
+
+    let addr = env::var("MNEMONIC_ADDR").unwrap_or_else(|_| "127.0.0.1:8080".to_string());
+    let listener = tokio::net::TcpListener::bind(&addr)
+        .await
+        .unwrap_or_else(|error| panic!("failed to bind {}: {}", addr, error));
+    axum::serve(listener, app).await.expect("server failed");
+}
+
+fn app(generator: MnemonicGenerator, profile: Option<NamingProfile>) -> Router {
+    let state = Arc::new(AppState {
+        names: Mutex::new(DrainingGenerator::new(generator.clone())),
+        generator,
+        profile,
+    });
+
+    Router::new()
+        .route("/name", get(name))
+        .route("/names", post(names))
+        .route("/validate", get(validate))
+        .with_state(state)
+}
+
+async fn name(State(state): State<Shared>) -> Response<NameResponse> {
+    let name = state
+        .names
+        .lock()
+        .await
+        .generate()
+        .map_err(error_response)?;
+    Ok(Json(NameResponse { name }))
+}
+
+async fn names(
+    State(state): State<Shared>,
+    Query(query): Query<CountQuery>,
+) -> Response<Vec<String>> {
+    let count = query.count.unwrap_or(1);
+    if count > MAX_COUNT {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("count must be at most {}", MAX_COUNT),
+        ));
+    }
+
+    // Names drawn before a failure are already marked as issued, so they are
+    // returned rather than lost.
+    let mut generator = state.names.lock().await;
+    let mut names = Vec::with_capacity(count);
+    for _ in 0..count {
+        match generator.generate() {
+            Ok(name) => names.push(name),
+            Err(error) if names.is_empty() => return Err(error_response(error)),
+            Err(_) => break,
+        }
+    }
+    Ok(Json(names))
+}
+
+async fn validate(
+    State(state): State<Shared>,
+    Query(query): Query<ValidateQuery>,
+) -> Json<ValidateResponse> {
+    let generator = &state.generator;
+
+    let reason = match generator.parse(&query.name) {
+        Err(error) => Some(error.to_string()),
+        Ok(_) => state
+            .profile
+            .as_ref()
+            .and_then(|profile| profile.validate(&query.name).err())
+            .map(|violation| violation.to_string()),
+    };
+    let suggestions = match reason {
+        Some(_) => generator
+            .suggest(&query.name, SUGGESTIONS)
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        None => Vec::new(),
+    };
+
+    Json(ValidateResponse {
+        valid: reason.is_none(),
+        reason,
+        suggestions,
+    })
+}
+
+fn error_response(error: MnemonicError) -> (StatusCode, String) {
+    let status = match error {
+        MnemonicError::NamespaceExhausted { .. } => StatusCode::SERVICE_UNAVAILABLE,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    (status, error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::{to_bytes, Body};
+    use axum::http::{Method, Request};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    async fn send(app: &Router, method: Method, uri: &str) -> (StatusCode, String) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn batches_stop_at_the_end_of_the_namespace() {
+        let app = app(
+            MnemonicGenerator::with_words(["happy"], ["turing", "hopper"]),
+            None,
+        );
+
+        let (status, body) = send(&app, Method::POST, "/names?count=3").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(
+            body == r#"["happy_turing","happy_hopper"]"#
+                || body == r#"["happy_hopper","happy_turing"]"#
+        );
+
+        let (status, _) = send(&app, Method::GET, "/name").await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        let (status, _) = send(&app, Method::POST, "/names?count=1001").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn validates_names_with_suggestions() {
+        let app = app(
+            MnemonicGenerator::with_words(["happy"], ["turing", "hopper"]),
+            None,
+        );
+
+        let (status, body) = send(&app, Method::GET, "/name").await;
+        assert_eq!(status, StatusCode::OK);
+        let name = body
+            .strip_prefix(r#"{"name":""#)
+            .and_then(|rest| rest.strip_suffix(r#""}"#))
+            .unwrap();
+        let (_, body) = send(&app, Method::GET, &format!("/validate?name={}", name)).await;
+        assert_eq!(body, r#"{"valid":true,"reason":null,"suggestions":[]}"#);
+
+        let (status, body) = send(&app, Method::GET, "/validate?name=happy_turnig").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.starts_with(r#"{"valid":false,"#), "{}", body);
+        assert!(body.contains("happy_turing"), "{}", body);
+    }
+}
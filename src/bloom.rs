@@ -0,0 +1,158 @@
+use std::collections::hash_map::DefaultHasher;
+use std::f64::consts::LN_2;
+use std::hash::{Hash, Hasher};
+
+use crate::{MnemonicError, MnemonicGenerator, NameRegistry};
+
+/// A fixed-size probabilistic set of issued names.
+///
+/// The filter never reports an issued name as free, but may report a small
+/// fraction of never-issued names as taken. Memory use depends only on the
+/// expected number of names and the target false-positive rate, not on the
+/// names themselves.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{BloomFilter, MnemonicGenerator};
+///
+/// let mut issued = BloomFilter::new(1_000_000, 0.001);
+/// let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "hopper"]);
+/// let name = generator
+///     .generate_unique_bloom(&mut issued, 100)
+///     .expect("Failed to generate mnemonic");
+/// assert!(issued.contains(&name));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    num_bits: u64,
+    num_hashes: u32,
+}
+
+impl BloomFilter {
+    /// Creates a filter sized for `expected_items` names at the given
+    /// false-positive rate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `false_positive_rate` is not strictly between 0 and 1.
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "false positive rate must be between 0 and 1"
+        );
+
+        let items = expected_items.max(1) as f64;
+        let num_bits = (-items * false_positive_rate.ln() / (LN_2 * LN_2)).ceil() as u64;
+        let num_bits = num_bits.max(64);
+        let num_hashes = ((num_bits as f64 / items) * LN_2).round().max(1.0) as u32;
+
+        Self {
+            bits: vec![0; num_bits.div_ceil(64) as usize],
+            num_bits,
+            num_hashes,
+        }
+    }
+
+    /// Records a name as issued.
+    pub fn insert(&mut self, name: &str) {
+        for bit in self.bit_positions(name) {
+            self.bits[(bit / 64) as usize] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Returns `true` if the name may have been issued, `false` if it definitely was not.
+    pub fn contains(&self, name: &str) -> bool {
+        self.bit_positions(name)
+            .all(|bit| self.bits[(bit / 64) as usize] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Returns the size of the filter in bits.
+    pub fn num_bits(&self) -> u64 {
+        self.num_bits
+    }
+
+    /// Returns the number of hash functions applied per name.
+    pub fn num_hashes(&self) -> u32 {
+        self.num_hashes
+    }
+
+    /// Derives the bit positions for a name using double hashing.
+    fn bit_positions(&self, name: &str) -> impl Iterator<Item = u64> {
+        let h1 = hash_with_seed(name, 0);
+        let h2 = hash_with_seed(name, 1) | 1;
+        let num_bits = self.num_bits;
+        (0..u64::from(self.num_hashes)).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % num_bits)
+    }
+}
+
+impl NameRegistry for BloomFilter {
+    fn is_taken(&self, name: &str) -> bool {
+        self.contains(name)
+    }
+}
+
+impl MnemonicGenerator {
+    /// Generates a name the filter has not seen, and records it in the filter.
+    ///
+    /// Because the filter is probabilistic, a small fraction of free names are
+    /// skipped as if taken, but an issued name is never returned twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate_available`]
+    pub fn generate_unique_bloom(
+        &self,
+        filter: &mut BloomFilter,
+        max_retries: usize,
+    ) -> Result<String, MnemonicError> {
+        let name = self.generate_available(filter, max_retries)?;
+        filter.insert(&name);
+        Ok(name)
+    }
+}
+
+/// Hashes a name with a seed to obtain independent hash values.
+fn hash_with_seed(name: &str, seed: u64) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    seed.hash(&mut hasher);
+    name.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn never_forgets_inserted_names() {
+        let mut filter = BloomFilter::new(1_000, 0.01);
+        let names: Vec<String> = (0..1_000).map(|i| format!("name_{}", i)).collect();
+        for name in &names {
+            filter.insert(name);
+        }
+        assert!(names.iter().all(|name| filter.contains(name)));
+    }
+
+    #[test]
+    fn false_positive_rate_is_close_to_target() {
+        let mut filter = BloomFilter::new(10_000, 0.01);
+        for i in 0..10_000 {
+            filter.insert(&format!("issued_{}", i));
+        }
+        let false_positives = (0..10_000)
+            .filter(|i| filter.contains(&format!("fresh_{}", i)))
+            .count();
+        assert!(false_positives < 300, "{} false positives", false_positives);
+    }
+
+    #[test]
+    fn generates_distinct_names() {
+        let mut filter = BloomFilter::new(100, 0.001);
+        let generator = MnemonicGenerator::with_words(["amazing", "legend"], ["jordan"]);
+        let first = generator.generate_unique_bloom(&mut filter, 100).unwrap();
+        let second = generator.generate_unique_bloom(&mut filter, 100).unwrap();
+        assert_ne!(first, second);
+    }
+}
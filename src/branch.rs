@@ -0,0 +1,160 @@
+use crate::{MnemonicError, MnemonicGenerator, MAX_FILTER_ATTEMPTS};
+
+impl MnemonicGenerator {
+    /// Generates a git branch name such as `feat/hopeful-turing`, with the
+    /// words joined by `-` under the branch type `kind`.
+    ///
+    /// If `ticket` is given it is placed before the words, as in
+    /// `fix/PROJ-123-hopeful-turing`, and an empty `kind` omits the type.
+    /// Every name is checked against the rules of
+    /// `git check-ref-format --branch`, so custom words that would produce
+    /// `..`, a trailing dot, or a `.lock` suffix are re-rolled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidConfig` if `kind` or `ticket` can never
+    /// form a valid branch name, `MnemonicError::AllCandidatesRejected` if no
+    /// valid name was found, or the same errors as
+    /// [`MnemonicGenerator::generate`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"]);
+    /// assert_eq!(generator.generate_branch("feat", None).unwrap(), "feat/hopeful-turing");
+    /// assert_eq!(
+    ///     generator.generate_branch("fix", Some("PROJ-123")).unwrap(),
+    ///     "fix/PROJ-123-hopeful-turing"
+    /// );
+    /// ```
+    pub fn generate_branch(
+        &self,
+        kind: &str,
+        ticket: Option<&str>,
+    ) -> Result<String, MnemonicError> {
+        let mut prefix = String::new();
+        if !kind.is_empty() {
+            check_ref_name(kind).map_err(|reason| {
+                MnemonicError::InvalidConfig(format!("branch type {:?} {}", kind, reason))
+            })?;
+            prefix.push_str(kind);
+            prefix.push('/');
+        }
+        if let Some(ticket) = ticket {
+            if ticket.contains('/') {
+                return Err(MnemonicError::InvalidConfig(format!(
+                    "ticket {:?} contains `/`",
+                    ticket
+                )));
+            }
+            check_ref_name(ticket).map_err(|reason| {
+                MnemonicError::InvalidConfig(format!("ticket {:?} {}", ticket, reason))
+            })?;
+            prefix.push_str(ticket);
+            prefix.push('-');
+        }
+
+        let mut rng = crate::rng::default_rng();
+        for _ in 0..MAX_FILTER_ATTEMPTS {
+            let pick = self.choose(&mut rng, "-")?;
+            let branch = prefix.clone() + &self.render(pick, "-");
+            if check_ref_name(&branch).is_ok() {
+                self.observe(|observer| observer.on_generated(&branch));
+                return Ok(branch);
+            }
+            trace_event!(
+                trace,
+                left_idx = pick.left,
+                right_idx = pick.right,
+                "not a valid branch name, retrying"
+            );
+            self.observe(|observer| observer.on_retry());
+        }
+
+        self.observe(|observer| observer.on_exhausted(MAX_FILTER_ATTEMPTS));
+        Err(MnemonicError::AllCandidatesRejected)
+    }
+}
+
+/// Checks `name` against the rules git applies to branch names, describing
+/// the first rule it breaks.
+fn check_ref_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("is empty".to_string());
+    }
+    if name == "@" {
+        return Err("is `@`".to_string());
+    }
+    if name.starts_with('-') {
+        return Err("begins with `-`".to_string());
+    }
+    if name.starts_with('/') || name.ends_with('/') {
+        return Err("begins or ends with `/`".to_string());
+    }
+    if name.ends_with('.') {
+        return Err("ends with `.`".to_string());
+    }
+    for sequence in ["..", "//", "@{"] {
+        if name.contains(sequence) {
+            return Err(format!("contains `{}`", sequence));
+        }
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|&c| c.is_ascii_control() || " ~^:?*[\\".contains(c))
+    {
+        return Err(format!("contains {:?}", c));
+    }
+    if name
+        .split('/')
+        .any(|component| component.starts_with('.') || component.ends_with(".lock"))
+    {
+        return Err("has a component beginning with `.` or ending with `.lock`".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_invalid_kinds_and_tickets() {
+        let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"]);
+        for (kind, ticket) in [
+            ("feat..x", None),
+            ("feat.", None),
+            ("-feat", None),
+            ("feat", Some("a/b")),
+            ("feat", Some("PROJ 1")),
+        ] {
+            assert!(matches!(
+                generator.generate_branch(kind, ticket),
+                Err(MnemonicError::InvalidConfig(_))
+            ));
+        }
+        assert_eq!(
+            generator.generate_branch("", None).unwrap(),
+            "hopeful-turing"
+        );
+    }
+
+    #[test]
+    fn rerolls_words_that_break_ref_rules() {
+        let generator = MnemonicGenerator::with_words(["hopeful", "odd.."], ["turing", "x.lock"]);
+        for _ in 0..50 {
+            assert_eq!(
+                generator.generate_branch("feat", None).unwrap(),
+                "feat/hopeful-turing"
+            );
+        }
+
+        let generator = MnemonicGenerator::with_words(["odd.."], ["x.lock"]);
+        assert!(matches!(
+            generator.generate_branch("feat", None),
+            Err(MnemonicError::AllCandidatesRejected)
+        ));
+    }
+}
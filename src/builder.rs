@@ -0,0 +1,190 @@
+use crate::word_list::{WordList, WordLists};
+use crate::{MnemonicError, MnemonicGenerator, WordSide};
+
+/// Marks a word list that has not been provided to a
+/// [`MnemonicGeneratorBuilder`] yet.
+pub struct Missing;
+
+/// Marks a non-empty word list provided to a [`MnemonicGeneratorBuilder`].
+pub struct Provided(WordList);
+
+/// A builder that only offers `build()` once both word lists are provided.
+///
+/// Lists given as arrays are checked for emptiness at compile time; lists
+/// only known at run time go through the fallible `try_*_words` methods. The
+/// built generator therefore never fails with `MnemonicError::EmptyList`.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::MnemonicGenerator;
+///
+/// let generator = MnemonicGenerator::builder()
+///     .left_words(["amazing", "legend"])
+///     .right_words(["jordan", "larry"])
+///     .build();
+/// assert!(generator.generate().is_ok());
+/// ```
+///
+/// Forgetting a list, or passing an empty array, does not compile:
+///
+/// ```compile_fail
+/// use mnemonic_generator::MnemonicGenerator;
+///
+/// let generator = MnemonicGenerator::builder().left_words(["amazing"]).build();
+/// ```
+pub struct MnemonicGeneratorBuilder<L = Missing, R = Missing> {
+    left: L,
+    right: R,
+}
+
+impl MnemonicGenerator {
+    /// Starts a [`MnemonicGeneratorBuilder`] with neither list provided.
+    pub fn builder() -> MnemonicGeneratorBuilder {
+        MnemonicGeneratorBuilder {
+            left: Missing,
+            right: Missing,
+        }
+    }
+}
+
+impl<R> MnemonicGeneratorBuilder<Missing, R> {
+    /// Provides the left-hand words.
+    ///
+    /// An empty array is rejected at compile time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a word is empty or contains whitespace.
+    pub fn left_words<S: Into<String>, const N: usize>(
+        self,
+        words: [S; N],
+    ) -> MnemonicGeneratorBuilder<Provided, R> {
+        const { assert!(N > 0, "the left word list must not be empty") };
+        self.try_left_words(words)
+            .expect("left words must not be empty or contain whitespace")
+    }
+
+    /// Provides the left-hand words, validated as by
+    /// [`MnemonicGenerator::try_with_words`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::EmptyList` if no words are given, or
+    /// `MnemonicError::EmptyWord` or `MnemonicError::WhitespaceInWord` for
+    /// the first invalid word found
+    pub fn try_left_words<I>(
+        self,
+        words: I,
+    ) -> Result<MnemonicGeneratorBuilder<Provided, R>, MnemonicError>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Ok(MnemonicGeneratorBuilder {
+            left: provided(WordSide::Left, words)?,
+            right: self.right,
+        })
+    }
+}
+
+impl<L> MnemonicGeneratorBuilder<L, Missing> {
+    /// Provides the right-hand words.
+    ///
+    /// An empty array is rejected at compile time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a word is empty or contains whitespace.
+    pub fn right_words<S: Into<String>, const N: usize>(
+        self,
+        words: [S; N],
+    ) -> MnemonicGeneratorBuilder<L, Provided> {
+        const { assert!(N > 0, "the right word list must not be empty") };
+        self.try_right_words(words)
+            .expect("right words must not be empty or contain whitespace")
+    }
+
+    /// Provides the right-hand words, validated as by
+    /// [`MnemonicGenerator::try_with_words`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::EmptyList` if no words are given, or
+    /// `MnemonicError::EmptyWord` or `MnemonicError::WhitespaceInWord` for
+    /// the first invalid word found
+    pub fn try_right_words<I>(
+        self,
+        words: I,
+    ) -> Result<MnemonicGeneratorBuilder<L, Provided>, MnemonicError>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Ok(MnemonicGeneratorBuilder {
+            left: self.left,
+            right: provided(WordSide::Right, words)?,
+        })
+    }
+}
+
+#[cfg(feature = "default-words")]
+impl MnemonicGeneratorBuilder<Missing, Missing> {
+    /// Selects the built-in word lists for both sides.
+    ///
+    /// Requires the `default-words` feature.
+    pub fn default_words(self) -> MnemonicGeneratorBuilder<Provided, Provided> {
+        let lists = WordLists::defaults();
+        MnemonicGeneratorBuilder {
+            left: Provided(lists.left.clone()),
+            right: Provided(lists.right.clone()),
+        }
+    }
+}
+
+impl MnemonicGeneratorBuilder<Provided, Provided> {
+    /// Builds the generator, which can be configured further with the
+    /// `with_*` methods.
+    pub fn build(self) -> MnemonicGenerator {
+        MnemonicGenerator::from_lists(WordLists::new(self.left.0, self.right.0))
+    }
+}
+
+/// Validates one side's words, rejecting an empty list.
+fn provided<I>(side: WordSide, words: I) -> Result<Provided, MnemonicError>
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+{
+    let list = WordList::validated(words)?;
+    if list.is_empty() {
+        return Err(MnemonicError::EmptyList { list: side.into() });
+    }
+    Ok(Provided(list))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ListName;
+
+    #[test]
+    fn builds_from_both_lists_in_any_order() {
+        let generator = MnemonicGenerator::builder()
+            .right_words(["turing"])
+            .left_words(["happy"])
+            .build();
+        assert_eq!(generator.generate().unwrap(), "happy_turing");
+    }
+
+    #[test]
+    fn rejects_empty_runtime_lists() {
+        let result = MnemonicGenerator::builder().try_right_words(Vec::<String>::new());
+        assert!(matches!(
+            result,
+            Err(MnemonicError::EmptyList {
+                list: ListName::Side(WordSide::Right)
+            })
+        ));
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{MnemonicError, MnemonicGenerator, WordSide};
+
+/// The number of words needed to give every byte value its own word.
+pub(crate) const BYTE_VALUES: usize = 256;
+
+impl MnemonicGenerator {
+    /// Returns the word for `byte`, normalized to the profile's case.
+    ///
+    /// Byte values index into the left-hand list followed by the right-hand
+    /// list, so only the first 256 words take part. Callers must check the
+    /// lists with [`MnemonicGenerator::check_byte_words`] first.
+    pub(crate) fn byte_word(&self, byte: u8) -> Result<String, MnemonicError> {
+        let idx = usize::from(byte);
+        let left_len = self.words.left.len();
+        if idx < left_len {
+            return Ok(self.normalize(self.words.left.get(idx)));
+        }
+
+        let word = self.normalize(self.words.right.get(idx - left_len));
+        if self.find_word(WordSide::Left, &word).is_some() {
+            return Err(MnemonicError::InvalidConfig(format!(
+                "word {:?} appears in both lists and cannot encode a byte",
+                word
+            )));
+        }
+        Ok(word)
+    }
+
+    /// Returns the byte encoded by a word written by
+    /// [`MnemonicGenerator::byte_word`], or `None` for any other word.
+    pub(crate) fn word_byte(&self, word: &str) -> Option<u8> {
+        let left_len = self.words.left.len();
+        self.find_word(WordSide::Left, word)
+            .map(|(idx, _)| idx)
+            .or_else(|| {
+                self.find_word(WordSide::Right, word)
+                    .map(|(idx, _)| left_len + idx)
+            })
+            .filter(|&idx| idx < BYTE_VALUES)
+            .map(|idx| idx as u8)
+    }
+
+    /// Checks that the lists hold a word for every byte value.
+    pub(crate) fn check_byte_words(&self) -> Result<(), MnemonicError> {
+        let available = self.words.left.len() + self.words.right.len();
+        if available < BYTE_VALUES {
+            return Err(MnemonicError::InsufficientWords {
+                needed: BYTE_VALUES,
+                available,
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+
+use crate::word_list::WordList;
+#[cfg(feature = "default-words")]
+use crate::words;
+use crate::{sample, ListName, MnemonicError, MnemonicGenerator};
+
+/// A generator over any number of named word categories, combined in a
+/// configured order.
+///
+/// Where [`MnemonicGenerator`] always joins a left and a right word, a
+/// `CategoryGenerator` joins one word from each category named in its pattern,
+/// so names with three or more slots need no workarounds.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use mnemonic_generator::MnemonicGenerator;
+///
+/// let categories = HashMap::from([
+///     ("adjective".to_string(), vec!["happy".to_string()]),
+///     ("color".to_string(), vec!["crimson".to_string()]),
+///     ("scientist".to_string(), vec!["turing".to_string()]),
+/// ]);
+/// let generator =
+///     MnemonicGenerator::with_categories(categories, ["adjective", "color", "scientist"]).unwrap();
+/// assert_eq!(generator.generate().unwrap(), "happy_crimson_turing");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CategoryGenerator {
+    categories: HashMap<String, WordList>,
+    pattern: Vec<String>,
+}
+
+impl CategoryGenerator {
+    /// Creates a generator from named categories and the order in which to use them.
+    ///
+    /// A category may appear in the pattern more than once.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidPattern` if the pattern is empty,
+    /// `MnemonicError::UnknownCategory` if it names a category that is not in
+    /// the map, and `MnemonicError::EmptyList` if a category used by the
+    /// pattern has no words
+    pub fn new<P, S>(
+        categories: HashMap<String, Vec<String>>,
+        pattern: P,
+    ) -> Result<Self, MnemonicError>
+    where
+        P: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let categories = categories
+            .into_iter()
+            .map(|(name, words)| (name, WordList::Owned(words)))
+            .collect();
+        Self::from_parts(categories, pattern.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates a generator with the built-in `adjective` and `scientist`
+    /// categories, in that order.
+    #[cfg(feature = "default-words")]
+    pub fn defaults() -> Self {
+        let categories = HashMap::from([
+            ("adjective".to_string(), WordList::Static(words::LEFT_WORDS)),
+            (
+                "scientist".to_string(),
+                WordList::Static(words::RIGHT_WORDS),
+            ),
+        ]);
+        Self {
+            categories,
+            pattern: vec!["adjective".to_string(), "scientist".to_string()],
+        }
+    }
+
+    /// Validates the pattern against the categories.
+    fn from_parts(
+        categories: HashMap<String, WordList>,
+        pattern: Vec<String>,
+    ) -> Result<Self, MnemonicError> {
+        if pattern.is_empty() {
+            return Err(MnemonicError::InvalidPattern(
+                "pattern has no categories".to_string(),
+            ));
+        }
+        for name in &pattern {
+            match categories.get(name) {
+                None => return Err(MnemonicError::UnknownCategory(name.clone())),
+                Some(words) if words.is_empty() => {
+                    return Err(MnemonicError::EmptyList {
+                        list: ListName::Category(name.clone()),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(Self {
+            categories,
+            pattern,
+        })
+    }
+
+    /// Returns the category names in the order they appear in generated names.
+    pub fn pattern(&self) -> &[String] {
+        &self.pattern
+    }
+
+    /// Returns the words of a category, or `None` if it does not exist.
+    pub fn category(&self, name: &str) -> Option<Vec<&str>> {
+        self.categories
+            .get(name)
+            .map(|words| words.iter().collect())
+    }
+
+    /// Generates a name joined by underscores.
+    ///
+    /// # Errors
+    ///
+    /// Never fails for a generator built through [`CategoryGenerator::new`];
+    /// the `Result` mirrors [`MnemonicGenerator::generate`]
+    pub fn generate(&self) -> Result<String, MnemonicError> {
+        self.generate_with_separator("_")
+    }
+
+    /// Generates a name joined by a custom separator.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`CategoryGenerator::generate`]
+    pub fn generate_with_separator(&self, separator: &str) -> Result<String, MnemonicError> {
+        Ok(self.generate_parts()?.join(separator))
+    }
+
+    /// Generates one word per slot of the pattern without joining them.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`CategoryGenerator::generate`]
+    pub fn generate_parts(&self) -> Result<Vec<&str>, MnemonicError> {
+        let mut rng = crate::rng::default_rng();
+        self.pattern
+            .iter()
+            .map(|name| {
+                let words = &self.categories[name];
+                if words.is_empty() {
+                    return Err(MnemonicError::EmptyList {
+                        list: ListName::Category(name.clone()),
+                    });
+                }
+                Ok(words.get(sample::index(&mut rng, words.len())))
+            })
+            .collect()
+    }
+}
+
+impl MnemonicGenerator {
+    /// Creates a [`CategoryGenerator`] from named categories and an ordered
+    /// pattern of category names.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`CategoryGenerator::new`]
+    pub fn with_categories<P, S>(
+        categories: HashMap<String, Vec<String>>,
+        pattern: P,
+    ) -> Result<CategoryGenerator, MnemonicError>
+    where
+        P: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        CategoryGenerator::new(categories, pattern)
+    }
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use super::*;
+
+    fn categories() -> HashMap<String, Vec<String>> {
+        HashMap::from([
+            ("adjective".to_string(), vec!["happy".to_string()]),
+            ("scientist".to_string(), vec!["turing".to_string()]),
+        ])
+    }
+
+    #[test]
+    fn follows_pattern_order() {
+        let generator =
+            CategoryGenerator::new(categories(), ["scientist", "adjective", "scientist"]).unwrap();
+        assert_eq!(
+            generator.generate_with_separator("-").unwrap(),
+            "turing-happy-turing"
+        );
+    }
+
+    #[test]
+    fn validates_pattern() {
+        assert!(matches!(
+            CategoryGenerator::new(categories(), ["adjective", "color"]),
+            Err(MnemonicError::UnknownCategory(name)) if name == "color"
+        ));
+        assert!(matches!(
+            CategoryGenerator::new(categories(), Vec::<String>::new()),
+            Err(MnemonicError::InvalidPattern(_))
+        ));
+
+        let mut empty = categories();
+        empty.insert("color".to_string(), Vec::new());
+        let error = CategoryGenerator::new(empty, ["color"]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The \"color\" category word list is empty"
+        );
+    }
+
+    #[test]
+    fn defaults_match_default_generator() {
+        let generator = CategoryGenerator::defaults();
+        let parts = generator.generate_parts().unwrap();
+        assert!(words::LEFT_WORDS.contains(&parts[0]));
+        assert!(words::RIGHT_WORDS.contains(&parts[1]));
+    }
+}
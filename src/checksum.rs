@@ -0,0 +1,207 @@
+use crate::{MnemonicError, MnemonicGenerator, SuffixAlphabet};
+
+/// A checksum appended to every generated name so that transcription errors
+/// can be detected with [`MnemonicGenerator::verify`].
+///
+/// The checksum is derived from the positions of both words in their lists and
+/// joined to the name with the separator, before any suffix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Checksum {
+    /// A word from the right-hand list, as in `hopeful_turing_curie`.
+    Word,
+    /// A single character of Crockford's base32, as in `hopeful_turing_k`.
+    Character,
+    /// A single character of the given alphabet, such as
+    /// [`SuffixAlphabet::UnambiguousCrockford`] for names that are read aloud.
+    /// `CharacterIn(SuffixAlphabet::Crockford)` is the same as `Character`.
+    CharacterIn(SuffixAlphabet),
+}
+
+impl Checksum {
+    /// Returns the alphabet of check characters, or `None` for check words.
+    pub(crate) fn alphabet(self) -> Option<SuffixAlphabet> {
+        match self {
+            Self::Word => None,
+            Self::Character => Some(SuffixAlphabet::Crockford),
+            Self::CharacterIn(alphabet) => Some(alphabet),
+        }
+    }
+}
+
+impl MnemonicGenerator {
+    /// Appends a checksum to every generated name.
+    ///
+    /// The checksum, with its separator, is counted by the length window and
+    /// checked by the filters and the naming profile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::{Checksum, MnemonicGenerator};
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "hopper"])
+    ///     .with_checksum(Checksum::Character);
+    /// let name = generator.generate().unwrap();
+    /// assert_eq!(name.split('_').count(), 3);
+    /// assert!(generator.verify(&name).is_ok());
+    /// ```
+    pub fn with_checksum(mut self, checksum: Checksum) -> Self {
+        self.checksum = Some(checksum);
+        self
+    }
+
+    /// Checks that a name parses and carries the correct checksum.
+    ///
+    /// Substituting one word for another word of the same list changes the
+    /// expected checksum, so most typos that still produce known words are
+    /// caught. Check characters are read ignoring case and, for Crockford's
+    /// base32, with `i` and `l` read as `1` and `o` as `0`, like
+    /// [`MnemonicGenerator::decode_short_code`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::ChecksumMismatch` if the checksum does not match
+    /// the words, or any error of [`MnemonicGenerator::parse`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::{Checksum, MnemonicError, MnemonicGenerator};
+    ///
+    /// let generator = MnemonicGenerator::with_words(["bold"], ["bohr", "bose"])
+    ///     .with_checksum(Checksum::Character);
+    /// assert!(generator.verify("bold_bohr_f").is_ok());
+    /// assert!(generator.verify("bold_bohr_F").is_ok());
+    /// assert!(matches!(
+    ///     generator.verify("bold_bose_f"),
+    ///     Err(MnemonicError::ChecksumMismatch(_))
+    /// ));
+    /// ```
+    pub fn verify(&self, name: &str) -> Result<(), MnemonicError> {
+        self.parse(name).map(drop)
+    }
+
+    /// Returns the checksum for the pair, if checksums are enabled.
+    pub(crate) fn checksum_part(&self, left_idx: usize, right_idx: usize) -> Option<&str> {
+        let hash = mix((left_idx as u64) << 32 | right_idx as u64);
+        match self.checksum?.alphabet() {
+            None => Some(
+                self.words
+                    .right
+                    .get((hash % self.words.right.len() as u64) as usize),
+            ),
+            Some(alphabet) => {
+                let idx = (hash % alphabet.len() as u64) as usize;
+                Some(alphabet.char_at(idx))
+            }
+        }
+    }
+
+    /// Returns `true` if `checksum`, as read from a name, belongs to the pair.
+    pub(crate) fn checksum_matches(
+        &self,
+        left_idx: usize,
+        right_idx: usize,
+        checksum: &str,
+    ) -> bool {
+        let Some(expected) = self.checksum_part(left_idx, right_idx) else {
+            return false;
+        };
+        match self.checksum.and_then(Checksum::alphabet) {
+            Some(alphabet) => {
+                let mut chars = checksum.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => alphabet.position(c) == alphabet.chars().find(expected),
+                    _ => false,
+                }
+            }
+            None => self.normalize(expected) == checksum,
+        }
+    }
+
+    /// Returns the number of characters the checksum adds to the name of the
+    /// pair, including its separator.
+    pub(crate) fn checksum_len(&self, left_idx: usize, right_idx: usize, separator: &str) -> usize {
+        self.checksum_part(left_idx, right_idx)
+            .map_or(0, |check| separator.chars().count() + check.chars().count())
+    }
+}
+
+/// Scrambles the bits of `x` (SplitMix64 finalizer).
+///
+/// The checksum must never change between releases, so this is spelled out
+/// instead of relying on the standard library's unspecified hasher.
+pub(crate) fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_substituted_words() {
+        let generator = MnemonicGenerator::new().with_checksum(Checksum::Character);
+        let check = generator.checksum_part(10, 20).unwrap().to_string();
+        let name = format!(
+            "{}_{}_{}",
+            crate::words::LEFT_WORDS[10],
+            crate::words::RIGHT_WORDS[20],
+            check
+        );
+        assert!(generator.verify(&name).is_ok());
+
+        let collisions = (0..crate::words::RIGHT_WORDS.len())
+            .filter(|&right_idx| right_idx != 20)
+            .filter(|&right_idx| generator.checksum_part(10, right_idx) == Some(check.as_str()))
+            .count();
+        assert!(collisions < crate::words::RIGHT_WORDS.len() / 8);
+    }
+
+    #[test]
+    fn length_window_counts_checksum_words() {
+        let generator = MnemonicGenerator::new().with_checksum(Checksum::Word);
+        let names = generator.generate_shuffled_all(0);
+        let max_len = 17;
+        let fitting = names
+            .iter()
+            .filter(|name| name.chars().count() <= max_len)
+            .count();
+
+        let generator = generator.with_length_range(1..=max_len);
+        assert_eq!(generator.combination_count(), fitting as u128);
+        for _ in 0..100 {
+            assert!(generator.generate().unwrap().chars().count() <= max_len);
+        }
+    }
+
+    #[test]
+    fn checksum_is_stable() {
+        let generator = MnemonicGenerator::new().with_checksum(Checksum::Character);
+        assert_eq!(mix(0), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(generator.checksum_part(0, 0), Some("f"));
+    }
+
+    #[test]
+    fn check_characters_use_the_configured_alphabet() {
+        let alphabet = SuffixAlphabet::UnambiguousCrockford;
+        let generator = MnemonicGenerator::new().with_checksum(Checksum::CharacterIn(alphabet));
+        for _ in 0..50 {
+            let name = generator.generate().unwrap();
+            let (_, check) = name.rsplit_once('_').unwrap();
+            assert!(alphabet.chars().contains(check), "{}", name);
+        }
+
+        let generator = MnemonicGenerator::with_words(["bold"], (0..64).map(|n| format!("w{}", n)))
+            .with_checksum(Checksum::Character);
+        let right_idx = (0..64)
+            .find(|&right_idx| generator.checksum_part(0, right_idx) == Some("1"))
+            .unwrap();
+        let name = format!("bold_w{}_l", right_idx);
+        assert!(generator.verify(&name).is_ok());
+        assert!(generator.verify(&name.replace("_l", "_I")).is_ok());
+    }
+}
@@ -0,0 +1,110 @@
+//! Command-line argument parsing, enabled by the `clap` feature.
+
+use std::ffi::OsStr;
+use std::sync::Arc;
+
+use clap::builder::TypedValueParser;
+use clap::error::ErrorKind;
+use clap::{Arg, Command};
+
+use crate::{Mnemonic, MnemonicGenerator};
+
+/// A clap value parser that accepts only names the wrapped generator can
+/// produce.
+///
+/// `Mnemonic` arguments use a parser for the default generator automatically.
+/// Construct one explicitly to validate against a customized generator.
+///
+/// # Examples
+///
+/// ```
+/// use clap::{Arg, Command};
+/// use mnemonic_generator::{Mnemonic, MnemonicGenerator, MnemonicValueParser};
+///
+/// let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "hopper"])
+///     .with_prefix("prod-")
+///     .into_shared();
+/// let command = Command::new("deploy")
+///     .arg(Arg::new("name").value_parser(MnemonicValueParser::new(generator)));
+///
+/// let matches = command.clone().try_get_matches_from(["deploy", "prod-hopeful_turing"]).unwrap();
+/// assert_eq!(matches.get_one::<Mnemonic>("name").unwrap().right(), "turing");
+///
+/// assert!(command.try_get_matches_from(["deploy", "hopeful_turing"]).is_err());
+/// ```
+#[derive(Clone)]
+pub struct MnemonicValueParser {
+    generator: Arc<MnemonicGenerator>,
+}
+
+impl MnemonicValueParser {
+    /// Creates a parser validating names against the given generator.
+    pub fn new(generator: Arc<MnemonicGenerator>) -> Self {
+        Self { generator }
+    }
+}
+
+#[cfg(feature = "default-words")]
+impl Default for MnemonicValueParser {
+    /// Creates a parser for the process-wide default generator.
+    fn default() -> Self {
+        Self::new(MnemonicGenerator::shared())
+    }
+}
+
+impl TypedValueParser for MnemonicValueParser {
+    type Value = Mnemonic;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        let value = value
+            .to_str()
+            .ok_or_else(|| clap::Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        self.generator.parse(value).map_err(|error| {
+            let arg = arg.map_or_else(|| "...".to_string(), |arg| arg.to_string());
+            cmd.clone().error(
+                ErrorKind::InvalidValue,
+                format!("invalid value '{}' for '{}': {}", value, arg, error),
+            )
+        })
+    }
+}
+
+#[cfg(feature = "default-words")]
+impl clap::builder::ValueParserFactory for Mnemonic {
+    type Parser = MnemonicValueParser;
+
+    fn value_parser() -> Self::Parser {
+        MnemonicValueParser::default()
+    }
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_invalid_names() {
+        let command = Command::new("deploy")
+            .arg(Arg::new("name").value_parser(clap::value_parser!(Mnemonic)));
+
+        let matches = command
+            .clone()
+            .try_get_matches_from(["deploy", "hopeful_turing"])
+            .unwrap();
+        assert_eq!(
+            matches.get_one::<Mnemonic>("name").unwrap().as_str(),
+            "hopeful_turing"
+        );
+
+        let error = command
+            .try_get_matches_from(["deploy", "hopeful_nobody"])
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidValue);
+        assert!(error.to_string().contains("unknown right word \"nobody\""));
+    }
+}
@@ -0,0 +1,116 @@
+use crate::mnemonic::offset_of;
+use crate::{MnemonicError, MnemonicGenerator};
+
+impl MnemonicGenerator {
+    /// Encodes a 24-bit color as three words, one per channel, joined by the
+    /// default separator.
+    ///
+    /// Channel values index into the left-hand list followed by the
+    /// right-hand list, so with the default lists dark channels read as
+    /// adjectives and bright ones as scientists. Only the first 256 words take
+    /// part. Words are normalized to the profile's case.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InsufficientWords` if the lists hold fewer than
+    /// 256 words together, or `MnemonicError::InvalidConfig` if a right-hand
+    /// word used by the color also appears in the left-hand list, which would
+    /// make the name ambiguous
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::new();
+    /// let name = generator.encode_color(0xff, 0x80, 0x00).unwrap();
+    /// assert_eq!(name.split('_').count(), 3);
+    /// assert_eq!(generator.decode_color(&name).unwrap(), (0xff, 0x80, 0x00));
+    /// # }
+    /// ```
+    pub fn encode_color(&self, r: u8, g: u8, b: u8) -> Result<String, MnemonicError> {
+        self.check_byte_words()?;
+        let words = [r, g, b]
+            .into_iter()
+            .map(|channel| self.byte_word(channel))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(words.join(&self.normalize(self.default_separator())))
+    }
+
+    /// Decodes a color encoded by [`MnemonicGenerator::encode_color`] into its
+    /// red, green, and blue channels.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidMnemonic` if the name is not three known
+    /// words, or `MnemonicError::InsufficientWords` if the lists hold fewer
+    /// than 256 words together
+    pub fn decode_color(&self, name: &str) -> Result<(u8, u8, u8), MnemonicError> {
+        let invalid = |part: &str, reason: String| MnemonicError::InvalidMnemonic {
+            name: name.to_string(),
+            position: offset_of(name, part),
+            reason,
+        };
+        self.check_byte_words()?;
+
+        let parts: Vec<&str> = name
+            .split(self.normalize(self.default_separator()).as_str())
+            .collect();
+        if parts.len() != 3 {
+            return Err(invalid(
+                name,
+                format!("{} words encode no color", parts.len()),
+            ));
+        }
+
+        let mut channels = [0; 3];
+        for (channel, part) in channels.iter_mut().zip(parts) {
+            *channel = self
+                .word_byte(part)
+                .ok_or_else(|| invalid(part, format!("unknown word {:?}", part)))?;
+        }
+        Ok((channels[0], channels[1], channels[2]))
+    }
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_channel_value() {
+        let generator = MnemonicGenerator::new().with_separator("-").unwrap();
+        for value in 0..=255 {
+            let name = generator.encode_color(value, 255 - value, 7).unwrap();
+            assert_eq!(
+                generator.decode_color(&name).unwrap(),
+                (value, 255 - value, 7)
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_and_unused_words() {
+        let generator = MnemonicGenerator::new();
+        let unused = generator.right_words().last().unwrap();
+        for name in [
+            "happy_turing".to_string(),
+            "happy_turing_xyzzy".to_string(),
+            format!("happy_turing_{}", unused),
+        ] {
+            assert!(matches!(
+                generator.decode_color(&name),
+                Err(MnemonicError::InvalidMnemonic { .. })
+            ));
+        }
+        assert!(matches!(
+            MnemonicGenerator::with_words(["a"], ["b"]).encode_color(0, 0, 0),
+            Err(MnemonicError::InsufficientWords {
+                needed: 256,
+                available: 2
+            })
+        ));
+    }
+}
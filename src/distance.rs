@@ -0,0 +1,83 @@
+use crate::Mnemonic;
+
+/// Estimates how easily two mnemonics are confused, from `0.0` for entirely
+/// different words to `1.0` for the same words.
+///
+/// Averages a word-level score, the share of sides whose words are the same,
+/// with a character-level score, one minus the edit distance between the
+/// words of each side relative to the longer word. Only the words are compared,
+/// ignoring case, so prefixes, suffixes, and separators do not count. Useful
+/// for warning when two issued names are close enough to be mixed up.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "default-words")]
+/// # {
+/// use mnemonic_generator::{similarity, MnemonicGenerator};
+///
+/// let generator = MnemonicGenerator::new();
+/// let name = generator.parse("bold_bohr").unwrap();
+/// let close = generator.parse("bold_bose").unwrap();
+/// let distinct = generator.parse("hopeful_turing").unwrap();
+/// assert_eq!(similarity(&name, &name), 1.0);
+/// assert!(similarity(&name, &close) > 0.5);
+/// assert!(similarity(&name, &distinct) < 0.2);
+/// # }
+/// ```
+pub fn similarity(a: &Mnemonic, b: &Mnemonic) -> f32 {
+    let sides = [(a.left(), b.left()), (a.right(), b.right())];
+    let (mut words, mut chars) = (0.0, 0.0);
+    for (a, b) in sides {
+        let (a, b) = (a.to_lowercase(), b.to_lowercase());
+        if a == b {
+            words += 1.0;
+        }
+        let longest = a.chars().count().max(b.chars().count()).max(1);
+        chars += 1.0 - levenshtein(&a, &b) as f32 / longest as f32;
+    }
+    (words + chars) / (2.0 * sides.len() as f32)
+}
+
+/// Returns the Levenshtein distance between two strings, counted in characters.
+pub(crate) fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_edit_distance() {
+        assert_eq!(levenshtein("bold_bohr", "bold_bose"), 2);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("müller", "muller"), 1);
+    }
+
+    #[test]
+    fn similarity_is_symmetric_and_ignores_case() {
+        let a = Mnemonic::new("eu-bold_bohr".to_string(), "Bold", "bohr");
+        let b = Mnemonic::new("bold-bose".to_string(), "bold", "bose");
+        assert_eq!(similarity(&a, &b), 0.625);
+        assert_eq!(similarity(&b, &a), similarity(&a, &b));
+        assert_eq!(
+            similarity(&a, &Mnemonic::new("x".to_string(), "bold", "BOHR")),
+            1.0
+        );
+    }
+}
@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+use rand::RngCore;
+
+use crate::{sample, MnemonicError, MnemonicGenerator};
+
+/// A stateful generator that never returns the same name twice.
+///
+/// Every issued combination of words is removed from the pool, tracked with
+/// one bit per combination, so names are globally unique for the lifetime of
+/// the object without any external storage. Names are drawn uniformly from the
+/// combinations that have not been issued yet.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{DrainingGenerator, MnemonicGenerator};
+///
+/// let generator = MnemonicGenerator::with_words(["happy", "brave"], ["turing", "hopper"]);
+/// let mut draining = DrainingGenerator::new(generator);
+///
+/// let mut names: Vec<String> = (0..4).map(|_| draining.generate().unwrap()).collect();
+/// names.sort();
+/// names.dedup();
+/// assert_eq!(names.len(), 4);
+/// assert!(draining.generate().is_err());
+/// ```
+pub struct DrainingGenerator {
+    generator: MnemonicGenerator,
+    issued: Vec<u64>,
+    issued_count: u128,
+    total: u128,
+    pool: RankPool,
+}
+
+/// The ranks of all combinations in a random order that is drawn lazily, one rank at
+/// a time, by a Fisher-Yates shuffle that only stores the moved entries.
+#[derive(Debug, Clone, Default)]
+struct RankPool {
+    /// The number of combinations.
+    len: usize,
+    /// The number of ranks drawn so far, which sit at the front of the order.
+    drawn: usize,
+    /// The rank at each position that differs from the position itself.
+    moved: HashMap<usize, usize>,
+}
+
+impl RankPool {
+    fn new(len: usize) -> Self {
+        Self {
+            len,
+            ..Self::default()
+        }
+    }
+
+    /// Draws one of the ranks not drawn yet, uniformly, or returns `None` once
+    /// every rank has been drawn.
+    fn draw<G: RngCore + ?Sized>(&mut self, rng: &mut G) -> Option<usize> {
+        if self.drawn == self.len {
+            return None;
+        }
+        let position = self.drawn + sample::index(rng, self.len - self.drawn);
+        let at = |moved: &HashMap<usize, usize>, position| {
+            moved.get(&position).copied().unwrap_or(position)
+        };
+        let rank = at(&self.moved, position);
+        let front = at(&self.moved, self.drawn);
+        self.moved.insert(position, front);
+        self.moved.remove(&self.drawn);
+        self.drawn += 1;
+        Some(rank)
+    }
+}
+
+impl DrainingGenerator {
+    /// Wraps a generator with an empty record of issued names.
+    ///
+    /// The size of the namespace is computed once, which checks every name
+    /// if filters are configured; see [`MnemonicGenerator::combination_count`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of combinations of words does not fit in a
+    /// `usize`. Use [`DrainingGenerator::try_new`] for lists of unknown size.
+    pub fn new(generator: MnemonicGenerator) -> Self {
+        Self::try_new(generator).expect("the number of combinations fits in a usize")
+    }
+
+    /// Like [`DrainingGenerator::new`], but returns an error for namespaces
+    /// too large to track.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidConfig` if the number of combinations of
+    /// words does not fit in a `usize`
+    pub fn try_new(generator: MnemonicGenerator) -> Result<Self, MnemonicError> {
+        let ranks = usize::try_from(generator.pick_count()).map_err(|_| {
+            MnemonicError::InvalidConfig(format!(
+                "{} combinations of words are too many to track",
+                generator.pick_count()
+            ))
+        })?;
+        Ok(Self {
+            total: generator.combination_count(),
+            generator,
+            issued: vec![0; ranks.div_ceil(64)],
+            issued_count: 0,
+            pool: RankPool::new(ranks),
+        })
+    }
+
+    /// Generates a name that has not been issued before, using the default separator.
+    ///
+    /// Names are drawn from a lazily shuffled pool of every combination of
+    /// words, so each call takes constant time on average however much of the
+    /// pool is used up. Combinations the filters reject are dropped from the
+    /// pool as they come up.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::NamespaceExhausted` if every name the filters
+    /// accept has been issued, or `MnemonicError::EmptyList` if either
+    /// word list is empty
+    pub fn generate(&mut self) -> Result<String, MnemonicError> {
+        self.generator.check_not_empty()?;
+        let separator = self.generator.default_separator();
+        let mut rng = crate::rng::default_rng();
+
+        while self.remaining() > 0 {
+            let Some(rank) = self.pool.draw(&mut rng) else {
+                break;
+            };
+            let pick = self.generator.pick_at(rank as u64);
+            if self.is_issued(rank) || !self.generator.is_reachable(pick, separator) {
+                trace_event!(trace, rank, "unavailable, retrying");
+                self.generator.observe(|observer| observer.on_retry());
+                continue;
+            }
+
+            let name = self.generator.render(pick, separator);
+            self.generator
+                .observe(|observer| observer.on_generated(&name));
+            self.mark_issued(rank);
+            return Ok(name);
+        }
+
+        trace_event!(warn, issued = self.issued_count, "namespace exhausted");
+        Err(MnemonicError::NamespaceExhausted {
+            issued: self.issued_count,
+        })
+    }
+
+    /// Returns the number of names that can still be issued.
+    pub fn remaining(&self) -> u128 {
+        self.total.saturating_sub(self.issued_count)
+    }
+
+    /// Returns every name to the pool.
+    pub fn reset(&mut self) {
+        self.issued.fill(0);
+        self.issued_count = 0;
+        self.pool = RankPool::new(self.pool.len);
+    }
+
+    /// Returns the wrapped generator.
+    pub fn generator(&self) -> &MnemonicGenerator {
+        &self.generator
+    }
+
+    /// Writes the configuration and the record of issued names to `writer`,
+    /// so that a restarted process can resume without repeating names.
+    ///
+    /// The state follows the format of [`MnemonicGenerator::save`], of kind
+    /// `draining`, with a final `issued` line holding one bit per combination
+    /// of words as 16-digit hexadecimal fields: bit `r % 64` of field `r / 64`
+    /// is set if the name of rank `r` was issued, where ranks are as returned
+    /// by [`MnemonicGenerator::rank`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::save`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::{DrainingGenerator, MnemonicGenerator};
+    ///
+    /// let generator = MnemonicGenerator::with_words(["happy", "brave"], ["turing"]);
+    /// let mut draining = DrainingGenerator::new(generator);
+    /// let first = draining.generate().unwrap();
+    ///
+    /// let mut saved = Vec::new();
+    /// draining.save(&mut saved).unwrap();
+    /// let mut restarted = DrainingGenerator::load(saved.as_slice()).unwrap();
+    /// assert_ne!(restarted.generate().unwrap(), first);
+    /// assert!(restarted.generate().is_err());
+    /// ```
+    pub fn save(&self, writer: &mut impl Write) -> Result<(), MnemonicError> {
+        self.generator.write_state(writer, "draining")?;
+        writer.write_all(b"issued")?;
+        for bits in &self.issued {
+            write!(writer, " {:016x}", bits)?;
+        }
+        writeln!(writer)?;
+        Ok(())
+    }
+
+    /// Restores a generator written by [`DrainingGenerator::save`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidState` if the state is malformed or its
+    /// record of issued names does not match the word lists, or the same
+    /// errors as [`MnemonicGenerator::load`]
+    pub fn load(reader: impl BufRead) -> Result<Self, MnemonicError> {
+        let (generator, rest) = MnemonicGenerator::read_state(reader, "draining")?;
+        let mut draining = Self::try_new(generator)?;
+        let mut rest = rest.into_iter();
+        let directive = match rest.next() {
+            Some(directive) if directive.key() == "issued" => directive,
+            Some(directive) => return Err(directive.unknown()),
+            None => {
+                return Err(MnemonicError::InvalidState {
+                    line: 1,
+                    reason: "missing \"issued\"".to_string(),
+                })
+            }
+        };
+        if let Some(extra) = rest.next() {
+            return Err(extra.unknown());
+        }
+
+        if directive.fields().len() != draining.issued.len() {
+            return Err(directive.invalid(format!(
+                "expected {} fields for the word lists, found {}",
+                draining.issued.len(),
+                directive.fields().len()
+            )));
+        }
+        for (bits, field) in draining.issued.iter_mut().zip(directive.fields()) {
+            *bits = u64::from_str_radix(field, 16)
+                .map_err(|_| directive.invalid(format!("invalid bits {:?}", field)))?;
+        }
+        draining.issued_count = draining
+            .issued
+            .iter()
+            .map(|bits| u128::from(bits.count_ones()))
+            .sum();
+        Ok(draining)
+    }
+
+    /// Returns `true` if the name of the given rank has already been issued.
+    fn is_issued(&self, rank: usize) -> bool {
+        self.issued[rank / 64] & (1 << (rank % 64)) != 0
+    }
+
+    /// Removes the name of the given rank from the pool.
+    fn mark_issued(&mut self, rank: usize) {
+        self.issued[rank / 64] |= 1 << (rank % 64);
+        self.issued_count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn drains_every_pair_exactly_once() {
+        let generator = MnemonicGenerator::with_words(["a", "b", "c"], ["x", "y", "z", "w"]);
+        let mut draining = DrainingGenerator::new(generator);
+
+        let names: HashSet<String> = (0..12).map(|_| draining.generate().unwrap()).collect();
+        assert_eq!(names.len(), 12);
+        assert_eq!(draining.remaining(), 0);
+        assert!(matches!(
+            draining.generate(),
+            Err(MnemonicError::NamespaceExhausted { issued: 12 })
+        ));
+
+        draining.reset();
+        assert_eq!(draining.remaining(), 12);
+        assert!(draining.generate().is_ok());
+    }
+
+    #[test]
+    fn resumes_from_saved_state() {
+        let generator = MnemonicGenerator::with_words(["a", "b", "c"], ["x", "y", "z", "w"]);
+        let mut draining = DrainingGenerator::new(generator);
+        let mut names: HashSet<String> = (0..5).map(|_| draining.generate().unwrap()).collect();
+
+        let mut saved = Vec::new();
+        draining.save(&mut saved).unwrap();
+        assert!(String::from_utf8_lossy(&saved).contains("\nissued 0000000000000"));
+        let mut restarted = DrainingGenerator::load(saved.as_slice()).unwrap();
+        assert_eq!(restarted.remaining(), 7);
+        while let Ok(name) = restarted.generate() {
+            assert!(names.insert(name));
+        }
+        assert_eq!(names.len(), 12);
+
+        let mut corrupted = saved.strip_suffix(b"\n").unwrap().to_vec();
+        corrupted.extend_from_slice(b" ffff\n");
+        assert!(matches!(
+            DrainingGenerator::load(corrupted.as_slice()),
+            Err(MnemonicError::InvalidState { line: 4, .. })
+        ));
+    }
+
+    #[test]
+    fn pool_draws_every_rank_once() {
+        let mut pool = RankPool::new(1000);
+        let mut rng = crate::rng::default_rng();
+        let mut ranks: Vec<usize> = std::iter::from_fn(|| pool.draw(&mut rng)).collect();
+        assert!(pool.moved.is_empty());
+        ranks.sort_unstable();
+        assert!(ranks.into_iter().eq(0..1000));
+    }
+
+    #[test]
+    fn skips_filtered_pairs() {
+        let generator =
+            MnemonicGenerator::with_words(["a", "bb"], ["x", "yy"]).with_length_range(3..=3);
+        let mut draining = DrainingGenerator::new(generator);
+        assert_eq!(draining.remaining(), 1);
+        assert_eq!(draining.generate().unwrap(), "a_x");
+        assert_eq!(draining.remaining(), 0);
+    }
+}
@@ -0,0 +1,56 @@
+//! Generation seeded directly from `getrandom`, enabled by the `getrandom` feature.
+//!
+//! rand's thread-local generator panics if the operating system cannot supply
+//! entropy, which happens on `wasm32-unknown-unknown` without a JavaScript
+//! backend and in some edge runtimes. These entry points report the failure as
+//! `MnemonicError::Entropy` instead.
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::{MnemonicError, MnemonicGenerator};
+
+impl MnemonicGenerator {
+    /// Generates a mnemonic using the default separator, seeded with fresh
+    /// entropy from `getrandom` for this call only.
+    ///
+    /// Requires the `getrandom` feature, which the `wasm` feature enables
+    /// together with `getrandom`'s `wasm_js` backend for browsers and
+    /// JavaScript runtimes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::Entropy` if no entropy is available, or the
+    /// same errors as [`MnemonicGenerator::generate`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["happy"], ["turing"]);
+    /// assert_eq!(generator.generate_with_os_entropy().unwrap(), "happy_turing");
+    /// ```
+    pub fn generate_with_os_entropy(&self) -> Result<String, MnemonicError> {
+        self.generate_with_rng(&mut os_rng()?)
+    }
+}
+
+/// Returns a random number generator seeded with 256 bits from the operating system.
+pub(crate) fn os_rng() -> Result<StdRng, MnemonicError> {
+    let mut seed = [0; 32];
+    getrandom::fill(&mut seed)?;
+    Ok(StdRng::from_seed(seed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeds_differ_between_calls() {
+        use rand::RngCore;
+
+        assert_ne!(os_rng().unwrap().next_u64(), os_rng().unwrap().next_u64());
+    }
+}
@@ -0,0 +1,131 @@
+//! C-compatible interface, enabled by the `ffi` feature.
+//!
+//! Generators are handed out as opaque pointers and must be released with
+//! [`mnemonic_free`]. Strings returned by the generate functions are owned by
+//! the caller and must be released with [`mnemonic_string_free`]. A matching C
+//! header lives in `include/mnemonic_generator.h`. Build the shared library
+//! with `cargo rustc --lib --crate-type cdylib --features ffi`.
+
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+use crate::MnemonicGenerator;
+
+/// Creates a generator with the default word lists.
+///
+/// The returned pointer is never null and must be released with [`mnemonic_free`].
+#[no_mangle]
+pub extern "C" fn mnemonic_new() -> *mut MnemonicGenerator {
+    Box::into_raw(Box::new(MnemonicGenerator::new()))
+}
+
+/// Generates a mnemonic using the default separator.
+///
+/// Returns null if `generator` is null or generation fails.
+///
+/// # Safety
+///
+/// `generator` must be null or a pointer returned by [`mnemonic_new`] that has
+/// not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn mnemonic_generate(generator: *const MnemonicGenerator) -> *mut c_char {
+    match generator.as_ref() {
+        Some(generator) => into_c_string(generator.generate()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Generates a mnemonic joined by the given NUL-terminated separator.
+///
+/// Returns null if either pointer is null, the separator is not valid UTF-8,
+/// or generation fails.
+///
+/// # Safety
+///
+/// `generator` must be null or a live pointer returned by [`mnemonic_new`], and
+/// `separator` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn mnemonic_generate_with_separator(
+    generator: *const MnemonicGenerator,
+    separator: *const c_char,
+) -> *mut c_char {
+    let Some(generator) = generator.as_ref() else {
+        return ptr::null_mut();
+    };
+    if separator.is_null() {
+        return ptr::null_mut();
+    }
+    match CStr::from_ptr(separator).to_str() {
+        Ok(separator) => into_c_string(generator.generate_with_separator(separator)),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Releases a generator created by [`mnemonic_new`]. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `generator` must be null or a pointer returned by [`mnemonic_new`] that has
+/// not already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn mnemonic_free(generator: *mut MnemonicGenerator) {
+    if !generator.is_null() {
+        drop(Box::from_raw(generator));
+    }
+}
+
+/// Releases a string returned by one of the generate functions. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `mnemonic` must be null or a pointer returned by this library that has not
+/// already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn mnemonic_string_free(mnemonic: *mut c_char) {
+    if !mnemonic.is_null() {
+        drop(CString::from_raw(mnemonic));
+    }
+}
+
+/// Converts a generation result into an owned C string, or null on failure.
+fn into_c_string<E>(result: Result<String, E>) -> *mut c_char {
+    result
+        .ok()
+        .and_then(|mnemonic| CString::new(mnemonic).ok())
+        .map_or(ptr::null_mut(), CString::into_raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_through_c_interface() {
+        unsafe {
+            let generator = mnemonic_new();
+            let separator = CString::new("-").unwrap();
+
+            let mnemonic = mnemonic_generate_with_separator(generator, separator.as_ptr());
+            assert!(!mnemonic.is_null());
+            assert_eq!(
+                CStr::from_ptr(mnemonic)
+                    .to_str()
+                    .unwrap()
+                    .split('-')
+                    .count(),
+                2
+            );
+
+            mnemonic_string_free(mnemonic);
+            mnemonic_free(generator);
+        }
+    }
+
+    #[test]
+    fn null_generator_yields_null() {
+        unsafe {
+            assert!(mnemonic_generate(ptr::null()).is_null());
+            mnemonic_free(ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::mnemonic::offset_of;
+use crate::{MnemonicError, MnemonicGenerator};
+
+/// The number of words per space-separated chunk of a fingerprint.
+const CHUNK_WORDS: usize = 4;
+
+impl MnemonicGenerator {
+    /// Renders a key fingerprint, such as a SHA-256 or SSH key hash, as words
+    /// that can be compared by reading them aloud instead of reading hex.
+    ///
+    /// Every byte becomes one word, as in [`MnemonicGenerator::encode_color`].
+    /// Words are joined by the default separator in chunks of four, and the
+    /// chunks by spaces, so a 32-byte SHA-256 fingerprint reads as eight
+    /// chunks. Words are normalized to the profile's case.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InsufficientWords` if the lists hold fewer than
+    /// 256 words together, or `MnemonicError::InvalidConfig` if a right-hand
+    /// word used by the fingerprint also appears in the left-hand list
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::new();
+    /// let fingerprint = [0x5e, 0x1f, 0x00, 0xa7, 0x42];
+    /// let words = generator.encode_fingerprint(&fingerprint).unwrap();
+    /// assert_eq!(words.split(' ').count(), 2);
+    /// assert!(generator.verify_fingerprint(&words, &fingerprint));
+    /// # }
+    /// ```
+    pub fn encode_fingerprint(&self, fingerprint: &[u8]) -> Result<String, MnemonicError> {
+        self.check_byte_words()?;
+        let separator = self.normalize(self.default_separator());
+        let chunks = fingerprint
+            .chunks(CHUNK_WORDS)
+            .map(|chunk| {
+                let words = chunk
+                    .iter()
+                    .map(|&byte| self.byte_word(byte))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(words.join(&separator))
+            })
+            .collect::<Result<Vec<_>, MnemonicError>>()?;
+        Ok(chunks.join(" "))
+    }
+
+    /// Decodes words produced by [`MnemonicGenerator::encode_fingerprint`]
+    /// back into the fingerprint's bytes.
+    ///
+    /// Chunks may be separated by any whitespace, including line breaks.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidMnemonic` if a word does not encode a
+    /// byte, or `MnemonicError::InsufficientWords` if the lists hold fewer
+    /// than 256 words together
+    pub fn decode_fingerprint(&self, words: &str) -> Result<Vec<u8>, MnemonicError> {
+        self.check_byte_words()?;
+        let separator = self.normalize(self.default_separator());
+        words
+            .split_whitespace()
+            .flat_map(|chunk| chunk.split(separator.as_str()))
+            .map(|word| {
+                self.word_byte(word)
+                    .ok_or_else(|| MnemonicError::InvalidMnemonic {
+                        name: words.to_string(),
+                        position: offset_of(words, word),
+                        reason: format!("unknown word {:?}", word),
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns `true` if `words` decode to exactly `fingerprint`.
+    ///
+    /// Words that do not decode, for example because one was misheard, do
+    /// not match any fingerprint.
+    pub fn verify_fingerprint(&self, words: &str, fingerprint: &[u8]) -> bool {
+        self.decode_fingerprint(words)
+            .is_ok_and(|decoded| decoded == fingerprint)
+    }
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_sha256_fingerprints() {
+        let generator = MnemonicGenerator::new().with_separator("-").unwrap();
+        let fingerprint: Vec<u8> = (0..32).map(|byte| byte * 8).collect();
+        let words = generator.encode_fingerprint(&fingerprint).unwrap();
+        let chunks: Vec<&str> = words.split(' ').collect();
+        assert_eq!(chunks.len(), 8);
+        assert!(chunks.iter().all(|chunk| chunk.split('-').count() == 4));
+        assert_eq!(
+            generator
+                .decode_fingerprint(&words.replace(' ', "\n"))
+                .unwrap(),
+            fingerprint
+        );
+    }
+
+    #[test]
+    fn mismatches_are_detected() {
+        let generator = MnemonicGenerator::new();
+        let words = generator.encode_fingerprint(&[1, 2, 3]).unwrap();
+        assert!(!generator.verify_fingerprint(&words, &[1, 2, 4]));
+        assert!(!generator.verify_fingerprint(&words, &[1, 2]));
+        assert!(matches!(
+            generator.decode_fingerprint("admiring_xyzzy"),
+            Err(MnemonicError::InvalidMnemonic { position: 9, .. })
+        ));
+    }
+}
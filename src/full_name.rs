@@ -0,0 +1,133 @@
+use crate::{sample, scientist_info, MnemonicError, MnemonicGenerator, Pick, MAX_FILTER_ATTEMPTS};
+
+impl MnemonicGenerator {
+    /// Generates a mnemonic with the person's full name in place of their
+    /// surname, such as `hopeful_grace_hopper`, using the default separator.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate`]
+    pub fn generate_full_name(&self) -> Result<String, MnemonicError> {
+        self.generate_full_name_with_separator(self.default_separator())
+    }
+
+    /// Generates a mnemonic with the person's full name in place of their
+    /// surname, joining every word with `separator`.
+    ///
+    /// First names come from [`scientist_info`]. Right-hand words without a
+    /// known first name, such as people known by a single name or words from
+    /// custom lists, are kept as they are. Pass `" "` for display names such
+    /// as `hopeful grace hopper` on leaderboards or in welcome messages.
+    ///
+    /// The length window, profanity filter, regular expression, and naming
+    /// profile are checked against the name with the first name included.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful"], ["hopper"]);
+    /// assert_eq!(
+    ///     generator.generate_full_name_with_separator(" ").unwrap(),
+    ///     "hopeful grace hopper"
+    /// );
+    /// ```
+    pub fn generate_full_name_with_separator(
+        &self,
+        separator: &str,
+    ) -> Result<String, MnemonicError> {
+        let mut rng = crate::rng::default_rng();
+        for _ in 0..MAX_FILTER_ATTEMPTS {
+            let pick = self.choose(&mut rng, separator)?;
+            if let Some(name) = self.full_name(pick, separator) {
+                self.observe(|observer| observer.on_generated(&name));
+                return Ok(name);
+            }
+            self.observe(|observer| observer.on_retry());
+        }
+
+        // Few full names pass the filters: list the ones that do.
+        let names: Vec<String> = self
+            .reachable_picks(separator)
+            .into_iter()
+            .filter_map(|pick| self.full_name(pick, separator))
+            .collect();
+        if names.is_empty() {
+            self.observe(|observer| observer.on_exhausted(MAX_FILTER_ATTEMPTS));
+            return Err(MnemonicError::AllCandidatesRejected);
+        }
+        let name = names[sample::index(&mut rng, names.len())].clone();
+        self.observe(|observer| observer.on_generated(&name));
+        Ok(name)
+    }
+
+    /// Renders the picked words with the person's full name, or returns `None`
+    /// if the full name falls outside the length window or is rejected by the
+    /// profanity filter, the regular expression, or the naming profile.
+    fn full_name(&self, pick: Pick, separator: &str) -> Option<String> {
+        let surname = self.words.right.get(pick.right);
+        let name = match scientist_info(surname).and_then(|person| person.first_name) {
+            Some(first_name) => {
+                let full_name = format!("{}{}{}", first_name, separator, surname);
+                self.render_with_right(pick, &full_name, separator)
+            }
+            None => return Some(self.render(pick, separator)),
+        };
+        let fits = self
+            .length_window
+            .as_ref()
+            .is_none_or(|window| window.range().contains(&name.chars().count()));
+        (fits && self.accepts_name(pick, &name)).then_some(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_names_and_custom_words_are_kept() {
+        let generator = MnemonicGenerator::with_words(["hopeful"], ["archimedes"]);
+        assert_eq!(
+            generator.generate_full_name().unwrap(),
+            "hopeful_archimedes"
+        );
+        let generator = MnemonicGenerator::with_words(["hopeful"], ["nobody"]);
+        assert_eq!(generator.generate_full_name().unwrap(), "hopeful_nobody");
+    }
+
+    #[test]
+    fn filters_check_the_full_name() {
+        let generator = MnemonicGenerator::with_words(["hopeful"], ["hopper", "bohr"])
+            .with_length_range(1..=18);
+        for _ in 0..20 {
+            assert_eq!(
+                generator.generate_full_name().unwrap(),
+                "hopeful_niels_bohr"
+            );
+        }
+        let generator =
+            MnemonicGenerator::with_words(["hopeful"], ["hopper"]).with_length_range(1..=14);
+        assert!(matches!(
+            generator.generate_full_name(),
+            Err(MnemonicError::AllCandidatesRejected)
+        ));
+    }
+
+    #[test]
+    fn applies_prefix_and_separator() {
+        let generator = MnemonicGenerator::with_words(["Hopeful"], ["Lovelace"])
+            .with_prefix("team-")
+            .with_separator("-")
+            .unwrap();
+        assert_eq!(
+            generator.generate_full_name().unwrap(),
+            "team-Hopeful-ada-Lovelace"
+        );
+    }
+}
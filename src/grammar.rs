@@ -0,0 +1,435 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use rand::RngCore;
+
+#[cfg(feature = "animals")]
+use crate::theme::animals;
+use crate::word_list::WordList;
+#[cfg(any(feature = "default-words", feature = "animals"))]
+use crate::words;
+use crate::{sample, ListName, MnemonicError};
+
+/// Adverbs that read naturally in front of the default adjectives.
+#[cfg(any(feature = "default-words", feature = "animals"))]
+static ADVERBS: &[&str] = &[
+    "awfully",
+    "boldly",
+    "briskly",
+    "calmly",
+    "cheerfully",
+    "curiously",
+    "deeply",
+    "eagerly",
+    "fairly",
+    "fiercely",
+    "gently",
+    "gladly",
+    "happily",
+    "highly",
+    "incredibly",
+    "keenly",
+    "kindly",
+    "madly",
+    "mildly",
+    "oddly",
+    "openly",
+    "proudly",
+    "quietly",
+    "rather",
+    "really",
+    "remarkably",
+    "simply",
+    "slightly",
+    "strangely",
+    "suddenly",
+    "truly",
+    "utterly",
+    "very",
+    "warmly",
+    "wildly",
+];
+
+/// The grammatical role of a category of words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PartOfSpeech {
+    Adjective,
+    Adverb,
+    Noun,
+}
+
+impl PartOfSpeech {
+    /// Returns the abbreviation used in pattern strings.
+    pub(crate) fn abbreviation(self) -> &'static str {
+        match self {
+            Self::Adjective => "Adj",
+            Self::Adverb => "Adv",
+            Self::Noun => "Noun",
+        }
+    }
+}
+
+impl FromStr for PartOfSpeech {
+    type Err = MnemonicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "adj" | "adjective" => Ok(Self::Adjective),
+            "adv" | "adverb" => Ok(Self::Adverb),
+            "noun" => Ok(Self::Noun),
+            _ => Err(MnemonicError::InvalidPattern(format!(
+                "unknown part of speech {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+/// An ordered sequence of parts of speech, such as `Adv Adj Noun`.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{Pattern, PartOfSpeech};
+///
+/// let pattern: Pattern = "Adv Adj Noun".parse().unwrap();
+/// assert_eq!(
+///     pattern.parts(),
+///     &[PartOfSpeech::Adverb, PartOfSpeech::Adjective, PartOfSpeech::Noun]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pattern(Vec<PartOfSpeech>);
+
+impl Pattern {
+    /// Creates a pattern from its parts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidPattern` if `parts` is empty
+    pub fn new(parts: Vec<PartOfSpeech>) -> Result<Self, MnemonicError> {
+        if parts.is_empty() {
+            return Err(MnemonicError::InvalidPattern(
+                "pattern has no parts".to_string(),
+            ));
+        }
+        Ok(Self(parts))
+    }
+
+    /// Returns the parts of speech in order.
+    pub fn parts(&self) -> &[PartOfSpeech] {
+        &self.0
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = MnemonicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(parts)
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<&str> = self.0.iter().map(|part| part.abbreviation()).collect();
+        f.write_str(&parts.join(" "))
+    }
+}
+
+/// A generator that composes names from categories of words following
+/// grammatical patterns, such as `Adj Adj Noun` or `Adv Adj Noun`.
+///
+/// The built-in grammar uses the default adjectives and scientist surnames,
+/// plus a list of adverbs. Categories and patterns can be replaced or extended.
+/// Words of the same category are drawn without replacement within a name, so
+/// `Adj Adj Noun` never repeats an adjective.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "default-words")]
+/// # {
+/// use mnemonic_generator::{Grammar, PartOfSpeech};
+///
+/// let grammar = Grammar::new().with_pattern("Adv Adj Noun".parse().unwrap());
+/// let name = grammar.generate().expect("Failed to generate name");
+/// assert_eq!(name.split('_').count(), 3);
+///
+/// let custom = Grammar::new()
+///     .with_category(PartOfSpeech::Noun, ["otter", "heron"])
+///     .with_pattern("Adj Adj Noun".parse().unwrap());
+/// let name = custom.generate_with_separator("-").expect("Failed to generate name");
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Grammar {
+    categories: HashMap<PartOfSpeech, WordList>,
+    patterns: Vec<Pattern>,
+    camel_case: bool,
+}
+
+impl Grammar {
+    /// Creates a grammar with the built-in categories and no patterns.
+    ///
+    /// Until a pattern is added, the grammar generates `Adj Noun` names.
+    #[cfg(feature = "default-words")]
+    pub fn new() -> Self {
+        let mut categories = HashMap::new();
+        categories.insert(PartOfSpeech::Adjective, WordList::Static(words::LEFT_WORDS));
+        categories.insert(PartOfSpeech::Adverb, WordList::Static(ADVERBS));
+        categories.insert(PartOfSpeech::Noun, WordList::Static(words::RIGHT_WORDS));
+        Self {
+            categories,
+            patterns: Vec::new(),
+            camel_case: false,
+        }
+    }
+
+    /// Creates the `AdjAdjAnimal` preset: two default adjectives followed by an
+    /// animal, in CamelCase, such as `BraveQuietOtter`.
+    ///
+    /// The animals are those of `Theme::Animals`, so this requires the
+    /// `animals` or `theme-animals` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "animals")]
+    /// # {
+    /// use mnemonic_generator::Grammar;
+    ///
+    /// let name = Grammar::adj_adj_animal().generate().unwrap();
+    /// assert_eq!(name.chars().filter(|c| c.is_ascii_uppercase()).count(), 3);
+    /// # }
+    /// ```
+    #[cfg(feature = "animals")]
+    pub fn adj_adj_animal() -> Self {
+        let mut grammar = Self::empty()
+            .with_pattern(Pattern(vec![
+                PartOfSpeech::Adjective,
+                PartOfSpeech::Adjective,
+                PartOfSpeech::Noun,
+            ]))
+            .with_camel_case(true);
+        for (part, words) in [
+            (PartOfSpeech::Adjective, words::LEFT_WORDS),
+            (PartOfSpeech::Adverb, ADVERBS),
+            (PartOfSpeech::Noun, animals::WORDS),
+        ] {
+            grammar.categories.insert(part, WordList::Static(words));
+        }
+        grammar
+    }
+
+    /// Creates a grammar without any categories or patterns.
+    pub fn empty() -> Self {
+        Self {
+            categories: HashMap::new(),
+            patterns: Vec::new(),
+            camel_case: false,
+        }
+    }
+
+    /// Sets the words of a category, replacing any existing ones.
+    pub fn with_category<I>(mut self, part: PartOfSpeech, words: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let words = words.into_iter().map(Into::into).collect();
+        self.categories.insert(part, WordList::Owned(words));
+        self
+    }
+
+    /// Adds a pattern. When several patterns are configured, each name uses
+    /// one of them chosen uniformly at random.
+    pub fn with_pattern(mut self, pattern: Pattern) -> Self {
+        self.patterns.push(pattern);
+        self
+    }
+
+    /// Capitalizes the first letter of every word. `generate()` then joins the
+    /// words without a separator.
+    pub fn with_camel_case(mut self, camel_case: bool) -> Self {
+        self.camel_case = camel_case;
+        self
+    }
+
+    /// Generates a name joined by underscores, or by nothing in CamelCase.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::EmptyList` if a category used by the chosen
+    /// pattern has no words, or `MnemonicError::InvalidPattern` if the pattern
+    /// uses a category more often than it has words
+    pub fn generate(&self) -> Result<String, MnemonicError> {
+        self.generate_with_separator(if self.camel_case { "" } else { "_" })
+    }
+
+    /// Generates a name joined by a custom separator.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Grammar::generate`]
+    pub fn generate_with_separator(&self, separator: &str) -> Result<String, MnemonicError> {
+        let mut rng = crate::rng::default_rng();
+        let default_pattern;
+        let pattern = if self.patterns.is_empty() {
+            default_pattern = Pattern(vec![PartOfSpeech::Adjective, PartOfSpeech::Noun]);
+            &default_pattern
+        } else {
+            &self.patterns[sample::index(&mut rng, self.patterns.len())]
+        };
+
+        self.generate_pattern_with(&mut rng, pattern, separator)
+    }
+
+    /// Generates a name following a specific pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Grammar::generate`]
+    pub fn generate_pattern(
+        &self,
+        pattern: &Pattern,
+        separator: &str,
+    ) -> Result<String, MnemonicError> {
+        self.generate_pattern_with(&mut crate::rng::default_rng(), pattern, separator)
+    }
+
+    /// Picks one word per part of the pattern and joins them. Parts of the
+    /// same category draw distinct words.
+    fn generate_pattern_with<G: RngCore + ?Sized>(
+        &self,
+        rng: &mut G,
+        pattern: &Pattern,
+        separator: &str,
+    ) -> Result<String, MnemonicError> {
+        let mut words = Vec::with_capacity(pattern.0.len());
+        let mut drawn: HashMap<PartOfSpeech, Vec<usize>> = HashMap::new();
+        for part in &pattern.0 {
+            let list = self
+                .categories
+                .get(part)
+                .filter(|list| !list.is_empty())
+                .ok_or(MnemonicError::EmptyList {
+                    list: ListName::PartOfSpeech(*part),
+                })?;
+            let used = drawn.entry(*part).or_default();
+            if used.len() == list.len() {
+                return Err(MnemonicError::InvalidPattern(format!(
+                    "pattern {} needs more distinct {} words than the {} available",
+                    pattern,
+                    part.abbreviation(),
+                    list.len()
+                )));
+            }
+            // Draw among the unused indices, then shift past the used ones,
+            // which are kept sorted.
+            let mut idx = sample::index(rng, list.len() - used.len());
+            for &taken in used.iter() {
+                if idx >= taken {
+                    idx += 1;
+                }
+            }
+            used.insert(used.partition_point(|&taken| taken < idx), idx);
+            let word = list.get(idx);
+            words.push(if self.camel_case {
+                capitalize(word)
+            } else {
+                word.to_string()
+            });
+        }
+        Ok(words.join(separator))
+    }
+}
+
+/// Uppercases the first character of a word.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(feature = "default-words")]
+impl Default for Grammar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_pattern_order() {
+        let grammar = Grammar::empty()
+            .with_category(PartOfSpeech::Adverb, ["very"])
+            .with_category(PartOfSpeech::Adjective, ["brave", "quiet"])
+            .with_category(PartOfSpeech::Noun, ["otter"])
+            .with_pattern("Adv Adj Adj Noun".parse().unwrap());
+        for _ in 0..20 {
+            let name = grammar.generate().unwrap();
+            assert!(
+                name == "very_brave_quiet_otter" || name == "very_quiet_brave_otter",
+                "{}",
+                name
+            );
+        }
+
+        let short = grammar.with_category(PartOfSpeech::Adjective, ["brave"]);
+        assert!(matches!(
+            short.generate(),
+            Err(MnemonicError::InvalidPattern(_))
+        ));
+    }
+
+    #[test]
+    fn camel_case_capitalizes_every_word() {
+        let grammar = Grammar::empty()
+            .with_category(PartOfSpeech::Adjective, ["brave"])
+            .with_category(PartOfSpeech::Noun, ["otter"])
+            .with_pattern("Adj Noun".parse().unwrap())
+            .with_camel_case(true);
+        assert_eq!(grammar.generate().unwrap(), "BraveOtter");
+        assert_eq!(grammar.generate_with_separator("-").unwrap(), "Brave-Otter");
+    }
+
+    #[test]
+    fn missing_category_is_an_error() {
+        let grammar = Grammar::empty()
+            .with_category(PartOfSpeech::Noun, ["otter"])
+            .with_pattern("Adj Noun".parse().unwrap());
+        assert!(matches!(
+            grammar.generate(),
+            Err(MnemonicError::EmptyList {
+                list: ListName::PartOfSpeech(PartOfSpeech::Adjective)
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        assert!(matches!(
+            "Adj Verb".parse::<Pattern>(),
+            Err(MnemonicError::InvalidPattern(_))
+        ));
+        assert!("".parse::<Pattern>().is_err());
+        assert_eq!(
+            "adverb adjective noun"
+                .parse::<Pattern>()
+                .unwrap()
+                .to_string(),
+            "Adv Adj Noun"
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::VecDeque;
+
+use crate::{MnemonicError, MnemonicGenerator, MAX_FILTER_ATTEMPTS};
+
+/// A stateful generator that avoids repeating recently generated names.
+///
+/// The last `capacity` names are remembered and never returned again until
+/// they fall out of the history. Optionally, the last few left and right words
+/// can be avoided as well, so consecutive names also look different.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{HistoryGenerator, MnemonicGenerator};
+///
+/// let generator = MnemonicGenerator::with_words(["happy", "brave"], ["turing", "hopper"]);
+/// let mut history = HistoryGenerator::new(generator, 3);
+///
+/// let first = history.generate().expect("Failed to generate mnemonic");
+/// let second = history.generate().expect("Failed to generate mnemonic");
+/// assert_ne!(first, second);
+/// ```
+pub struct HistoryGenerator {
+    generator: MnemonicGenerator,
+    capacity: usize,
+    word_capacity: usize,
+    recent_pairs: VecDeque<(usize, usize)>,
+    recent_left: VecDeque<usize>,
+    recent_right: VecDeque<usize>,
+}
+
+impl HistoryGenerator {
+    /// Wraps a generator, remembering the last `capacity` generated names.
+    pub fn new(generator: MnemonicGenerator, capacity: usize) -> Self {
+        Self {
+            generator,
+            capacity,
+            word_capacity: 0,
+            recent_pairs: VecDeque::with_capacity(capacity),
+            recent_left: VecDeque::new(),
+            recent_right: VecDeque::new(),
+        }
+    }
+
+    /// Also avoids reusing any of the last `capacity` left words and right words.
+    pub fn with_word_history(mut self, capacity: usize) -> Self {
+        self.word_capacity = capacity;
+        self
+    }
+
+    /// Generates a name that is not in the recent history, using the default separator.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::NoAvailableName` if no candidate outside the
+    /// history could be found, for example when the history is larger than the
+    /// namespace, or any error returned by [`MnemonicGenerator::generate`]
+    pub fn generate(&mut self) -> Result<String, MnemonicError> {
+        let separator = self.generator.default_separator();
+        let mut rng = crate::rng::default_rng();
+
+        for _ in 0..MAX_FILTER_ATTEMPTS {
+            let pick = self.generator.choose(&mut rng, separator)?;
+            let (left_idx, right_idx) = (pick.left, pick.right);
+            if self.is_recent(left_idx, right_idx) {
+                trace_event!(trace, left_idx, right_idx, "recently used, retrying");
+                self.generator.observe(|observer| observer.on_retry());
+                continue;
+            }
+
+            let name = self.generator.render(pick, separator);
+            self.generator
+                .observe(|observer| observer.on_generated(&name));
+            self.remember(left_idx, right_idx);
+            return Ok(name);
+        }
+
+        trace_event!(warn, "no name outside the history found");
+        self.generator
+            .observe(|observer| observer.on_exhausted(MAX_FILTER_ATTEMPTS));
+        Err(MnemonicError::NoAvailableName {
+            attempts: MAX_FILTER_ATTEMPTS,
+        })
+    }
+
+    /// Forgets every remembered name and word.
+    pub fn clear(&mut self) {
+        self.recent_pairs.clear();
+        self.recent_left.clear();
+        self.recent_right.clear();
+    }
+
+    /// Returns the wrapped generator.
+    pub fn generator(&self) -> &MnemonicGenerator {
+        &self.generator
+    }
+
+    /// Returns `true` if the pair or either of its words was used recently.
+    fn is_recent(&self, left_idx: usize, right_idx: usize) -> bool {
+        self.recent_pairs.contains(&(left_idx, right_idx))
+            || self.recent_left.contains(&left_idx)
+            || self.recent_right.contains(&right_idx)
+    }
+
+    /// Records a pair, evicting the oldest entries beyond capacity.
+    fn remember(&mut self, left_idx: usize, right_idx: usize) {
+        push_bounded(&mut self.recent_pairs, (left_idx, right_idx), self.capacity);
+        push_bounded(&mut self.recent_left, left_idx, self.word_capacity);
+        push_bounded(&mut self.recent_right, right_idx, self.word_capacity);
+    }
+}
+
+/// Appends a value, dropping the oldest values so that at most `capacity` remain.
+fn push_bounded<T>(queue: &mut VecDeque<T>, value: T, capacity: usize) {
+    if capacity == 0 {
+        return;
+    }
+    while queue.len() >= capacity {
+        queue.pop_front();
+    }
+    queue.push_back(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn never_repeats_within_history() {
+        let generator = MnemonicGenerator::with_words(["a", "b"], ["x", "y"]);
+        let mut history = HistoryGenerator::new(generator, 3);
+
+        let mut recent: VecDeque<String> = VecDeque::new();
+        for _ in 0..100 {
+            let name = history.generate().unwrap();
+            assert!(!recent.contains(&name), "{} repeated", name);
+            push_bounded(&mut recent, name, 3);
+        }
+    }
+
+    #[test]
+    fn avoids_recent_words() {
+        let generator = MnemonicGenerator::with_words(["a", "b"], ["x", "y"]);
+        let mut history = HistoryGenerator::new(generator, 0).with_word_history(1);
+
+        let mut previous = history.generate().unwrap();
+        for _ in 0..50 {
+            let name = history.generate().unwrap();
+            assert_ne!(name[..1], previous[..1]);
+            assert_ne!(name[2..], previous[2..]);
+            previous = name;
+        }
+    }
+
+    #[test]
+    fn errors_when_history_exceeds_namespace() {
+        let generator = MnemonicGenerator::with_words(["a"], ["x"]);
+        let mut history = HistoryGenerator::new(generator, 5);
+        history.generate().unwrap();
+        assert!(matches!(
+            history.generate(),
+            Err(MnemonicError::NoAvailableName { .. })
+        ));
+    }
+}
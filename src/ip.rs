@@ -0,0 +1,150 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::mnemonic::offset_of;
+use crate::{radix, MnemonicError, MnemonicGenerator, WordSide};
+
+impl MnemonicGenerator {
+    /// Encodes an IP address as a sequence of right-hand words joined by the
+    /// default separator.
+    ///
+    /// The address is written in base `n`, where `n` is the number of
+    /// right-hand words, using as few words as any address of its family
+    /// needs. With the default lists an IPv4 address takes five words and an
+    /// IPv6 address seventeen. Words are normalized to the profile's case.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InsufficientWords` if the right-hand list has
+    /// fewer than two words
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use std::net::{IpAddr, Ipv4Addr};
+    ///
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::new().with_separator("-").unwrap();
+    /// let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1));
+    /// let words = generator.encode_ip(ip).unwrap();
+    /// assert_eq!(words.split('-').count(), 5);
+    /// assert_eq!(generator.decode_ip(&words).unwrap(), ip);
+    /// # }
+    /// ```
+    pub fn encode_ip(&self, ip: IpAddr) -> Result<String, MnemonicError> {
+        let (value, bits) = match ip {
+            IpAddr::V4(ip) => (u128::from(u32::from(ip)), 32),
+            IpAddr::V6(ip) => (u128::from(ip), 128),
+        };
+        let base = self.ip_base()?;
+
+        let digits = radix::to_digits(value, base, radix::width(base, bits));
+
+        let words: Vec<String> = digits
+            .into_iter()
+            .map(|digit| self.normalize(self.words.right.get(digit)))
+            .collect();
+        Ok(words.join(&self.normalize(self.default_separator())))
+    }
+
+    /// Decodes an IP address encoded by [`MnemonicGenerator::encode_ip`].
+    ///
+    /// The address family is determined by the number of words.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidMnemonic` if the words do not encode an
+    /// address, or `MnemonicError::InsufficientWords` if the right-hand list
+    /// has fewer than two words
+    pub fn decode_ip(&self, words: &str) -> Result<IpAddr, MnemonicError> {
+        let invalid = |part: &str, reason: String| MnemonicError::InvalidMnemonic {
+            name: words.to_string(),
+            position: offset_of(words, part),
+            reason,
+        };
+        let base = self.ip_base()?;
+
+        let parts: Vec<&str> = words
+            .split(self.normalize(self.default_separator()).as_str())
+            .collect();
+        let bits = match parts.len() {
+            len if len == radix::width(base, 32) => 32,
+            len if len == radix::width(base, 128) => 128,
+            len => {
+                return Err(invalid(
+                    words,
+                    format!("{} words encode no IP address", len),
+                ))
+            }
+        };
+
+        let digits = parts
+            .into_iter()
+            .map(|part| {
+                self.find_word(WordSide::Right, part)
+                    .map(|(digit, _)| digit)
+                    .ok_or_else(|| invalid(part, format!("unknown word {:?}", part)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let value = radix::from_digits(digits, base)
+            .ok_or_else(|| invalid(words, "value exceeds the address range".to_string()))?;
+
+        match bits {
+            32 => u32::try_from(value)
+                .map(|value| IpAddr::V4(Ipv4Addr::from(value)))
+                .map_err(|_| invalid(words, "value exceeds the address range".to_string())),
+            _ => Ok(IpAddr::V6(Ipv6Addr::from(value))),
+        }
+    }
+
+    /// Returns the number of right-hand words, which serves as the base of
+    /// the encoding.
+    fn ip_base(&self) -> Result<u128, MnemonicError> {
+        match self.words.right.len() {
+            len @ 0..=1 => Err(MnemonicError::InsufficientWords {
+                needed: 2,
+                available: len,
+            }),
+            len => Ok(len as u128),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_addresses() {
+        let generator = MnemonicGenerator::new();
+        for ip in [
+            IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            IpAddr::V4(Ipv4Addr::BROADCAST),
+            IpAddr::V6(Ipv6Addr::LOCALHOST),
+            IpAddr::V6(Ipv6Addr::from(u128::MAX)),
+        ] {
+            let words = generator.encode_ip(ip).unwrap();
+            assert_eq!(generator.decode_ip(&words).unwrap(), ip);
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_range_words() {
+        let generator = MnemonicGenerator::with_words(["a"], ["zero", "one", "two"]);
+        let max = "two_".repeat(20) + "two";
+        assert!(matches!(
+            generator.decode_ip(&max),
+            Err(MnemonicError::InvalidMnemonic { .. })
+        ));
+        assert!(matches!(
+            MnemonicGenerator::with_words(["a"], ["zero"])
+                .encode_ip(IpAddr::V4(Ipv4Addr::LOCALHOST)),
+            Err(MnemonicError::InsufficientWords {
+                needed: 2,
+                available: 1
+            })
+        ));
+    }
+}
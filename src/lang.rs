@@ -0,0 +1,12 @@
+//! Curated word lists for languages other than English.
+//!
+//! Each language is compiled in only when its cargo feature is enabled, and is
+//! registered in [`LocaleRegistry::new`](crate::LocaleRegistry::new) under the
+//! matching [`Locale`](crate::Locale).
+
+#[cfg(feature = "lang-de")]
+pub(crate) mod de;
+#[cfg(feature = "lang-es")]
+pub(crate) mod es;
+#[cfg(feature = "lang-fr")]
+pub(crate) mod fr;
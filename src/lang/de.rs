@@ -0,0 +1,118 @@
+//! German word lists, enabled by the `lang-de` feature.
+
+/// German adjectives.
+pub(crate) static LEFT_WORDS: &[&str] = &[
+    "achtsam",
+    "artig",
+    "beherzt",
+    "bunt",
+    "eifrig",
+    "emsig",
+    "findig",
+    "fleißig",
+    "flink",
+    "freundlich",
+    "fröhlich",
+    "geduldig",
+    "gelassen",
+    "gewitzt",
+    "glücklich",
+    "großartig",
+    "heiter",
+    "höflich",
+    "klug",
+    "kreativ",
+    "kühn",
+    "lustig",
+    "munter",
+    "mutig",
+    "neugierig",
+    "pfiffig",
+    "ruhig",
+    "sanft",
+    "schlau",
+    "sorgsam",
+    "stolz",
+    "tapfer",
+    "treu",
+    "wach",
+    "weise",
+    "witzig",
+    "wunderbar",
+    "zäh",
+    "zuversichtlich",
+];
+
+/// Surnames of notable scientists and inventors from the German-speaking world.
+pub(crate) static RIGHT_WORDS: &[&str] = &[
+    // Max Born - German physicist who formulated the probabilistic interpretation of quantum mechanics. https://en.wikipedia.org/wiki/Max_Born
+    "born",
+    // Ludwig Boltzmann - Austrian physicist who founded statistical mechanics. https://en.wikipedia.org/wiki/Ludwig_Boltzmann
+    "boltzmann",
+    // Robert Bunsen - German chemist who pioneered spectroscopy. https://en.wikipedia.org/wiki/Robert_Bunsen
+    "bunsen",
+    // Georg Cantor - German mathematician who created set theory. https://en.wikipedia.org/wiki/Georg_Cantor
+    "cantor",
+    // Richard Dedekind - German mathematician known for his work on number theory. https://en.wikipedia.org/wiki/Richard_Dedekind
+    "dedekind",
+    // Rudolf Diesel - German inventor of the diesel engine. https://en.wikipedia.org/wiki/Rudolf_Diesel
+    "diesel",
+    // Joseph von Fraunhofer - German optician who discovered dark lines in the solar spectrum. https://en.wikipedia.org/wiki/Joseph_von_Fraunhofer
+    "fraunhofer",
+    // Carl Friedrich Gauß - German mathematician, the "prince of mathematicians". https://en.wikipedia.org/wiki/Carl_Friedrich_Gauss
+    "gauß",
+    // Fritz Haber - German chemist who developed ammonia synthesis. https://en.wikipedia.org/wiki/Fritz_Haber
+    "haber",
+    // Otto Hahn - German chemist who discovered nuclear fission. https://en.wikipedia.org/wiki/Otto_Hahn
+    "hahn",
+    // Werner Heisenberg - German physicist and pioneer of quantum mechanics. https://en.wikipedia.org/wiki/Werner_Heisenberg
+    "heisenberg",
+    // Heinrich Hertz - German physicist who proved the existence of electromagnetic waves. https://en.wikipedia.org/wiki/Heinrich_Hertz
+    "hertz",
+    // David Hilbert - German mathematician who shaped twentieth-century mathematics. https://en.wikipedia.org/wiki/David_Hilbert
+    "hilbert",
+    // Alexander von Humboldt - German naturalist and explorer. https://en.wikipedia.org/wiki/Alexander_von_Humboldt
+    "humboldt",
+    // Johannes Kepler - German astronomer who described the laws of planetary motion. https://en.wikipedia.org/wiki/Johannes_Kepler
+    "kepler",
+    // Felix Klein - German mathematician known for the Erlangen program. https://en.wikipedia.org/wiki/Felix_Klein
+    "klein",
+    // Robert Koch - German physician who founded modern bacteriology. https://en.wikipedia.org/wiki/Robert_Koch
+    "koch",
+    // Gottfried Wilhelm Leibniz - German polymath who co-invented calculus. https://en.wikipedia.org/wiki/Gottfried_Wilhelm_Leibniz
+    "leibniz",
+    // Justus von Liebig - German chemist and founder of organic chemistry. https://en.wikipedia.org/wiki/Justus_von_Liebig
+    "liebig",
+    // Lise Meitner - Austrian physicist who co-discovered nuclear fission. https://en.wikipedia.org/wiki/Lise_Meitner
+    "meitner",
+    // Gregor Mendel - Austrian biologist and founder of genetics. https://en.wikipedia.org/wiki/Gregor_Mendel
+    "mendel",
+    // Rudolf Mößbauer - German physicist who discovered recoilless resonance. https://en.wikipedia.org/wiki/Rudolf_M%C3%B6%C3%9Fbauer
+    "mößbauer",
+    // Emmy Noether - German mathematician known for her contributions to abstract algebra. https://en.wikipedia.org/wiki/Emmy_Noether
+    "noether",
+    // Georg Simon Ohm - German physicist who discovered Ohm's law. https://en.wikipedia.org/wiki/Georg_Ohm
+    "ohm",
+    // Wolfgang Pauli - Austrian physicist who formulated the exclusion principle. https://en.wikipedia.org/wiki/Wolfgang_Pauli
+    "pauli",
+    // Max Planck - German physicist who originated quantum theory. https://en.wikipedia.org/wiki/Max_Planck
+    "planck",
+    // Bernhard Riemann - German mathematician known for Riemannian geometry. https://en.wikipedia.org/wiki/Bernhard_Riemann
+    "riemann",
+    // Wilhelm Conrad Röntgen - German physicist who discovered X-rays. https://en.wikipedia.org/wiki/Wilhelm_R%C3%B6ntgen
+    "röntgen",
+    // Erwin Schrödinger - Austrian physicist who formulated wave mechanics. https://en.wikipedia.org/wiki/Erwin_Schr%C3%B6dinger
+    "schrödinger",
+    // Werner von Siemens - German inventor and industrialist. https://en.wikipedia.org/wiki/Werner_von_Siemens
+    "siemens",
+    // Rudolf Virchow - German physician, father of modern pathology. https://en.wikipedia.org/wiki/Rudolf_Virchow
+    "virchow",
+    // Alfred Wegener - German geophysicist who proposed continental drift. https://en.wikipedia.org/wiki/Alfred_Wegener
+    "wegener",
+    // Karl Weierstrass - German mathematician, father of modern analysis. https://en.wikipedia.org/wiki/Karl_Weierstrass
+    "weierstraß",
+    // Hermann Weyl - German mathematician and theoretical physicist. https://en.wikipedia.org/wiki/Hermann_Weyl
+    "weyl",
+    // Konrad Zuse - German engineer who built the first programmable computer. https://en.wikipedia.org/wiki/Konrad_Zuse
+    "zuse",
+];
@@ -0,0 +1,98 @@
+//! Spanish word lists, enabled by the `lang-es` feature.
+
+/// Spanish adjectives, chosen to be invariant in gender where possible.
+pub(crate) static LEFT_WORDS: &[&str] = &[
+    "admirable",
+    "alegre",
+    "amable",
+    "amigable",
+    "audaz",
+    "brillante",
+    "capaz",
+    "cortés",
+    "eficaz",
+    "elegante",
+    "entusiasta",
+    "feliz",
+    "fiel",
+    "firme",
+    "formidable",
+    "fuerte",
+    "genial",
+    "grande",
+    "hábil",
+    "humilde",
+    "idealista",
+    "inteligente",
+    "joven",
+    "leal",
+    "libre",
+    "noble",
+    "optimista",
+    "paciente",
+    "perspicaz",
+    "prudente",
+    "puntual",
+    "sagaz",
+    "sensible",
+    "sutil",
+    "tenaz",
+    "valiente",
+    "veloz",
+];
+
+/// Surnames of notable scientists and inventors from the Spanish-speaking world.
+pub(crate) static RIGHT_WORDS: &[&str] = &[
+    // Santiago Ramón y Cajal - Spanish neuroscientist, father of modern neuroscience. https://en.wikipedia.org/wiki/Santiago_Ram%C3%B3n_y_Cajal
+    "cajal",
+    // Blas Cabrera - Spanish physicist known for his work on magnetism. https://en.wikipedia.org/wiki/Blas_Cabrera_Felipe
+    "cabrera",
+    // Francisco José de Caldas - Colombian naturalist and geographer. https://en.wikipedia.org/wiki/Francisco_Jos%C3%A9_de_Caldas
+    "caldas",
+    // Juan de la Cierva - Spanish engineer who invented the autogyro. https://en.wikipedia.org/wiki/Juan_de_la_Cierva
+    "cierva",
+    // Josep Comas i Solà - Catalan astronomer. https://en.wikipedia.org/wiki/Josep_Comas_i_Sol%C3%A0
+    "comas",
+    // Andrés Manuel del Río - Spanish-Mexican chemist who discovered vanadium. https://en.wikipedia.org/wiki/Andr%C3%A9s_Manuel_del_R%C3%ADo
+    "delrio",
+    // Arturo Duperier - Spanish physicist who studied cosmic rays. https://en.wikipedia.org/wiki/Arturo_Duperier
+    "duperier",
+    // Fausto Elhuyar - Spanish chemist who co-discovered tungsten. https://en.wikipedia.org/wiki/Fausto_Elhuyar
+    "elhuyar",
+    // Carlos Finlay - Cuban epidemiologist who identified the mosquito as the carrier of yellow fever. https://en.wikipedia.org/wiki/Carlos_Finlay
+    "finlay",
+    // Bernardo Houssay - Argentine physiologist and Nobel laureate. https://en.wikipedia.org/wiki/Bernardo_Houssay
+    "houssay",
+    // Luis Federico Leloir - Argentine biochemist and Nobel laureate. https://en.wikipedia.org/wiki/Luis_Federico_Leloir
+    "leloir",
+    // Gregorio Marañón - Spanish physician and endocrinologist. https://en.wikipedia.org/wiki/Gregorio_Mara%C3%B1%C3%B3n
+    "marañón",
+    // Ramón Margalef - Catalan ecologist. https://en.wikipedia.org/wiki/Ramon_Margalef
+    "margalef",
+    // César Milstein - Argentine biochemist who pioneered monoclonal antibodies. https://en.wikipedia.org/wiki/C%C3%A9sar_Milstein
+    "milstein",
+    // Francisco Mojica - Spanish microbiologist who discovered CRISPR. https://en.wikipedia.org/wiki/Francisco_Mojica
+    "mojica",
+    // Mario Molina - Mexican chemist who explained the ozone hole. https://en.wikipedia.org/wiki/Mario_Molina
+    "molina",
+    // Narcís Monturiol - Catalan inventor of the first combustion-engine submarine. https://en.wikipedia.org/wiki/Narc%C3%ADs_Monturiol
+    "monturiol",
+    // José Celestino Mutis - Spanish botanist and mathematician. https://en.wikipedia.org/wiki/Jos%C3%A9_Celestino_Mutis
+    "mutis",
+    // Severo Ochoa - Spanish-American biochemist and Nobel laureate. https://en.wikipedia.org/wiki/Severo_Ochoa
+    "ochoa",
+    // Julio Rey Pastor - Spanish mathematician and historian of science. https://en.wikipedia.org/wiki/Julio_Rey_Pastor
+    "pastor",
+    // Isaac Peral - Spanish engineer who built an early electric submarine. https://en.wikipedia.org/wiki/Isaac_Peral
+    "peral",
+    // Leonardo Torres Quevedo - Spanish engineer and pioneer of automation. https://en.wikipedia.org/wiki/Leonardo_Torres_Quevedo
+    "quevedo",
+    // Ángela Ruiz Robles - Spanish teacher who invented a precursor of the e-book. https://en.wikipedia.org/wiki/%C3%81ngela_Ruiz_Robles
+    "robles",
+    // Margarita Salas - Spanish biochemist who discovered phi29 DNA polymerase. https://en.wikipedia.org/wiki/Margarita_Salas
+    "salas",
+    // Miguel Servet - Spanish theologian and physician who described pulmonary circulation. https://en.wikipedia.org/wiki/Michael_Servetus
+    "servet",
+    // Antonio de Ulloa - Spanish scientist who co-discovered platinum. https://en.wikipedia.org/wiki/Antonio_de_Ulloa
+    "ulloa",
+];
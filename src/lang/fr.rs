@@ -0,0 +1,116 @@
+//! French word lists, enabled by the `lang-fr` feature.
+
+/// French adjectives, chosen to be invariant in gender.
+pub(crate) static LEFT_WORDS: &[&str] = &[
+    "agile",
+    "aimable",
+    "alerte",
+    "brave",
+    "calme",
+    "célèbre",
+    "digne",
+    "drôle",
+    "efficace",
+    "énergique",
+    "fiable",
+    "fidèle",
+    "habile",
+    "honnête",
+    "humble",
+    "libre",
+    "lucide",
+    "magnifique",
+    "modeste",
+    "noble",
+    "optimiste",
+    "pacifique",
+    "paisible",
+    "poétique",
+    "pratique",
+    "rapide",
+    "romantique",
+    "sage",
+    "serviable",
+    "simple",
+    "sincère",
+    "sociable",
+    "solide",
+    "souple",
+    "sympathique",
+    "tranquille",
+    "utile",
+];
+
+/// Surnames of notable scientists and inventors from the French-speaking world.
+pub(crate) static RIGHT_WORDS: &[&str] = &[
+    // Clément Ader - French engineer and aviation pioneer. https://en.wikipedia.org/wiki/Cl%C3%A9ment_Ader
+    "ader",
+    // André-Marie Ampère - French physicist, founder of electrodynamics. https://en.wikipedia.org/wiki/Andr%C3%A9-Marie_Amp%C3%A8re
+    "ampère",
+    // Françoise Barré-Sinoussi - French virologist who co-discovered HIV. https://en.wikipedia.org/wiki/Fran%C3%A7oise_Barr%C3%A9-Sinoussi
+    "barré",
+    // Henri Becquerel - French physicist who discovered radioactivity. https://en.wikipedia.org/wiki/Henri_Becquerel
+    "becquerel",
+    // Louis Braille - French inventor of the Braille reading system. https://en.wikipedia.org/wiki/Louis_Braille
+    "braille",
+    // Louis de Broglie - French physicist who proposed the wave nature of electrons. https://en.wikipedia.org/wiki/Louis_de_Broglie
+    "broglie",
+    // Georges-Louis Leclerc, Comte de Buffon - French naturalist. https://en.wikipedia.org/wiki/Georges-Louis_Leclerc,_Comte_de_Buffon
+    "buffon",
+    // Sadi Carnot - French physicist, father of thermodynamics. https://en.wikipedia.org/wiki/Nicolas_L%C3%A9onard_Sadi_Carnot
+    "carnot",
+    // Augustin-Louis Cauchy - French mathematician who pioneered rigorous analysis. https://en.wikipedia.org/wiki/Augustin-Louis_Cauchy
+    "cauchy",
+    // Emmanuelle Charpentier - French microbiologist who co-developed CRISPR gene editing. https://en.wikipedia.org/wiki/Emmanuelle_Charpentier
+    "charpentier",
+    // Charles-Augustin de Coulomb - French physicist known for Coulomb's law. https://en.wikipedia.org/wiki/Charles-Augustin_de_Coulomb
+    "coulomb",
+    // Georges Cuvier - French naturalist, founder of paleontology. https://en.wikipedia.org/wiki/Georges_Cuvier
+    "cuvier",
+    // Marie Curie - Polish-French physicist and chemist, twice a Nobel laureate. https://en.wikipedia.org/wiki/Marie_Curie
+    "curie",
+    // Louis Daguerre - French inventor of the daguerreotype. https://en.wikipedia.org/wiki/Louis_Daguerre
+    "daguerre",
+    // René Descartes - French philosopher and mathematician. https://en.wikipedia.org/wiki/Ren%C3%A9_Descartes
+    "descartes",
+    // Pierre de Fermat - French mathematician known for Fermat's Last Theorem. https://en.wikipedia.org/wiki/Pierre_de_Fermat
+    "fermat",
+    // Léon Foucault - French physicist known for the Foucault pendulum. https://en.wikipedia.org/wiki/L%C3%A9on_Foucault
+    "foucault",
+    // Joseph Fourier - French mathematician who introduced Fourier series. https://en.wikipedia.org/wiki/Joseph_Fourier
+    "fourier",
+    // Augustin-Jean Fresnel - French physicist who advanced wave optics. https://en.wikipedia.org/wiki/Augustin-Jean_Fresnel
+    "fresnel",
+    // Évariste Galois - French mathematician who founded Galois theory. https://en.wikipedia.org/wiki/%C3%89variste_Galois
+    "galois",
+    // Sophie Germain - French mathematician known for her work on elasticity and number theory. https://en.wikipedia.org/wiki/Sophie_Germain
+    "germain",
+    // Irène Joliot-Curie - French chemist who discovered artificial radioactivity. https://en.wikipedia.org/wiki/Ir%C3%A8ne_Joliot-Curie
+    "joliot",
+    // Joseph-Louis Lagrange - Italian-French mathematician known for analytical mechanics. https://en.wikipedia.org/wiki/Joseph-Louis_Lagrange
+    "lagrange",
+    // Jean-Baptiste Lamarck - French naturalist and early evolutionary theorist. https://en.wikipedia.org/wiki/Jean-Baptiste_Lamarck
+    "lamarck",
+    // Pierre-Simon Laplace - French mathematician and astronomer. https://en.wikipedia.org/wiki/Pierre-Simon_Laplace
+    "laplace",
+    // Antoine Lavoisier - French chemist, father of modern chemistry. https://en.wikipedia.org/wiki/Antoine_Lavoisier
+    "lavoisier",
+    // Adrien-Marie Legendre - French mathematician known for Legendre polynomials. https://en.wikipedia.org/wiki/Adrien-Marie_Legendre
+    "legendre",
+    // Auguste and Louis Lumière - French pioneers of cinematography. https://en.wikipedia.org/wiki/Auguste_and_Louis_Lumi%C3%A8re
+    "lumière",
+    // Gaspard Monge - French mathematician, inventor of descriptive geometry. https://en.wikipedia.org/wiki/Gaspard_Monge
+    "monge",
+    // Nicéphore Niépce - French inventor who made the first photograph. https://en.wikipedia.org/wiki/Nic%C3%A9phore_Ni%C3%A9pce
+    "niépce",
+    // Blaise Pascal - French mathematician, physicist, and inventor. https://en.wikipedia.org/wiki/Blaise_Pascal
+    "pascal",
+    // Louis Pasteur - French chemist and microbiologist. https://en.wikipedia.org/wiki/Louis_Pasteur
+    "pasteur",
+    // Jean Perrin - French physicist who confirmed the atomic nature of matter. https://en.wikipedia.org/wiki/Jean_Baptiste_Perrin
+    "perrin",
+    // Henri Poincaré - French mathematician and theoretical physicist. https://en.wikipedia.org/wiki/Henri_Poincar%C3%A9
+    "poincaré",
+    // Jean-Pierre Serre - French mathematician and first recipient of the Abel Prize. https://en.wikipedia.org/wiki/Jean-Pierre_Serre
+    "serre",
+];
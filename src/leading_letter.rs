@@ -0,0 +1,137 @@
+use crate::word_list::WordList;
+use crate::MnemonicGenerator;
+
+/// Required first letters of the left and right word, each with the bucket of
+/// words starting with that letter precomputed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct LeadingLetters {
+    left: Option<LetterBucket>,
+    right: Option<LetterBucket>,
+}
+
+/// The words of one list starting with `letter`, ignoring case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LetterBucket {
+    letter: char,
+    /// Indices of the words in the bucket, in ascending order.
+    words: Vec<usize>,
+}
+
+impl LetterBucket {
+    fn new(letter: char, words: &WordList) -> Self {
+        Self {
+            letter,
+            words: words
+                .iter()
+                .enumerate()
+                .filter(|(_, word)| starts_with_letter(word, letter))
+                .map(|(idx, _)| idx)
+                .collect(),
+        }
+    }
+
+    fn contains(&self, idx: usize) -> bool {
+        self.words.binary_search(&idx).is_ok()
+    }
+}
+
+impl LeadingLetters {
+    /// Returns the required letters of the left and right word, if any.
+    pub(crate) fn letters(&self) -> (Option<char>, Option<char>) {
+        let letter = |bucket: &Option<LetterBucket>| bucket.as_ref().map(|bucket| bucket.letter);
+        (letter(&self.left), letter(&self.right))
+    }
+
+    /// Returns the indices of the words in each bucket, if constrained.
+    pub(crate) fn indices(&self) -> (Option<&[usize]>, Option<&[usize]>) {
+        (
+            self.left.as_ref().map(|bucket| bucket.words.as_slice()),
+            self.right.as_ref().map(|bucket| bucket.words.as_slice()),
+        )
+    }
+
+    /// Returns `true` if both words start with their required letters.
+    pub(crate) fn fits(&self, left_idx: usize, right_idx: usize) -> bool {
+        self.left
+            .as_ref()
+            .is_none_or(|bucket| bucket.contains(left_idx))
+            && self
+                .right
+                .as_ref()
+                .is_none_or(|bucket| bucket.contains(right_idx))
+    }
+}
+
+/// Returns `true` if the first character of `word` is `letter`, ignoring case.
+fn starts_with_letter(word: &str, letter: char) -> bool {
+    word.chars()
+        .next()
+        .is_some_and(|first| first.to_lowercase().eq(letter.to_lowercase()))
+}
+
+impl MnemonicGenerator {
+    /// Requires the left word to start with `letter`, ignoring case.
+    ///
+    /// The words starting with the letter are found once, here, and candidates
+    /// are drawn from them directly. Useful for namespacing names
+    /// alphabetically, such as giving every staging machine a name starting
+    /// with `s`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["sleepy", "hopeful"], ["shannon", "turing"])
+    ///     .with_left_starting_with('s');
+    /// assert!(generator.generate().unwrap().starts_with("sleepy_"));
+    /// ```
+    pub fn with_left_starting_with(mut self, letter: char) -> Self {
+        let mut letters = self.leading_letters.take().unwrap_or_default();
+        letters.left = Some(LetterBucket::new(letter, &self.words.left));
+        self.leading_letters = Some(letters);
+        self.restrict_length_window()
+    }
+
+    /// Requires the right word to start with `letter`, ignoring case.
+    ///
+    /// See [`MnemonicGenerator::with_left_starting_with`].
+    pub fn with_right_starting_with(mut self, letter: char) -> Self {
+        let mut letters = self.leading_letters.take().unwrap_or_default();
+        letters.right = Some(LetterBucket::new(letter, &self.words.right));
+        self.leading_letters = Some(letters);
+        self.restrict_length_window()
+    }
+
+    /// Requires both words to start with `letter`, ignoring case, for
+    /// alliterative names such as `sleepy_shannon`.
+    pub fn with_words_starting_with(self, letter: char) -> Self {
+        self.with_left_starting_with(letter)
+            .with_right_starting_with(letter)
+    }
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use crate::{MnemonicError, MnemonicGenerator};
+
+    #[test]
+    fn names_start_with_the_letter() {
+        let generator = MnemonicGenerator::new().with_words_starting_with('S');
+        for _ in 0..100 {
+            let name = generator.generate().unwrap();
+            let (left, right) = name.split_once('_').unwrap();
+            assert!(left.starts_with('s') && right.starts_with('s'), "{}", name);
+        }
+        assert!(generator.combination_count() < 108 * 237);
+    }
+
+    #[test]
+    fn missing_letters_are_reported() {
+        let generator = MnemonicGenerator::new().with_right_starting_with('0');
+        assert!(matches!(
+            generator.generate(),
+            Err(MnemonicError::AllCandidatesRejected)
+        ));
+    }
+}
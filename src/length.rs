@@ -0,0 +1,241 @@
+use std::ops::RangeInclusive;
+
+use rand::RngCore;
+
+use crate::sample;
+use crate::word_list::WordList;
+
+/// Word indices grouped by length, used to draw pairs whose combined length
+/// falls within a requested window without rejection sampling.
+///
+/// The pairs of length buckets are ordered by combined length, with the
+/// number of pairs of words up to and including each, when the window is
+/// configured and again whenever the words of a side are restricted. A draw
+/// then finds the range of combined lengths that fit and its bucket pair by
+/// binary search, without allocating, whatever the separator and affixes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LengthWindow {
+    range: RangeInclusive<usize>,
+    left_by_len: Vec<Vec<usize>>,
+    right_by_len: Vec<Vec<usize>>,
+    table: LengthTable,
+}
+
+/// The pairs of length buckets of the allowed words, ordered by combined
+/// length.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct LengthTable {
+    /// The allowed left indices of each length.
+    left_by_len: Vec<Vec<usize>>,
+    /// The allowed right indices of each length.
+    right_by_len: Vec<Vec<usize>>,
+    /// Every pair of non-empty buckets as the number of pairs of words up to
+    /// and including it, the left length, and the right length.
+    pairs: Vec<(usize, usize, usize)>,
+    /// The number of entries of `pairs` below each combined length, with one
+    /// more entry for the end.
+    starts: Vec<usize>,
+}
+
+impl LengthTable {
+    /// Builds the table over the words of `left_by_len` and `right_by_len`.
+    fn new(left_by_len: Vec<Vec<usize>>, right_by_len: Vec<Vec<usize>>) -> Self {
+        let longest = (left_by_len.len() + right_by_len.len()).saturating_sub(1);
+        let mut pairs = Vec::new();
+        let mut starts = Vec::with_capacity(longest + 1);
+        let mut total = 0;
+        for combined in 0..longest {
+            starts.push(pairs.len());
+            for (left_len, lefts) in left_by_len.iter().enumerate().take(combined + 1) {
+                let Some(rights) = right_by_len.get(combined - left_len) else {
+                    continue;
+                };
+                if !lefts.is_empty() && !rights.is_empty() {
+                    total += lefts.len() * rights.len();
+                    pairs.push((total, left_len, combined - left_len));
+                }
+            }
+        }
+        starts.push(pairs.len());
+        Self {
+            left_by_len,
+            right_by_len,
+            pairs,
+            starts,
+        }
+    }
+
+    /// Returns the number of pairs of words in the entries before `entry`.
+    fn count_before(&self, entry: usize) -> usize {
+        entry.checked_sub(1).map_or(0, |last| self.pairs[last].0)
+    }
+
+    /// Returns the entries whose combined length lies within `min..=max`.
+    fn entries(&self, min: usize, max: usize) -> (usize, usize) {
+        let end = self.starts.len() - 1;
+        (
+            self.starts[min.min(end)],
+            self.starts[max.saturating_add(1).min(end)],
+        )
+    }
+}
+
+impl LengthWindow {
+    /// Precomputes the length buckets for both word lists.
+    pub(crate) fn new(
+        range: RangeInclusive<usize>,
+        left_words: &WordList,
+        right_words: &WordList,
+    ) -> Self {
+        let (left_by_len, right_by_len) = (bucket_by_len(left_words), bucket_by_len(right_words));
+        Self {
+            range,
+            table: LengthTable::new(left_by_len.clone(), right_by_len.clone()),
+            left_by_len,
+            right_by_len,
+        }
+    }
+
+    /// Rebuilds the table over the words of each side in `left` and `right`,
+    /// sorted sets of indices for the per-word constraints configured
+    /// alongside the window, or over every word of a side without any.
+    pub(crate) fn restrict(&mut self, left: Option<&[usize]>, right: Option<&[usize]>) {
+        self.table = LengthTable::new(
+            restrict(&self.left_by_len, left),
+            restrict(&self.right_by_len, right),
+        );
+    }
+
+    /// Returns the window of total lengths.
+    pub(crate) fn range(&self) -> RangeInclusive<usize> {
+        self.range.clone()
+    }
+
+    /// Returns `true` if the words, together with `fixed_len` characters of
+    /// separator and affixes, fit the window.
+    pub(crate) fn fits(&self, left: &str, right: &str, fixed_len: usize) -> bool {
+        self.range
+            .contains(&(left.chars().count() + fixed_len + right.chars().count()))
+    }
+
+    /// Returns the number of allowed pairs whose words, together with
+    /// `fixed_len` characters of separator and affixes, fit the window.
+    pub(crate) fn count(&self, fixed_len: usize) -> usize {
+        let Some((first, last)) = self.feasible(fixed_len) else {
+            return 0;
+        };
+        self.table.count_before(last) - self.table.count_before(first)
+    }
+
+    /// Draws a uniformly distributed pair of allowed indices whose words,
+    /// together with `fixed_len` characters of separator and affixes, fit the
+    /// window.
+    ///
+    /// Returns `None` if no pair fits.
+    pub(crate) fn pick<G: RngCore + ?Sized>(
+        &self,
+        rng: &mut G,
+        fixed_len: usize,
+    ) -> Option<(usize, usize)> {
+        let (first, last) = self.feasible(fixed_len)?;
+        let below = self.table.count_before(first);
+        let total = self.table.count_before(last) - below;
+        if total == 0 {
+            return None;
+        }
+
+        let target = below + sample::index(rng, total);
+        let entry = self
+            .table
+            .pairs
+            .partition_point(|&(end, _, _)| end <= target);
+        let (end, left_len, right_len) = self.table.pairs[entry];
+        let (lefts, rights) = (
+            &self.table.left_by_len[left_len],
+            &self.table.right_by_len[right_len],
+        );
+        let offset = target - (end - lefts.len() * rights.len());
+        Some((lefts[offset / rights.len()], rights[offset % rights.len()]))
+    }
+
+    /// Returns the entries of the table whose pairs fit the window with
+    /// `fixed_len` more characters, or `None` if it leaves no room.
+    fn feasible(&self, fixed_len: usize) -> Option<(usize, usize)> {
+        let min = self.range.start().saturating_sub(fixed_len);
+        let max = self.range.end().checked_sub(fixed_len)?;
+        Some(self.table.entries(min, max))
+    }
+}
+
+/// Keeps only the indices of each bucket that are in `allowed`, if given.
+fn restrict(buckets: &[Vec<usize>], allowed: Option<&[usize]>) -> Vec<Vec<usize>> {
+    buckets
+        .iter()
+        .map(|bucket| match allowed {
+            Some(allowed) => bucket
+                .iter()
+                .copied()
+                .filter(|idx| allowed.binary_search(idx).is_ok())
+                .collect(),
+            None => bucket.clone(),
+        })
+        .collect()
+}
+
+/// Groups word indices by their length in characters.
+pub(crate) fn bucket_by_len(words: &WordList) -> Vec<Vec<usize>> {
+    let mut buckets: Vec<Vec<usize>> = Vec::new();
+    for (idx, word) in words.iter().enumerate() {
+        let len = word.chars().count();
+        if buckets.len() <= len {
+            buckets.resize(len + 1, Vec::new());
+        }
+        buckets[len].push(idx);
+    }
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_only_pairs_within_window() {
+        let left = ["a", "bbb", "ccccc"];
+        let right = ["x", "yyyy"];
+        let window = LengthWindow::new(
+            5..=6,
+            &WordList::Static(&["a", "bbb", "ccccc"]),
+            &WordList::Static(&["x", "yyyy"]),
+        );
+        let mut rng = crate::rng::default_rng();
+
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..100 {
+            let (l, r) = window.pick(&mut rng, 1).expect("Should find a pair");
+            let len = left[l].len() + 1 + right[r].len();
+            assert!((5..=6).contains(&len), "length {} out of window", len);
+            seen.insert((l, r));
+        }
+        assert_eq!(seen, [(0, 1), (1, 0)].into());
+        assert_eq!(window.count(1), 2);
+        assert_eq!(window.count(3), 1);
+        assert_eq!(window.count(7), 0);
+
+        let mut restricted = window.clone();
+        restricted.restrict(Some(&[1]), None);
+        assert_eq!(restricted.pick(&mut rng, 1), Some((1, 0)));
+        restricted.restrict(None, None);
+        assert_eq!(restricted, window);
+    }
+
+    #[test]
+    fn returns_none_when_nothing_fits() {
+        let window = LengthWindow::new(
+            20..=30,
+            &WordList::Static(&["a"]),
+            &WordList::Static(&["b"]),
+        );
+        assert_eq!(window.pick(&mut crate::rng::default_rng(), 1), None);
+    }
+}
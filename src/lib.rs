@@ -1,11 +1,195 @@
-use rand::Rng;
+#[cfg(all(test, feature = "macros"))]
+extern crate self as mnemonic_generator;
+
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+#[cfg(any(feature = "default-words", feature = "mini-words"))]
+use std::sync::OnceLock;
+
+use rand::RngCore;
 use thiserror::Error;
 
+/// Emits a `tracing` event when the `tracing` feature is enabled, and expands
+/// to nothing otherwise.
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        tracing::$level!($($arg)+);
+    };
+}
+
+mod alphabet;
+mod ascii;
+mod batch;
+mod batch_stats;
+mod bloom;
+mod branch;
+mod builder;
+mod byte_words;
+mod categories;
+mod checksum;
+#[cfg(feature = "clap")]
+mod cli;
+mod color;
+#[cfg(any(feature = "sqlx", feature = "diesel"))]
+mod db;
+mod distance;
+mod draining;
+#[cfg(feature = "getrandom")]
+mod entropy;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fingerprint;
+#[cfg(feature = "scientists")]
+mod full_name;
+mod grammar;
+mod history;
+mod ip;
+mod lang;
+mod leading_letter;
+mod length;
+mod lint;
+mod locale;
+mod middle;
+#[cfg(all(feature = "mini-words", not(feature = "default-words")))]
+mod mini_words;
+mod mnemonic;
+mod name_generator;
+mod named;
+mod namespace;
+mod non_empty;
+mod observer;
+mod order;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod passphrase;
+mod pool;
+mod predicate;
+mod profanity;
+mod profile;
+mod pronounce;
+mod radix;
+#[cfg(feature = "tokio")]
+mod rate_limit;
+#[cfg(feature = "redis")]
+mod redis_registry;
+#[cfg(feature = "regex")]
+mod regex_filter;
+mod registry;
+#[cfg(feature = "http")]
+mod remote;
+#[cfg(any(feature = "axum", feature = "actix"))]
+mod request_name;
+mod rng;
+mod sample;
+#[cfg(feature = "scientists")]
+mod scientists;
+mod score;
+mod seeded;
+mod selection;
+mod separator;
+mod shard;
+mod short_code;
+mod side_length;
+mod state;
+mod store;
+#[cfg(feature = "futures")]
+mod stream;
+mod suggest;
+mod syllables;
+pub mod testing;
+mod theme;
+mod timestamp;
+mod transform;
+mod ulid;
+mod union;
+mod username;
+#[cfg(feature = "default-words")]
+mod version;
+mod vocabulary;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod word_filter;
+mod word_list;
+#[cfg(any(feature = "adjectives", feature = "scientists"))]
+mod words;
+mod write;
+
+pub use alphabet::SuffixAlphabet;
+pub use ascii::AsciiPolicy;
+pub use batch::BatchFormat;
+pub use batch_stats::BatchStats;
+pub use bloom::BloomFilter;
+pub use builder::{Missing, MnemonicGeneratorBuilder, Provided};
+pub use categories::CategoryGenerator;
+pub use checksum::Checksum;
+#[cfg(feature = "clap")]
+pub use cli::MnemonicValueParser;
+pub use distance::similarity;
+pub use draining::DrainingGenerator;
+pub use grammar::{Grammar, PartOfSpeech, Pattern};
+pub use history::HistoryGenerator;
+use leading_letter::LeadingLetters;
+use length::LengthWindow;
+pub use lint::{Lint, ListName, WordSide};
+pub use locale::{Locale, LocaleRegistry};
+use middle::MiddleWords;
+pub use mnemonic::Mnemonic;
+#[cfg(feature = "macros")]
+pub use mnemonic_generator_macros::{mnemonic, MnemonicNamed};
+pub use name_generator::NameGenerator;
+#[cfg(feature = "default-words")]
+pub use named::name_for;
+pub use named::Named;
+pub use non_empty::NonEmptyMnemonicGenerator;
+pub use observer::GenerationObserver;
+pub use passphrase::Passphrase;
+pub use pool::{GeneratorPool, PooledGenerator};
+pub use profanity::ProfanityFilter;
+pub use profile::{NamingProfile, ProfileViolation};
+use pronounce::PronounceableWords;
+#[cfg(feature = "tokio")]
+pub use rate_limit::RateLimitedGenerator;
+#[cfg(feature = "redis")]
+pub use redis_registry::RedisNameRegistry;
+#[cfg(feature = "regex")]
+pub use regex_filter::WordPattern;
+pub use registry::{AsyncNameRegistry, NameRegistry};
+#[cfg(any(feature = "axum", feature = "actix"))]
+pub use request_name::RequestName;
+#[cfg(feature = "scientists")]
+pub use scientists::{scientist_info, PersonInfo};
+pub use seeded::{SeededGenerator, SeededState};
+pub use selection::{RoundRobin, SelectionStrategy, Sequential, UniformRandom};
+use shard::Shard;
+use side_length::SideLengths;
+pub use store::UsedNameStore;
+use syllables::SyllableLimit;
+pub use theme::Theme;
+use timestamp::TimestampWords;
+pub use transform::Transform;
+pub use ulid::UlidMnemonic;
+pub use username::UsernameOptions;
+#[cfg(feature = "default-words")]
+pub use version::WordlistVersion;
+use word_filter::AllowedWords;
+pub use word_filter::{WordFilter, WordLength};
+use word_list::{WordList, WordLists};
+pub use write::MnemonicDisplay;
+
+/// Maximum number of candidates drawn before giving up on finding one that
+/// passes every configured filter.
+const MAX_FILTER_ATTEMPTS: usize = 1_000;
+
 /// A generator for creating memorable word combinations from predefined or custom word lists.
 ///
 /// # Examples
 ///
 /// ```
+/// # #[cfg(feature = "default-words")]
+/// # {
 /// use mnemonic_generator::MnemonicGenerator;
 ///
 /// // Use the default generator
@@ -15,23 +199,122 @@ use thiserror::Error;
 ///
 /// // Create a generator with custom words
 /// let custom_generator = MnemonicGenerator::with_words(
-///     vec!["amazing".to_string(), "friendly".to_string(), "epic".to_string()],
-///     vec!["einstein".to_string(), "galileo".to_string()]
+///     ["amazing", "friendly", "epic"],
+///     ["einstein", "galileo"]
 /// );
 /// let custom_mnemonic = custom_generator.generate_with_separator("-")
 ///     .expect("Failed to generate custom mnemonic");
 /// println!("Custom mnemonic: {}", custom_mnemonic);
+/// # }
 /// ```
+///
+/// # Thread Safety
+///
+/// A generator holds no mutable state: every call draws from the calling
+/// thread's random number generator, and transforms and selection strategies
+/// must be `Send + Sync`. It is therefore `Send + Sync` itself, and one
+/// instance behind an `Arc` can serve every thread of a server; see
+/// [`MnemonicGenerator::into_shared`].
+#[derive(Clone)]
 pub struct MnemonicGenerator {
-    left_words: Vec<String>,
-    right_words: Vec<String>,
+    words: Arc<WordLists>,
+    profanity_filter: Option<ProfanityFilter>,
+    profile: Option<NamingProfile>,
+    length_window: Option<LengthWindow>,
+    side_lengths: Option<SideLengths>,
+    leading_letters: Option<LeadingLetters>,
+    shard: Option<Shard>,
+    syllable_limit: Option<SyllableLimit>,
+    pronounceable: Option<PronounceableWords>,
+    allowed_words: Option<AllowedWords>,
+    reversed: bool,
+    middle: Option<MiddleWords>,
+    separator: Option<String>,
+    prefix: String,
+    suffix: String,
+    checksum: Option<Checksum>,
+    timestamp: Option<TimestampWords>,
+    transforms: Vec<Arc<dyn Transform>>,
+    selection: Option<Arc<dyn SelectionStrategy>>,
+    observer: Option<Arc<dyn GenerationObserver>>,
+    #[cfg(feature = "regex")]
+    regex: Option<regex_lite::Regex>,
 }
 
 /// Errors that can occur during mnemonic generation
+///
+/// Errors caused by an underlying failure, such as [`MnemonicError::Io`] or
+/// [`MnemonicError::Fetch`], expose it through [`std::error::Error::source`].
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum MnemonicError {
-    #[error("No words available for generation")]
-    EmptyWordList,
+    #[error("The {list} word list is empty")]
+    EmptyList { list: ListName },
+    #[error("Every candidate was rejected by the configured filters")]
+    AllCandidatesRejected,
+    #[error("No available name found after {attempts} attempts")]
+    NoAvailableName { attempts: usize },
+    #[error("No name was accepted within {attempts} attempts")]
+    MaxAttemptsExceeded { attempts: usize },
+    #[error("Rate limit reached, retry after {retry_after:?}")]
+    RateLimited { retry_after: std::time::Duration },
+    #[error("Every one of the {issued} names in the namespace has been issued")]
+    NamespaceExhausted { issued: u128 },
+    #[error("Invalid pattern: {0}")]
+    InvalidPattern(String),
+    #[error("Unknown category {0:?}")]
+    UnknownCategory(String),
+    #[error("No word lists registered for locale {0:?}")]
+    UnsupportedLocale(String),
+    #[error("Word lists must not contain empty words")]
+    EmptyWord,
+    #[error("Word {0:?} contains whitespace")]
+    WhitespaceInWord(String),
+    #[error("Word {0:?} contains non-ASCII characters, which are rejected")]
+    NonAsciiWord(String),
+    #[error("Word {word:?} contains {character:?}, which has no ASCII transliteration")]
+    Untransliterable { word: String, character: char },
+    #[error("Invalid separator {separator:?}: {reason}")]
+    InvalidSeparator { separator: String, reason: String },
+    #[error("{name:?} is not a valid mnemonic: {reason} at byte {position}")]
+    InvalidMnemonic {
+        name: String,
+        position: usize,
+        reason: String,
+    },
+    #[error("Checksum of {0:?} does not match its words")]
+    ChecksumMismatch(String),
+    #[error("Rank {rank} is out of range for a namespace of {count} names")]
+    RankOutOfRange { rank: u64, count: u64 },
+    #[error("At least {needed} words are required, but only {available} are available")]
+    InsufficientWords { needed: usize, available: usize },
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[cfg(feature = "getrandom")]
+    #[error("Failed to gather entropy from the operating system")]
+    Entropy(#[from] getrandom::Error),
+    #[error("Failed to write mnemonic")]
+    Fmt(#[from] std::fmt::Error),
+    #[cfg(feature = "redis")]
+    #[error("Redis error: {0}")]
+    Redis(#[from] redis::RedisError),
+    #[cfg(feature = "regex")]
+    #[error("Invalid regular expression: {0}")]
+    Regex(#[from] regex_lite::Error),
+    #[error("Timed out fetching word list from {url}")]
+    FetchTimeout { url: String },
+    #[error("Failed to fetch word list from {url}")]
+    Fetch {
+        url: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    #[error("Word list at {url} is invalid: {reason}")]
+    InvalidWordList { url: String, reason: String },
+    #[error("Saved state is invalid at line {line}: {reason}")]
+    InvalidState { line: usize, reason: String },
 }
 
 /// Creates a new `MnemonicGenerator` with a default set of words.
@@ -39,642 +322,315 @@ pub enum MnemonicError {
 /// # Examples
 ///
 /// ```
+/// # #[cfg(feature = "default-words")]
+/// # {
 /// use mnemonic_generator::MnemonicGenerator;
 ///
 /// let generator = MnemonicGenerator::new();
 /// let mnemonic = generator.generate().expect("Failed to generate mnemonic");
+/// # }
 /// ```
 impl MnemonicGenerator {
     /// Create a new MnemonicGenerator with default words
+    ///
+    /// The default lists are static data shared by every default generator, so
+    /// this does not copy any words. Requires the `default-words` feature, or
+    /// the `mini-words` feature for sixteen words per side.
+    ///
+    /// The lists follow [`WordlistVersion::LATEST`] and may grow in future
+    /// releases; use [`MnemonicGenerator::new_versioned`] to pin a version.
+    #[cfg(any(feature = "default-words", feature = "mini-words"))]
     pub fn new() -> Self {
-        Self {
-            // https://github.com/moby/moby/blob/39f7b2b6d0156811d9683c6cb0743118ae516a11/pkg/namesgenerator/names-generator.go#L21-L128
-            left_words: vec![
-                "admiring".to_string(),
-                "adoring".to_string(),
-                "affectionate".to_string(),
-                "agitated".to_string(),
-                "amazing".to_string(),
-                "angry".to_string(),
-                "awesome".to_string(),
-                "beautiful".to_string(),
-                "blissful".to_string(),
-                "bold".to_string(),
-                "boring".to_string(),
-                "brave".to_string(),
-                "busy".to_string(),
-                "charming".to_string(),
-                "clever".to_string(),
-                "cool".to_string(),
-                "compassionate".to_string(),
-                "competent".to_string(),
-                "condescending".to_string(),
-                "confident".to_string(),
-                "cranky".to_string(),
-                "crazy".to_string(),
-                "dazzling".to_string(),
-                "determined".to_string(),
-                "distracted".to_string(),
-                "dreamy".to_string(),
-                "eager".to_string(),
-                "ecstatic".to_string(),
-                "elastic".to_string(),
-                "elated".to_string(),
-                "elegant".to_string(),
-                "eloquent".to_string(),
-                "epic".to_string(),
-                "exciting".to_string(),
-                "fervent".to_string(),
-                "festive".to_string(),
-                "flamboyant".to_string(),
-                "focused".to_string(),
-                "friendly".to_string(),
-                "frosty".to_string(),
-                "funny".to_string(),
-                "gallant".to_string(),
-                "gifted".to_string(),
-                "goofy".to_string(),
-                "gracious".to_string(),
-                "great".to_string(),
-                "happy".to_string(),
-                "hardcore".to_string(),
-                "heuristic".to_string(),
-                "hopeful".to_string(),
-                "hungry".to_string(),
-                "infallible".to_string(),
-                "inspiring".to_string(),
-                "intelligent".to_string(),
-                "interesting".to_string(),
-                "jolly".to_string(),
-                "jovial".to_string(),
-                "keen".to_string(),
-                "kind".to_string(),
-                "laughing".to_string(),
-                "loving".to_string(),
-                "lucid".to_string(),
-                "magical".to_string(),
-                "mystifying".to_string(),
-                "modest".to_string(),
-                "musing".to_string(),
-                "naughty".to_string(),
-                "nervous".to_string(),
-                "nice".to_string(),
-                "nifty".to_string(),
-                "nostalgic".to_string(),
-                "objective".to_string(),
-                "optimistic".to_string(),
-                "peaceful".to_string(),
-                "pedantic".to_string(),
-                "pensive".to_string(),
-                "practical".to_string(),
-                "priceless".to_string(),
-                "quirky".to_string(),
-                "quizzical".to_string(),
-                "recursing".to_string(),
-                "relaxed".to_string(),
-                "reverent".to_string(),
-                "romantic".to_string(),
-                "sad".to_string(),
-                "serene".to_string(),
-                "sharp".to_string(),
-                "silly".to_string(),
-                "sleepy".to_string(),
-                "stoic".to_string(),
-                "strange".to_string(),
-                "stupefied".to_string(),
-                "suspicious".to_string(),
-                "sweet".to_string(),
-                "tender".to_string(),
-                "thirsty".to_string(),
-                "trusting".to_string(),
-                "unruffled".to_string(),
-                "upbeat".to_string(),
-                "vibrant".to_string(),
-                "vigilant".to_string(),
-                "vigorous".to_string(),
-                "wizardly".to_string(),
-                "wonderful".to_string(),
-                "xenodochial".to_string(),
-                "youthful".to_string(),
-                "zealous".to_string(),
-                "zen".to_string(),
-            ],
-            // https://github.com/moby/moby/blob/39f7b2b6d0156811d9683c6cb0743118ae516a11/pkg/namesgenerator/names-generator.go#L135-L845
-            right_words: vec![
-                // Maria Gaetana Agnesi - Italian mathematician, philosopher, theologian and humanitarian. She was the first woman to write a mathematics handbook and the first woman appointed as a Mathematics Professor at a University. https://en.wikipedia.org/wiki/Maria_Gaetana_Agnesi
-                "agnesi".to_string(),
-                // Muhammad ibn Jābir al-Ḥarrānī al-Battānī was a founding father of astronomy. https://en.wikipedia.org/wiki/Mu%E1%B8%A5ammad_ibn_J%C4%81bir_al-%E1%B8%A4arr%C4%81n%C4%AB_al-Batt%C4%81n%C4%AB
-                "albattani".to_string(),
-                // Frances E. Allen, became the first female IBM Fellow in 1989. In 2006, she became the first female recipient of the ACM's Turing Award. https://en.wikipedia.org/wiki/Frances_E._Allen
-                "allen".to_string(),
-                // June Almeida - Scottish virologist who took the first pictures of the rubella virus - https://en.wikipedia.org/wiki/June_Almeida
-                "almeida".to_string(),
-                // Kathleen Antonelli, American computer programmer and one of the six original programmers of the ENIAC - https://en.wikipedia.org/wiki/Kathleen_Antonelli
-                "antonelli".to_string(),
-                // Archimedes was a physicist, engineer and mathematician who invented too many things to list them here. https://en.wikipedia.org/wiki/Archimedes
-                "archimedes".to_string(),
-                // Maria Ardinghelli - Italian translator, mathematician and physicist - https://en.wikipedia.org/wiki/Maria_Ardinghelli
-                "ardinghelli".to_string(),
-                // Aryabhata - Ancient Indian mathematician-astronomer during 476-550 CE https://en.wikipedia.org/wiki/Aryabhata
-                "aryabhata".to_string(),
-                // Wanda Austin - Wanda Austin is the President and CEO of The Aerospace Corporation, a leading architect for the US security space programs. https://en.wikipedia.org/wiki/Wanda_Austin
-                "austin".to_string(),
-                // Charles Babbage invented the concept of a programmable computer. https://en.wikipedia.org/wiki/Charles_Babbage.
-                "babbage".to_string(),
-                // Stefan Banach - Polish mathematician, was one of the founders of modern functional analysis. https://en.wikipedia.org/wiki/Stefan_Banach
-                "banach".to_string(),
-                // Buckaroo Banzai and his mentor Dr. Hikita perfected the "oscillation overthruster", a device that allows one to pass through solid matter. - https://en.wikipedia.org/wiki/The_Adventures_of_Buckaroo_Banzai_Across_the_8th_Dimension
-                "banzai".to_string(),
-                // John Bardeen co-invented the transistor - https://en.wikipedia.org/wiki/John_Bardeen
-                "bardeen".to_string(),
-                // Jean Bartik, born Betty Jean Jennings, was one of the original programmers for the ENIAC computer. https://en.wikipedia.org/wiki/Jean_Bartik
-                "bartik".to_string(),
-                // Laura Bassi, the world's first female professor https://en.wikipedia.org/wiki/Laura_Bassi
-                "bassi".to_string(),
-                // Hugh Beaver, British engineer, founder of the Guinness Book of World Records https://en.wikipedia.org/wiki/Hugh_Beaver
-                "beaver".to_string(),
-                // Alexander Graham Bell - an eminent Scottish-born scientist, inventor, engineer and innovator who is credited with inventing the first practical telephone - https://en.wikipedia.org/wiki/Alexander_Graham_Bell
-                "bell".to_string(),
-                // Karl Friedrich Benz - a German automobile engineer. Inventor of the first practical motorcar. https://en.wikipedia.org/wiki/Karl_Benz
-                "benz".to_string(),
-                // Homi J Bhabha - was an Indian nuclear physicist, founding director, and professor of physics at the Tata Institute of Fundamental Research. Colloquially known as "father of Indian nuclear programme"- https://en.wikipedia.org/wiki/Homi_J._Bhabha
-                "bhabha".to_string(),
-                // Bhaskara II - Ancient Indian mathematician-astronomer whose work on calculus predates Newton and Leibniz by over half a millennium - https://en.wikipedia.org/wiki/Bh%C4%81skara_II#Calculus
-                "bhaskara".to_string(),
-                // Sue Black - British computer scientist and campaigner. She has been instrumental in saving Bletchley Park, the site of World War II codebreaking - https://en.wikipedia.org/wiki/Sue_Black_(computer_scientist)
-                "black".to_string(),
-                // Elizabeth Helen Blackburn - Australian-American Nobel laureate; best known for co-discovering telomerase. https://en.wikipedia.org/wiki/Elizabeth_Blackburn
-                "blackburn".to_string(),
-                // Elizabeth Blackwell - American doctor and first American woman to receive a medical degree - https://en.wikipedia.org/wiki/Elizabeth_Blackwell
-                "blackwell".to_string(),
-                // Niels Bohr is the father of quantum theory. https://en.wikipedia.org/wiki/Niels_Bohr.
-                "bohr".to_string(),
-                // Kathleen Booth, she's credited with writing the first assembly language. https://en.wikipedia.org/wiki/Kathleen_Booth
-                "booth".to_string(),
-                // Anita Borg - Anita Borg was the founding director of the Institute for Women and Technology (IWT). https://en.wikipedia.org/wiki/Anita_Borg
-                "borg".to_string(),
-                // Satyendra Nath Bose - He provided the foundation for Bose–Einstein statistics and the theory of the Bose–Einstein condensate. - https://en.wikipedia.org/wiki/Satyendra_Nath_Bose
-                "bose".to_string(),
-                // Katherine Louise Bouman is an imaging scientist and Assistant Professor of Computer Science at the California Institute of Technology. She researches computational methods for imaging, and developed an algorithm that made possible the picture first visualization of a black hole using the Event Horizon Telescope. - https://en.wikipedia.org/wiki/Katie_Bouman
-                "bouman".to_string(),
-                // Evelyn Boyd Granville - She was one of the first African-American woman to receive a Ph.D. in mathematics; she earned it in 1949 from Yale University. https://en.wikipedia.org/wiki/Evelyn_Boyd_Granville
-                "boyd".to_string(),
-                // Brahmagupta - Ancient Indian mathematician during 598-670 CE who gave rules to compute with zero - https://en.wikipedia.org/wiki/Brahmagupta#Zero
-                "brahmagupta".to_string(),
-                // Walter Houser Brattain co-invented the transistor - https://en.wikipedia.org/wiki/Walter_Houser_Brattain
-                "brattain".to_string(),
-                // Emmett Brown invented time travel. https://en.wikipedia.org/wiki/Emmett_Brown (thanks Brian Goff)
-                "brown".to_string(),
-                // Linda Brown Buck - American biologist and Nobel laureate best known for her genetic and molecular analyses of the mechanisms of smell. https://en.wikipedia.org/wiki/Linda_B._Buck
-                "buck".to_string(),
-                // Dame Susan Jocelyn Bell Burnell - Northern Irish astrophysicist who discovered radio pulsars and was the first to analyse them. https://en.wikipedia.org/wiki/Jocelyn_Bell_Burnell
-                "burnell".to_string(),
-                // Annie Jump Cannon - pioneering female astronomer who classified hundreds of thousands of stars and created the system we use to understand stars today. https://en.wikipedia.org/wiki/Annie_Jump_Cannon
-                "cannon".to_string(),
-                // Rachel Carson - American marine biologist and conservationist, her book Silent Spring and other writings are credited with advancing the global environmental movement. https://en.wikipedia.org/wiki/Rachel_Carson
-                "carson".to_string(),
-                // Dame Mary Lucy Cartwright - British mathematician who was one of the first to study what is now known as chaos theory. Also known for Cartwright's theorem which finds applications in signal processing. https://en.wikipedia.org/wiki/Mary_Cartwright
-                "cartwright".to_string(),
-                // George Washington Carver - American agricultural scientist and inventor. He was the most prominent black scientist of the early 20th century. https://en.wikipedia.org/wiki/George_Washington_Carver
-                "carver".to_string(),
-                // Vinton Gray Cerf - American Internet pioneer, recognised as one of "the fathers of the Internet". With Robert Elliot Kahn, he designed TCP and IP, the primary data communication protocols of the Internet and other computer networks. https://en.wikipedia.org/wiki/Vint_Cerf
-                "cerf".to_string(),
-                // Subrahmanyan Chandrasekhar - Astrophysicist known for his mathematical theory on different stages and evolution in structures of the stars. He has won nobel prize for physics - https://en.wikipedia.org/wiki/Subrahmanyan_Chandrasekhar
-                "chandrasekhar".to_string(),
-                // Sergey Alexeyevich Chaplygin (Russian: Серге́й Алексе́евич Чаплы́гин; April 5, 1869 – October 8, 1942) was a Russian and Soviet physicist, mathematician, and mechanical engineer. He is known for mathematical formulas such as Chaplygin's equation and for a hypothetical substance in cosmology called Chaplygin gas, named after him. https://en.wikipedia.org/wiki/Sergey_Chaplygin
-                "chaplygin".to_string(),
-                // Émilie du Châtelet - French natural philosopher, mathematician, physicist, and author during the early 1730s, known for her translation of and commentary on Isaac Newton's book Principia containing basic laws of physics. https://en.wikipedia.org/wiki/%C3%89milie_du_Ch%C3%A2telet
-                "chatelet".to_string(),
-                // Asima Chatterjee was an Indian organic chemist noted for her research on vinca alkaloids, development of drugs for treatment of epilepsy and malaria - https://en.wikipedia.org/wiki/Asima_Chatterjee
-                "chatterjee".to_string(),
-                // David Lee Chaum - American computer scientist and cryptographer. Known for his seminal contributions in the field of anonymous communication. https://en.wikipedia.org/wiki/David_Chaum
-                "chaum".to_string(),
-                // Pafnuty Chebyshev - Russian mathematician. He is known fo his works on probability, statistics, mechanics, analytical geometry and number theory https://en.wikipedia.org/wiki/Pafnuty_Chebyshev
-                "chebyshev".to_string(),
-                // Joan Clarke - Bletchley Park code breaker during the Second World War who pioneered techniques that remained top secret for decades. Also an accomplished numismatist https://en.wikipedia.org/wiki/Joan_Clarke
-                "clarke".to_string(),
-                // Bram Cohen - American computer programmer and author of the BitTorrent peer-to-peer protocol. https://en.wikipedia.org/wiki/Bram_Cohen
-                "cohen".to_string(),
-                // Jane Colden - American botanist widely considered the first female American botanist - https://en.wikipedia.org/wiki/Jane_Colden
-                "colden".to_string(),
-                // Gerty Theresa Cori - American biochemist who became the third woman—and first American woman—to win a Nobel Prize in science, and the first woman to be awarded the Nobel Prize in Physiology or Medicine. Cori was born in Prague. https://en.wikipedia.org/wiki/Gerty_Cori
-                "cori".to_string(),
-                // Seymour Roger Cray was an American electrical engineer and supercomputer architect who designed a series of computers that were the fastest in the world for decades. https://en.wikipedia.org/wiki/Seymour_Cray
-                "cray".to_string(),
-                // This entry reflects a husband and wife team who worked together:
-                // Joan Curran was a Welsh scientist who developed radar and invented chaff, a radar countermeasure. https://en.wikipedia.org/wiki/Joan_Curran
-                // Samuel Curran was an Irish physicist who worked alongside his wife during WWII and invented the proximity fuse. https://en.wikipedia.org/wiki/Samuel_Curran
-                "curran".to_string(),
-                // Marie Curie discovered radioactivity. https://en.wikipedia.org/wiki/Marie_Curie.
-                "curie".to_string(),
-                // Charles Darwin established the principles of natural evolution. https://en.wikipedia.org/wiki/Charles_Darwin.
-                "darwin".to_string(),
-                // Leonardo Da Vinci invented too many things to list here. https://en.wikipedia.org/wiki/Leonardo_da_Vinci.
-                "davinci".to_string(),
-                // A. K. (Alexander Keewatin) Dewdney, Canadian mathematician, computer scientist, author and filmmaker. Contributor to Scientific American's "Computer Recreations" from 1984 to 1991. Author of Core War (program), The Planiverse, The Armchair Universe, The Magic Machine, The New Turing Omnibus, and more. https://en.wikipedia.org/wiki/Alexander_Dewdney
-                "dewdney".to_string(),
-                // Satish Dhawan - Indian mathematician and aerospace engineer, known for leading the successful and indigenous development of the Indian space programme. https://en.wikipedia.org/wiki/Satish_Dhawan
-                "dhawan".to_string(),
-                // Bailey Whitfield Diffie - American cryptographer and one of the pioneers of public-key cryptography. https://en.wikipedia.org/wiki/Whitfield_Diffie
-                "diffie".to_string(),
-                // Edsger Wybe Dijkstra was a Dutch computer scientist and mathematical scientist. https://en.wikipedia.org/wiki/Edsger_W._Dijkstra.
-                "dijkstra".to_string(),
-                // Paul Adrien Maurice Dirac - English theoretical physicist who made fundamental contributions to the early development of both quantum mechanics and quantum electrodynamics. https://en.wikipedia.org/wiki/Paul_Dirac
-                "dirac".to_string(),
-                // Agnes Meyer Driscoll - American cryptanalyst during World Wars I and II who successfully cryptanalysed a number of Japanese ciphers. She was also the co-developer of one of the cipher machines of the US Navy, the CM. https://en.wikipedia.org/wiki/Agnes_Meyer_Driscoll
-                "driscoll".to_string(),
-                // Donna Dubinsky - played an integral role in the development of personal digital assistants (PDAs) serving as CEO of Palm, Inc. and co-founding Handspring. https://en.wikipedia.org/wiki/Donna_Dubinsky
-                "dubinsky".to_string(),
-                // Annie Easley - She was a leading member of the team which developed software for the Centaur rocket stage and one of the first African-Americans in her field. https://en.wikipedia.org/wiki/Annie_Easley
-                "easley".to_string(),
-                // Thomas Alva Edison, prolific inventor https://en.wikipedia.org/wiki/Thomas_Edison
-                "edison".to_string(),
-                // Albert Einstein invented the general theory of relativity. https://en.wikipedia.org/wiki/Albert_Einstein
-                "einstein".to_string(),
-                // Alexandra Asanovna Elbakyan (Russian: Алекса́ндра Аса́новна Элбакя́н) is a Kazakhstani graduate student, computer programmer, internet pirate in hiding, and the creator of the site Sci-Hub. Nature has listed her in 2016 in the top ten people that mattered in science, and Ars Technica has compared her to Aaron Swartz. - https://en.wikipedia.org/wiki/Alexandra_Elbakyan
-                "elbakyan".to_string(),
-                // Taher A. ElGamal - Egyptian cryptographer best known for the ElGamal discrete log cryptosystem and the ElGamal digital signature scheme. https://en.wikipedia.org/wiki/Taher_Elgamal
-                "elgamal".to_string(),
-                // Gertrude Elion - American biochemist, pharmacologist and the 1988 recipient of the Nobel Prize in Medicine - https://en.wikipedia.org/wiki/Gertrude_Elion
-                "elion".to_string(),
-                // James Henry Ellis - British engineer and cryptographer employed by the GCHQ. Best known for conceiving for the first time, the idea of public-key cryptography. https://en.wikipedia.org/wiki/James_H._Ellis
-                "ellis".to_string(),
-                // Douglas Engelbart gave the mother of all demos: https://en.wikipedia.org/wiki/Douglas_Engelbart
-                "engelbart".to_string(),
-                // Euclid invented geometry. https://en.wikipedia.org/wiki/Euclid
-                "euclid".to_string(),
-                // Leonhard Euler invented large parts of modern mathematics. https://de.wikipedia.org/wiki/Leonhard_Euler
-                "euler".to_string(),
-                // Michael Faraday - British scientist who contributed to the study of electromagnetism and electrochemistry. https://en.wikipedia.org/wiki/Michael_Faraday
-                "faraday".to_string(),
-                // Horst Feistel - German-born American cryptographer who was one of the earliest non-government researchers to study the design and theory of block ciphers. Co-developer of DES and Lucifer. Feistel networks, a symmetric structure used in the construction of block ciphers are named after him. https://en.wikipedia.org/wiki/Horst_Feistel
-                "feistel".to_string(),
-                // Pierre de Fermat pioneered several aspects of modern mathematics. https://en.wikipedia.org/wiki/Pierre_de_Fermat
-                "fermat".to_string(),
-                // Enrico Fermi invented the first nuclear reactor. https://en.wikipedia.org/wiki/Enrico_Fermi.
-                "fermi".to_string(),
-                // Richard Feynman was a key contributor to quantum mechanics and particle physics. https://en.wikipedia.org/wiki/Richard_Feynman
-                "feynman".to_string(),
-                // Benjamin Franklin is famous for his experiments in electricity and the invention of the lightning rod.
-                "franklin".to_string(),
-                // Yuri Alekseyevich Gagarin - Soviet pilot and cosmonaut, best known as the first human to journey into outer space. https://en.wikipedia.org/wiki/Yuri_Gagarin
-                "gagarin".to_string(),
-                // Galileo was a founding father of modern astronomy, and faced politics and obscurantism to establish scientific truth.  https://en.wikipedia.org/wiki/Galileo_Galilei
-                "galileo".to_string(),
-                // Évariste Galois - French mathematician whose work laid the foundations of Galois theory and group theory, two major branches of abstract algebra, and the subfield of Galois connections, all while still in his late teens. https://en.wikipedia.org/wiki/%C3%89variste_Galois
-                "galois".to_string(),
-                // Kadambini Ganguly - Indian physician, known for being the first South Asian female physician, trained in western medicine, to graduate in South Asia. https://en.wikipedia.org/wiki/Kadambini_Ganguly
-                "ganguly".to_string(),
-                // William Henry "Bill" Gates III is an American business magnate, philanthropist, investor, computer programmer, and inventor. https://en.wikipedia.org/wiki/Bill_Gates
-                "gates".to_string(),
-                // Johann Carl Friedrich Gauss - German mathematician who made significant contributions to many fields, including number theory, algebra, statistics, analysis, differential geometry, geodesy, geophysics, mechanics, electrostatics, magnetic fields, astronomy, matrix theory, and optics. https://en.wikipedia.org/wiki/Carl_Friedrich_Gauss
-                "gauss".to_string(),
-                // Marie-Sophie Germain - French mathematician, physicist and philosopher. Known for her work on elasticity theory, number theory and philosophy. https://en.wikipedia.org/wiki/Sophie_Germain
-                "germain".to_string(),
-                // Adele Goldberg, was one of the designers and developers of the Smalltalk language. https://en.wikipedia.org/wiki/Adele_Goldberg_(computer_scientist)
-                "goldberg".to_string(),
-                // Adele Goldstine, born Adele Katz, wrote the complete technical description for the first electronic digital computer, ENIAC. https://en.wikipedia.org/wiki/Adele_Goldstine
-                "goldstine".to_string(),
-                // Shafi Goldwasser is a computer scientist known for creating theoretical foundations of modern cryptography. Winner of 2012 ACM Turing Award. https://en.wikipedia.org/wiki/Shafi_Goldwasser
-                "goldwasser".to_string(),
-                // James Golick, all around gangster.
-                "golick".to_string(),
-                // Jane Goodall - British primatologist, ethologist, and anthropologist who is considered to be the world's foremost expert on chimpanzees - https://en.wikipedia.org/wiki/Jane_Goodall
-                "goodall".to_string(),
-                // Stephen Jay Gould was was an American paleontologist, evolutionary biologist, and historian of science. He is most famous for the theory of punctuated equilibrium - https://en.wikipedia.org/wiki/Stephen_Jay_Gould
-                "gould".to_string(),
-                // Carolyn Widney Greider - American molecular biologist and joint winner of the 2009 Nobel Prize for Physiology or Medicine for the discovery of telomerase. https://en.wikipedia.org/wiki/Carol_W._Greider
-                "greider".to_string(),
-                // Alexander Grothendieck - German-born French mathematician who became a leading figure in the creation of modern algebraic geometry. https://en.wikipedia.org/wiki/Alexander_Grothendieck
-                "grothendieck".to_string(),
-                // Lois Haibt - American computer scientist, part of the team at IBM that developed FORTRAN - https://en.wikipedia.org/wiki/Lois_Haibt
-                "haibt".to_string(),
-                // Margaret Hamilton - Director of the Software Engineering Division of the MIT Instrumentation Laboratory, which developed on-board flight software for the Apollo space program. https://en.wikipedia.org/wiki/Margaret_Hamilton_(scientist)
-                "hamilton".to_string(),
-                // Caroline Harriet Haslett - English electrical engineer, electricity industry administrator and champion of women's rights. Co-author of British Standard 1363 that specifies AC power plugs and sockets used across the United Kingdom (which is widely considered as one of the safest designs). https://en.wikipedia.org/wiki/Caroline_Haslett
-                "haslett".to_string(),
-                // Stephen Hawking pioneered the field of cosmology by combining general relativity and quantum mechanics. https://en.wikipedia.org/wiki/Stephen_Hawking
-                "hawking".to_string(),
-                // Martin Edward Hellman - American cryptologist, best known for his invention of public-key cryptography in co-operation with Whitfield Diffie and Ralph Merkle. https://en.wikipedia.org/wiki/Martin_Hellman
-                "hellman".to_string(),
-                // Werner Heisenberg was a founding father of quantum mechanics. https://en.wikipedia.org/wiki/Werner_Heisenberg
-                "heisenberg".to_string(),
-                // Grete Hermann was a German philosopher noted for her philosophical work on the foundations of quantum mechanics. https://en.wikipedia.org/wiki/Grete_Hermann
-                "hermann".to_string(),
-                // Caroline Lucretia Herschel - German astronomer and discoverer of several comets. https://en.wikipedia.org/wiki/Caroline_Herschel
-                "herschel".to_string(),
-                // Heinrich Rudolf Hertz - German physicist who first conclusively proved the existence of the electromagnetic waves. https://en.wikipedia.org/wiki/Heinrich_Hertz
-                "hertz".to_string(),
-                // Jaroslav Heyrovský was the inventor of the polarographic method, father of the electroanalytical method, and recipient of the Nobel Prize in 1959. His main field of work was polarography. https://en.wikipedia.org/wiki/Jaroslav_Heyrovsk%C3%BD
-                "heyrovsky".to_string(),
-                // Dorothy Hodgkin was a British biochemist, credited with the development of protein crystallography. She was awarded the Nobel Prize in Chemistry in 1964. https://en.wikipedia.org/wiki/Dorothy_Hodgkin
-                "hodgkin".to_string(),
-                // Douglas R. Hofstadter is an American professor of cognitive science and author of the Pulitzer Prize and American Book Award-winning work Goedel, Escher, Bach: An Eternal Golden Braid in 1979. A mind-bending work which coined Hofstadter's Law: "It always takes longer than you expect, even when you take into account Hofstadter's Law." https://en.wikipedia.org/wiki/Douglas_Hofstadter
-                "hofstadter".to_string(),
-                // Erna Schneider Hoover revolutionized modern communication by inventing a computerized telephone switching method. https://en.wikipedia.org/wiki/Erna_Schneider_Hoover
-                "hoover".to_string(),
-                // Grace Hopper developed the first compiler for a computer programming language and  is credited with popularizing the term "debugging" for fixing computer glitches. https://en.wikipedia.org/wiki/Grace_Hopper
-                "hopper".to_string(),
-                // Frances Hugle, she was an American scientist, engineer, and inventor who contributed to the understanding of semiconductors, integrated circuitry, and the unique electrical principles of microscopic materials. https://en.wikipedia.org/wiki/Frances_Hugle
-                "hugle".to_string(),
-                // Hypatia - Greek Alexandrine Neoplatonist philosopher in Egypt who was one of the earliest mothers of mathematics - https://en.wikipedia.org/wiki/Hypatia
-                "hypatia".to_string(),
-                // Teruko Ishizaka - Japanese scientist and immunologist who co-discovered the antibody class Immunoglobulin E. https://en.wikipedia.org/wiki/Teruko_Ishizaka
-                "ishizaka".to_string(),
-                // Mary Jackson, American mathematician and aerospace engineer who earned the highest title within NASA's engineering department - https://en.wikipedia.org/wiki/Mary_Jackson_(engineer)
-                "jackson".to_string(),
-                // Yeong-Sil Jang was a Korean scientist and astronomer during the Joseon Dynasty; he invented the first metal printing press and water gauge. https://en.wikipedia.org/wiki/Jang_Yeong-sil
-                "jang".to_string(),
-                // Mae Carol Jemison -  is an American engineer, physician, and former NASA astronaut. She became the first black woman to travel in space when she served as a mission specialist aboard the Space Shuttle Endeavour - https://en.wikipedia.org/wiki/Mae_Jemison
-                "jemison".to_string(),
-                // Betty Jennings - one of the original programmers of the ENIAC. https://en.wikipedia.org/wiki/ENIAC - https://en.wikipedia.org/wiki/Jean_Bartik
-                "jennings".to_string(),
-                // Mary Lou Jepsen, was the founder and chief technology officer of One Laptop Per Child (OLPC), and the founder of Pixel Qi. https://en.wikipedia.org/wiki/Mary_Lou_Jepsen
-                "jepsen".to_string(),
-                // Katherine Coleman Goble Johnson - American physicist and mathematician contributed to the NASA. https://en.wikipedia.org/wiki/Katherine_Johnson
-                "johnson".to_string(),
-                // Irène Joliot-Curie - French scientist who was awarded the Nobel Prize for Chemistry in 1935. Daughter of Marie and Pierre Curie. https://en.wikipedia.org/wiki/Ir%C3%A8ne_Joliot-Curie
-                "joliot".to_string(),
-                // Karen Spärck Jones came up with the concept of inverse document frequency, which is used in most search engines today. https://en.wikipedia.org/wiki/Karen_Sp%C3%A4rck_Jones
-                "jones".to_string(),
-                // A. P. J. Abdul Kalam - is an Indian scientist aka Missile Man of India for his work on the development of ballistic missile and launch vehicle technology - https://en.wikipedia.org/wiki/A._P._J._Abdul_Kalam
-                "kalam".to_string(),
-                // Sergey Petrovich Kapitsa (Russian: Серге́й Петро́вич Капи́ца; 14 February 1928 – 14 August 2012) was a Russian physicist and demographer. He was best known as host of the popular and long-running Russian scientific TV show, Evident, but Incredible. His father was the Nobel laureate Soviet-era physicist Pyotr Kapitsa, and his brother was the geographer and Antarctic explorer Andrey Kapitsa. - https://en.wikipedia.org/wiki/Sergey_Kapitsa
-                "kapitsa".to_string(),
-                // Susan Kare, created the icons and many of the interface elements for the original Apple Macintosh in the 1980s, and was an original employee of NeXT, working as the Creative Director. https://en.wikipedia.org/wiki/Susan_Kare
-                "kare".to_string(),
-                // Mstislav Keldysh - a Soviet scientist in the field of mathematics and mechanics, academician of the USSR Academy of Sciences (1946), President of the USSR Academy of Sciences (1961–1975), three times Hero of Socialist Labor (1956, 1961, 1971), fellow of the Royal Society of Edinburgh (1968). https://en.wikipedia.org/wiki/Mstislav_Keldysh
-                "keldysh".to_string(),
-                // Mary Kenneth Keller, Sister Mary Kenneth Keller became the first American woman to earn a PhD in Computer Science in 1965. https://en.wikipedia.org/wiki/Mary_Kenneth_Keller
-                "keller".to_string(),
-                // Johannes Kepler, German astronomer known for his three laws of planetary motion - https://en.wikipedia.org/wiki/Johannes_Kepler
-                "kepler".to_string(),
-                // Omar Khayyam - Persian mathematician, astronomer and poet. Known for his work on the classification and solution of cubic equations, for his contribution to the understanding of Euclid's fifth postulate and for computing the length of a year very accurately. https://en.wikipedia.org/wiki/Omar_Khayyam
-                "khayyam".to_string(),
-                // Har Gobind Khorana - Indian-American biochemist who shared the 1968 Nobel Prize for Physiology - https://en.wikipedia.org/wiki/Har_Gobind_Khorana
-                "khorana".to_string(),
-                // Jack Kilby invented silicon integrated circuits and gave Silicon Valley its name. - https://en.wikipedia.org/wiki/Jack_Kilby
-                "kilby".to_string(),
-                // Maria Kirch - German astronomer and first woman to discover a comet - https://en.wikipedia.org/wiki/Maria_Margarethe_Kirch
-                "kirch".to_string(),
-                // Donald Knuth - American computer scientist, author of "The Art of Computer Programming" and creator of the TeX typesetting system. https://en.wikipedia.org/wiki/Donald_Knuth
-                "knuth".to_string(),
-                // Sophie Kowalevski - Russian mathematician responsible for important original contributions to analysis, differential equations and mechanics - https://en.wikipedia.org/wiki/Sofia_Kovalevskaya
-                "kowalevski".to_string(),
-                // Marie-Jeanne de Lalande - French astronomer, mathematician and cataloguer of stars - https://en.wikipedia.org/wiki/Marie-Jeanne_de_Lalande
-                "lalande".to_string(),
-                // Hedy Lamarr - Actress and inventor. The principles of her work are now incorporated into modern Wi-Fi, CDMA and Bluetooth technology. https://en.wikipedia.org/wiki/Hedy_Lamarr
-                "lamarr".to_string(),
-                // Leslie B. Lamport - American computer scientist. Lamport is best known for his seminal work in distributed systems and was the winner of the 2013 Turing Award. https://en.wikipedia.org/wiki/Leslie_Lamport
-                "lamport".to_string(),
-                // Mary Leakey - British paleoanthropologist who discovered the first fossilized Proconsul skull - https://en.wikipedia.org/wiki/Mary_Leakey
-                "leakey".to_string(),
-                // Henrietta Swan Leavitt - she was an American astronomer who discovered the relation between the luminosity and the period of Cepheid variable stars. https://en.wikipedia.org/wiki/Henrietta_Swan_Leavitt
-                "leavitt".to_string(),
-                // Esther Miriam Zimmer Lederberg - American microbiologist and a pioneer of bacterial genetics. https://en.wikipedia.org/wiki/Esther_Lederberg
-                "lederberg".to_string(),
-                // Inge Lehmann - Danish seismologist and geophysicist. Known for discovering in 1936 that the Earth has a solid inner core inside a molten outer core. https://en.wikipedia.org/wiki/Inge_Lehmann
-                "lehmann".to_string(),
-                // Daniel Lewin - Mathematician, Akamai co-founder, soldier, 9/11 victim-- Developed optimization techniques for routing traffic on the internet. Died attempting to stop the 9-11 hijackers. https://en.wikipedia.org/wiki/Daniel_Lewin
-                "lewin".to_string(),
-                // Ruth Lichterman - one of the original programmers of the ENIAC. https://en.wikipedia.org/wiki/ENIAC - https://en.wikipedia.org/wiki/Ruth_Teitelbaum
-                "lichterman".to_string(),
-                // Barbara Liskov - co-developed the Liskov substitution principle. Liskov was also the winner of the Turing Prize in 2008. - https://en.wikipedia.org/wiki/Barbara_Liskov
-                "liskov".to_string(),
-                // Ada Lovelace invented the first algorithm. https://en.wikipedia.org/wiki/Ada_Lovelace (thanks James Turnbull)
-                "lovelace".to_string(),
-                // Auguste and Louis Lumière - the first filmmakers in history - https://en.wikipedia.org/wiki/Auguste_and_Louis_Lumi%C3%A8re
-                "lumiere".to_string(),
-                // Mahavira - Ancient Indian mathematician during 9th century AD who discovered basic algebraic identities - https://en.wikipedia.org/wiki/Mah%C4%81v%C4%ABra_(mathematician)
-                "mahavira".to_string(),
-                // Lynn Margulis (b. Lynn Petra Alexander) - an American evolutionary theorist and biologist, science author, educator, and popularizer, and was the primary modern proponent for the significance of symbiosis in evolution. - https://en.wikipedia.org/wiki/Lynn_Margulis
-                "margulis".to_string(),
-                // Yukihiro Matsumoto - Japanese computer scientist and software programmer best known as the chief designer of the Ruby programming language. https://en.wikipedia.org/wiki/Yukihiro_Matsumoto
-                "matsumoto".to_string(),
-                // James Clerk Maxwell - Scottish physicist, best known for his formulation of electromagnetic theory. https://en.wikipedia.org/wiki/James_Clerk_Maxwell
-                "maxwell".to_string(),
-                // Maria Mayer - American theoretical physicist and Nobel laureate in Physics for proposing the nuclear shell model of the atomic nucleus - https://en.wikipedia.org/wiki/Maria_Mayer
-                "mayer".to_string(),
-                // John McCarthy invented LISP: https://en.wikipedia.org/wiki/John_McCarthy_(computer_scientist)
-                "mccarthy".to_string(),
-                // Barbara McClintock - a distinguished American cytogeneticist, 1983 Nobel Laureate in Physiology or Medicine for discovering transposons. https://en.wikipedia.org/wiki/Barbara_McClintock
-                "mcclintock".to_string(),
-                // Anne Laura Dorinthea McLaren - British developmental biologist whose work helped lead to human in-vitro fertilisation. https://en.wikipedia.org/wiki/Anne_McLaren
-                "mclaren".to_string(),
-                // Malcolm McLean invented the modern shipping container: https://en.wikipedia.org/wiki/Malcom_McLean
-                "mclean".to_string(),
-                // Kay McNulty - one of the original programmers of the ENIAC. https://en.wikipedia.org/wiki/ENIAC - https://en.wikipedia.org/wiki/Kathleen_Antonelli
-                "mcnulty".to_string(),
-                // Gregor Johann Mendel - Czech scientist and founder of genetics. https://en.wikipedia.org/wiki/Gregor_Mendel
-                "mendel".to_string(),
-                // Dmitri Mendeleev - a chemist and inventor. He formulated the Periodic Law, created a farsighted version of the periodic table of elements, and used it to correct the properties of some already discovered elements and also to predict the properties of eight elements yet to be discovered. https://en.wikipedia.org/wiki/Dmitri_Mendeleev
-                "mendeleev".to_string(),
-                // Lise Meitner - Austrian/Swedish physicist who was involved in the discovery of nuclear fission. The element meitnerium is named after her - https://en.wikipedia.org/wiki/Lise_Meitner
-                "meitner".to_string(),
-                // Carla Meninsky, was the game designer and programmer for Atari 2600 games Dodge 'Em and Warlords. https://en.wikipedia.org/wiki/Carla_Meninsky
-                "meninsky".to_string(),
-                // Ralph C. Merkle - American computer scientist, known for devising Merkle's puzzles - one of the very first schemes for public-key cryptography. Also, inventor of Merkle trees and co-inventor of the Merkle-Damgård construction for building collision-resistant cryptographic hash functions and the Merkle-Hellman knapsack cryptosystem. https://en.wikipedia.org/wiki/Ralph_Merkle
-                "merkle".to_string(),
-                // Johanna Mestorf - German prehistoric archaeologist and first female museum director in Germany - https://en.wikipedia.org/wiki/Johanna_Mestorf
-                "mestorf".to_string(),
-                // Maryam Mirzakhani - an Iranian mathematician and the first woman to win the Fields Medal. https://en.wikipedia.org/wiki/Maryam_Mirzakhani
-                "mirzakhani".to_string(),
-                // Rita Levi-Montalcini - Won Nobel Prize in Physiology or Medicine jointly with colleague Stanley Cohen for the discovery of nerve growth factor (https://en.wikipedia.org/wiki/Rita_Levi-Montalcini)
-                "montalcini".to_string(),
-                // Gordon Earle Moore - American engineer, Silicon Valley founding father, author of Moore's law. https://en.wikipedia.org/wiki/Gordon_Moore
-                "moore".to_string(),
-                // Samuel Morse - contributed to the invention of a single-wire telegraph system based on European telegraphs and was a co-developer of the Morse code - https://en.wikipedia.org/wiki/Samuel_Morse
-                "morse".to_string(),
-                // Ian Murdock - founder of the Debian project - https://en.wikipedia.org/wiki/Ian_Murdock
-                "murdock".to_string(),
-                // May-Britt Moser - Nobel prize winner neuroscientist who contributed to the discovery of grid cells in the brain. https://en.wikipedia.org/wiki/May-Britt_Moser
-                "moser".to_string(),
-                // John Napier of Merchiston - Scottish landowner known as an astronomer, mathematician and physicist. Best known for his discovery of logarithms. https://en.wikipedia.org/wiki/John_Napier
-                "napier".to_string(),
-                // John Forbes Nash, Jr. - American mathematician who made fundamental contributions to game theory, differential geometry, and the study of partial differential equations. https://en.wikipedia.org/wiki/John_Forbes_Nash_Jr.
-                "nash".to_string(),
-                // John von Neumann - todays computer architectures are based on the von Neumann architecture. https://en.wikipedia.org/wiki/Von_Neumann_architecture
-                "neumann".to_string(),
-                // Isaac Newton invented classic mechanics and modern optics. https://en.wikipedia.org/wiki/Isaac_Newton
-                "newton".to_string(),
-                // Florence Nightingale, more prominently known as a nurse, was also the first female member of the Royal Statistical Society and a pioneer in statistical graphics https://en.wikipedia.org/wiki/Florence_Nightingale#Statistics_and_sanitary_reform
-                "nightingale".to_string(),
-                // Alfred Nobel - a Swedish chemist, engineer, innovator, and armaments manufacturer (inventor of dynamite) - https://en.wikipedia.org/wiki/Alfred_Nobel
-                "nobel".to_string(),
-                // Emmy Noether, German mathematician. Noether's Theorem is named after her. https://en.wikipedia.org/wiki/Emmy_Noether
-                "noether".to_string(),
-                // Poppy Northcutt. Poppy Northcutt was the first woman to work as part of NASA’s Mission Control. http://www.businessinsider.com/poppy-northcutt-helped-apollo-astronauts-2014-12?op=1
-                "northcutt".to_string(),
-                // Robert Noyce invented silicon integrated circuits and gave Silicon Valley its name. - https://en.wikipedia.org/wiki/Robert_Noyce
-                "noyce".to_string(),
-                // Panini - Ancient Indian linguist and grammarian from 4th century CE who worked on the world's first formal system - https://en.wikipedia.org/wiki/P%C4%81%E1%B9%87ini#Comparison_with_modern_formal_systems
-                "panini".to_string(),
-                // Ambroise Pare invented modern surgery. https://en.wikipedia.org/wiki/Ambroise_Par%C3%A9
-                "pare".to_string(),
-                // Blaise Pascal, French mathematician, physicist, and inventor - https://en.wikipedia.org/wiki/Blaise_Pascal
-                "pascal".to_string(),
-                // Louis Pasteur discovered vaccination, fermentation and pasteurization. https://en.wikipedia.org/wiki/Louis_Pasteur.
-                "pasteur".to_string(),
-                // Cecilia Payne-Gaposchkin was an astronomer and astrophysicist who, in 1925, proposed in her Ph.D. thesis an explanation for the composition of stars in terms of the relative abundances of hydrogen and helium. https://en.wikipedia.org/wiki/Cecilia_Payne-Gaposchkin
-                "payne".to_string(),
-                // Radia Perlman is a software designer and network engineer and most famous for her invention of the spanning-tree protocol (STP). https://en.wikipedia.org/wiki/Radia_Perlman
-                "perlman".to_string(),
-                // Rob Pike was a key contributor to Unix, Plan 9, the X graphic system, utf-8, and the Go programming language. https://en.wikipedia.org/wiki/Rob_Pike
-                "pike".to_string(),
-                // Henri Poincaré made fundamental contributions in several fields of mathematics. https://en.wikipedia.org/wiki/Henri_Poincar%C3%A9
-                "poincare".to_string(),
-                // Laura Poitras is a director and producer whose work, made possible by open source crypto tools, advances the causes of truth and freedom of information by reporting disclosures by whistleblowers such as Edward Snowden. https://en.wikipedia.org/wiki/Laura_Poitras
-                "poitras".to_string(),
-                // Tat’yana Avenirovna Proskuriakova (Russian: Татья́на Авени́ровна Проскуряко́ва) (January 23 [O.S. January 10] 1909 – August 30, 1985) was a Russian-American Mayanist scholar and archaeologist who contributed significantly to the deciphering of Maya hieroglyphs, the writing system of the pre-Columbian Maya civilization of Mesoamerica. https://en.wikipedia.org/wiki/Tatiana_Proskouriakoff
-                "proskuriakova".to_string(),
-                // Claudius Ptolemy - a Greco-Egyptian writer of Alexandria, known as a mathematician, astronomer, geographer, astrologer, and poet of a single epigram in the Greek Anthology - https://en.wikipedia.org/wiki/Ptolemy
-                "ptolemy".to_string(),
-                // C. V. Raman - Indian physicist who won the Nobel Prize in 1930 for proposing the Raman effect. - https://en.wikipedia.org/wiki/C._V._Raman
-                "raman".to_string(),
-                // Srinivasa Ramanujan - Indian mathematician and autodidact who made extraordinary contributions to mathematical analysis, number theory, infinite series, and continued fractions. - https://en.wikipedia.org/wiki/Srinivasa_Ramanujan
-                "ramanujan".to_string(),
-                // Sally Kristen Ride was an American physicist and astronaut. She was the first American woman in space, and the youngest American astronaut. https://en.wikipedia.org/wiki/Sally_Ride
-                "ride".to_string(),
-                // Dennis Ritchie - co-creator of UNIX and the C programming language. - https://en.wikipedia.org/wiki/Dennis_Ritchie
-                "ritchie".to_string(),
-                // Ida Rhodes - American pioneer in computer programming, designed the first computer used for Social Security. https://en.wikipedia.org/wiki/Ida_Rhodes
-                "rhodes".to_string(),
-                // Julia Hall Bowman Robinson - American mathematician renowned for her contributions to the fields of computability theory and computational complexity theory. https://en.wikipedia.org/wiki/Julia_Robinson
-                "robinson".to_string(),
-                // Wilhelm Conrad Röntgen - German physicist who was awarded the first Nobel Prize in Physics in 1901 for the discovery of X-rays (Röntgen rays). https://en.wikipedia.org/wiki/Wilhelm_R%C3%B6ntgen
-                "roentgen".to_string(),
-                // Rosalind Franklin - British biophysicist and X-ray crystallographer whose research was critical to the understanding of DNA - https://en.wikipedia.org/wiki/Rosalind_Franklin
-                "rosalind".to_string(),
-                // Vera Rubin - American astronomer who pioneered work on galaxy rotation rates. https://en.wikipedia.org/wiki/Vera_Rubin
-                "rubin".to_string(),
-                // Meghnad Saha - Indian astrophysicist best known for his development of the Saha equation, used to describe chemical and physical conditions in stars - https://en.wikipedia.org/wiki/Meghnad_Saha
-                "saha".to_string(),
-                // Jean E. Sammet developed FORMAC, the first widely used computer language for symbolic manipulation of mathematical formulas. https://en.wikipedia.org/wiki/Jean_E._Sammet
-                "sammet".to_string(),
-                // Mildred Sanderson - American mathematician best known for Sanderson's theorem concerning modular invariants. https://en.wikipedia.org/wiki/Mildred_Sanderson
-                "sanderson".to_string(),
-                // Satoshi Nakamoto is the name used by the unknown person or group of people who developed bitcoin, authored the bitcoin white paper, and created and deployed bitcoin's original reference implementation. https://en.wikipedia.org/wiki/Satoshi_Nakamoto
-                "satoshi".to_string(),
-                // Adi Shamir - Israeli cryptographer whose numerous inventions and contributions to cryptography include the Ferge Fiat Shamir identification scheme, the Rivest Shamir Adleman (RSA) public-key cryptosystem, the Shamir's secret sharing scheme, the breaking of the Merkle-Hellman cryptosystem, the TWINKLE and TWIRL factoring devices and the discovery of differential cryptanalysis (with Eli Biham). https://en.wikipedia.org/wiki/Adi_Shamir
-                "shamir".to_string(),
-                // Claude Shannon - The father of information theory and founder of digital circuit design theory. (https://en.wikipedia.org/wiki/Claude_Shannon)
-                "shannon".to_string(),
-                // Carol Shaw - Originally an Atari employee, Carol Shaw is said to be the first female video game designer. https://en.wikipedia.org/wiki/Carol_Shaw_(video_game_designer)
-                "shaw".to_string(),
-                // Dame Stephanie "Steve" Shirley - Founded a software company in 1962 employing women working from home. https://en.wikipedia.org/wiki/Steve_Shirley
-                "shirley".to_string(),
-                // William Shockley co-invented the transistor - https://en.wikipedia.org/wiki/William_Shockley
-                "shockley".to_string(),
-                // Lina Solomonovna Stern (or Shtern; Russian: Лина Соломоновна Штерн; 26 August 1878 – 7 March 1968) was a Soviet biochemist, physiologist and humanist whose medical discoveries saved thousands of lives at the fronts of World War II. She is best known for her pioneering work on blood–brain barrier, which she described as hemato-encephalic barrier in 1921. https://en.wikipedia.org/wiki/Lina_Stern
-                "shtern".to_string(),
-                // Françoise Barré-Sinoussi - French virologist and Nobel Prize Laureate in Physiology or Medicine; her work was fundamental in identifying HIV as the cause of AIDS. https://en.wikipedia.org/wiki/Fran%C3%A7oise_Barr%C3%A9-Sinoussi
-                "sinoussi".to_string(),
-                // Betty Snyder - one of the original programmers of the ENIAC. https://en.wikipedia.org/wiki/ENIAC - https://en.wikipedia.org/wiki/Betty_Holberton
-                "snyder".to_string(),
-                // Cynthia Solomon - Pioneer in the fields of artificial intelligence, computer science and educational computing. Known for creation of Logo, an educational programming language.  https://en.wikipedia.org/wiki/Cynthia_Solomon
-                "solomon".to_string(),
-                // Frances Spence - one of the original programmers of the ENIAC. https://en.wikipedia.org/wiki/ENIAC - https://en.wikipedia.org/wiki/Frances_Spence
-                "spence".to_string(),
-                // Michael Stonebraker is a database research pioneer and architect of Ingres, Postgres, VoltDB and SciDB. Winner of 2014 ACM Turing Award. https://en.wikipedia.org/wiki/Michael_Stonebraker
-                "stonebraker".to_string(),
-                // Ivan Edward Sutherland - American computer scientist and Internet pioneer, widely regarded as the father of computer graphics. https://en.wikipedia.org/wiki/Ivan_Sutherland
-                "sutherland".to_string(),
-                // Janese Swanson (with others) developed the first of the Carmen Sandiego games. She went on to found Girl Tech. https://en.wikipedia.org/wiki/Janese_Swanson
-                "swanson".to_string(),
-                // Aaron Swartz was influential in creating RSS, Markdown, Creative Commons, Reddit, and much of the internet as we know it today. He was devoted to freedom of information on the web. https://en.wikiquote.org/wiki/Aaron_Swartz
-                "swartz".to_string(),
-                // Bertha Swirles was a theoretical physicist who made a number of contributions to early quantum theory. https://en.wikipedia.org/wiki/Bertha_Swirles
-                "swirles".to_string(),
-                // Helen Brooke Taussig - American cardiologist and founder of the field of paediatric cardiology. https://en.wikipedia.org/wiki/Helen_B._Taussig
-                "taussig".to_string(),
-                // Valentina Tereshkova is a Russian engineer, cosmonaut and politician. She was the first woman to fly to space in 1963. In 2013, at the age of 76, she offered to go on a one-way mission to Mars. https://en.wikipedia.org/wiki/Valentina_Tereshkova
-                "tereshkova".to_string(),
-                // Nikola Tesla invented the AC electric system and every gadget ever used by a James Bond villain. https://en.wikipedia.org/wiki/Nikola_Tesla
-                "tesla".to_string(),
-                // Marie Tharp - American geologist and oceanic cartographer who co-created the first scientific map of the Atlantic Ocean floor. Her work led to the acceptance of the theories of plate tectonics and continental drift. https://en.wikipedia.org/wiki/Marie_Tharp
-                "tharp".to_string(),
-                // Ken Thompson - co-creator of UNIX and the C programming language - https://en.wikipedia.org/wiki/Ken_Thompson
-                "thompson".to_string(),
-                // Linus Torvalds invented Linux and Git. https://en.wikipedia.org/wiki/Linus_Torvalds
-                "torvalds".to_string(),
-                // Youyou Tu - Chinese pharmaceutical chemist and educator known for discovering artemisinin and dihydroartemisinin, used to treat malaria, which has saved millions of lives. Joint winner of the 2015 Nobel Prize in Physiology or Medicine. https://en.wikipedia.org/wiki/Tu_Youyou
-                "tu".to_string(),
-                // Alan Turing was a founding father of computer science. https://en.wikipedia.org/wiki/Alan_Turing.
-                "turing".to_string(),
-                // Varahamihira - Ancient Indian mathematician who discovered trigonometric formulae during 505-587 CE - https://en.wikipedia.org/wiki/Var%C4%81hamihira#Contributions
-                "varahamihira".to_string(),
-                // Dorothy Vaughan was a NASA mathematician and computer programmer on the SCOUT launch vehicle program that put America's first satellites into space - https://en.wikipedia.org/wiki/Dorothy_Vaughan
-                "vaughan".to_string(),
-                // Cédric Villani - French mathematician, won Fields Medal, Fermat Prize and Poincaré Price for his work in differential geometry and statistical mechanics. https://en.wikipedia.org/wiki/C%C3%A9dric_Villani
-                "villani".to_string(),
-                // Sir Mokshagundam Visvesvaraya - is a notable Indian engineer.  He is a recipient of the Indian Republic's highest honour, the Bharat Ratna, in 1955. On his birthday, 15 September is celebrated as Engineer's Day in India in his memory - https://en.wikipedia.org/wiki/Visvesvaraya
-                "visvesvaraya".to_string(),
-                // Christiane Nüsslein-Volhard - German biologist, won Nobel Prize in Physiology or Medicine in 1995 for research on the genetic control of embryonic development. https://en.wikipedia.org/wiki/Christiane_N%C3%BCsslein-Volhard
-                "volhard".to_string(),
-                // Marlyn Wescoff - one of the original programmers of the ENIAC. https://en.wikipedia.org/wiki/ENIAC - https://en.wikipedia.org/wiki/Marlyn_Meltzer
-                "wescoff".to_string(),
-                // Sylvia B. Wilbur - British computer scientist who helped develop the ARPANET, was one of the first to exchange email in the UK and a leading researcher in computer-supported collaborative work. https://en.wikipedia.org/wiki/Sylvia_Wilbur
-                "wilbur".to_string(),
-                // Andrew Wiles - Notable British mathematician who proved the enigmatic Fermat's Last Theorem - https://en.wikipedia.org/wiki/Andrew_Wiles
-                "wiles".to_string(),
-                // Roberta Williams, did pioneering work in graphical adventure games for personal computers, particularly the King's Quest series. https://en.wikipedia.org/wiki/Roberta_Williams
-                "williams".to_string(),
-                // Malcolm John Williamson - British mathematician and cryptographer employed by the GCHQ. Developed in 1974 what is now known as Diffie-Hellman key exchange (Diffie and Hellman first published the scheme in 1976). https://en.wikipedia.org/wiki/Malcolm_J._Williamson
-                "williamson".to_string(),
-                // Sophie Wilson designed the first Acorn Micro-Computer and the instruction set for ARM processors. https://en.wikipedia.org/wiki/Sophie_Wilson
-                "wilson".to_string(),
-                // Jeannette Wing - co-developed the Liskov substitution principle. - https://en.wikipedia.org/wiki/Jeannette_Wing
-                "wing".to_string(),
-                // Steve Wozniak invented the Apple I and Apple II. https://en.wikipedia.org/wiki/Steve_Wozniak
-                "wozniak".to_string(),
-                // The Wright brothers, Orville and Wilbur - credited with inventing and building the world's first successful airplane and making the first controlled, powered and sustained heavier-than-air human flight - https://en.wikipedia.org/wiki/Wright_brothers
-                "wright".to_string(),
-                // Chien-Shiung Wu - Chinese-American experimental physicist who made significant contributions to nuclear physics. https://en.wikipedia.org/wiki/Chien-Shiung_Wu
-                "wu".to_string(),
-                // Rosalyn Sussman Yalow - Rosalyn Sussman Yalow was an American medical physicist, and a co-winner of the 1977 Nobel Prize in Physiology or Medicine for development of the radioimmunoassay technique. https://en.wikipedia.org/wiki/Rosalyn_Sussman_Yalow
-                "yalow".to_string(),
-                // Ada Yonath - an Israeli crystallographer, the first woman from the Middle East to win a Nobel prize in the sciences. https://en.wikipedia.org/wiki/Ada_Yonath
-                "yonath".to_string(),
-                // Nikolay Yegorovich Zhukovsky (Russian: Никола́й Его́рович Жуко́вский, January 17 1847 – March 17, 1921) was a Russian scientist, mathematician and engineer, and a founding father of modern aero- and hydrodynamics. Whereas contemporary scientists scoffed at the idea of human flight, Zhukovsky was the first to undertake the study of airflow. He is often called the Father of Russian Aviation. https://en.wikipedia.org/wiki/Nikolay_Yegorovich_Zhukovsky
-                "zhukovsky".to_string(),
-            ],
-        }
+        Self::from_lists(WordLists::defaults())
+    }
+
+    /// Returns a cheap handle to a process-wide default generator.
+    ///
+    /// Every call returns a clone of the same `Arc`, which makes this a good fit
+    /// for sharing one generator across threads or storing it in application
+    /// state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::shared();
+    /// let handle = std::thread::spawn(move || generator.generate());
+    /// let mnemonic = handle.join().unwrap().expect("Failed to generate mnemonic");
+    /// ```
+    #[cfg(any(feature = "default-words", feature = "mini-words"))]
+    pub fn shared() -> Arc<MnemonicGenerator> {
+        Arc::clone(shared_default())
+    }
+
+    /// Moves a configured generator behind an `Arc` for sharing across threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use std::thread;
+    ///
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["happy", "brave"], ["turing", "hopper"])
+    ///     .with_prefix("worker-")
+    ///     .into_shared();
+    ///
+    /// let handles: Vec<_> = (0..4)
+    ///     .map(|_| {
+    ///         let generator = Arc::clone(&generator);
+    ///         thread::spawn(move || generator.generate())
+    ///     })
+    ///     .collect();
+    /// for handle in handles {
+    ///     assert!(handle.join().unwrap().unwrap().starts_with("worker-"));
+    /// }
+    /// ```
+    pub fn into_shared(self) -> Arc<MnemonicGenerator> {
+        Arc::new(self)
     }
 
     /// Creates a `MnemonicGenerator` with custom word lists.
     ///
     /// # Arguments
     ///
-    /// * `left_words` - A vector of words to be used as the first part of the mnemonic
-    /// * `right_words` - A vector of words to be used as the second part of the mnemonic
+    /// * `left_words` - Words to be used as the first part of the mnemonic
+    /// * `right_words` - Words to be used as the second part of the mnemonic
+    ///
+    /// Any iterable of string-like values is accepted, so string literals can be
+    /// passed directly.
     ///
     /// # Examples
     ///
     /// ```
     /// use mnemonic_generator::MnemonicGenerator;
     ///
-    /// let generator = MnemonicGenerator::with_words(
+    /// let generator = MnemonicGenerator::with_words(["amazing", "legend"], ["jordan", "larry"]);
+    /// let mnemonic = generator.generate().expect("Failed to generate mnemonic");
+    ///
+    /// let owned = MnemonicGenerator::with_words(
     ///     vec!["amazing".to_string(), "legend".to_string()],
     ///     vec!["jordan".to_string(), "larry".to_string()]
     /// );
+    /// let mnemonic = owned.generate().expect("Failed to generate mnemonic");
+    /// ```
+    pub fn with_words<L, R>(left_words: L, right_words: R) -> Self
+    where
+        L: IntoIterator,
+        L::Item: Into<String>,
+        R: IntoIterator,
+        R::Item: Into<String>,
+    {
+        Self::from_lists(WordLists::new(
+            WordList::Owned(left_words.into_iter().map(Into::into).collect()),
+            WordList::Owned(right_words.into_iter().map(Into::into).collect()),
+        ))
+    }
+
+    /// Creates a `MnemonicGenerator` with custom word lists, validating them first.
+    ///
+    /// Duplicate words are removed case-insensitively, keeping the first
+    /// spelling. Empty words and words containing whitespace are rejected, since
+    /// they would produce names that cannot be told apart or split again.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::EmptyWord` or `MnemonicError::WhitespaceInWord`
+    /// for the first invalid word found
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::{MnemonicError, MnemonicGenerator};
+    ///
+    /// let generator = MnemonicGenerator::try_with_words(["Happy", "happy"], ["turing"]).unwrap();
+    /// assert_eq!(generator.generate().unwrap(), "Happy_turing");
+    ///
+    /// let result = MnemonicGenerator::try_with_words(["happy"], ["grace hopper"]);
+    /// assert!(matches!(result, Err(MnemonicError::WhitespaceInWord(_))));
+    /// ```
+    pub fn try_with_words<L, R>(left_words: L, right_words: R) -> Result<Self, MnemonicError>
+    where
+        L: IntoIterator,
+        L::Item: Into<String>,
+        R: IntoIterator,
+        R::Item: Into<String>,
+    {
+        Ok(Self::from_lists(WordLists::new(
+            WordList::validated(left_words)?,
+            WordList::validated(right_words)?,
+        )))
+    }
+
+    /// Creates a `MnemonicGenerator` from slices of string literals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_str_words(&["amazing", "legend"], &["jordan", "larry"]);
     /// let mnemonic = generator.generate().expect("Failed to generate mnemonic");
     /// ```
-    pub fn with_words(left_words: Vec<String>, right_words: Vec<String>) -> Self {
+    pub fn with_str_words(left_words: &[&str], right_words: &[&str]) -> Self {
+        Self::with_words(left_words.iter().copied(), right_words.iter().copied())
+    }
+
+    /// Creates a generator over the given lists with no additional options.
+    fn from_lists(words: Arc<WordLists>) -> Self {
         Self {
-            left_words,
-            right_words,
+            words,
+            profanity_filter: None,
+            profile: None,
+            length_window: None,
+            side_lengths: None,
+            leading_letters: None,
+            shard: None,
+            syllable_limit: None,
+            pronounceable: None,
+            allowed_words: None,
+            reversed: false,
+            middle: None,
+            separator: None,
+            prefix: String::new(),
+            suffix: String::new(),
+            checksum: None,
+            timestamp: None,
+            transforms: Vec::new(),
+            selection: None,
+            observer: None,
+            #[cfg(feature = "regex")]
+            regex: None,
         }
     }
 
+    /// Screens every generated name through the given profanity filter.
+    ///
+    /// Candidates rejected by the filter are re-rolled. If no acceptable name is
+    /// found after a bounded number of attempts, generation fails with
+    /// `MnemonicError::AllCandidatesRejected`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::{MnemonicGenerator, ProfanityFilter};
+    ///
+    /// let generator = MnemonicGenerator::new().with_profanity_filter(ProfanityFilter::new());
+    /// let mnemonic = generator.generate().expect("Failed to generate mnemonic");
+    /// assert_ne!(mnemonic, "boring_wozniak");
+    /// # }
+    /// ```
+    pub fn with_profanity_filter(mut self, filter: ProfanityFilter) -> Self {
+        self.profanity_filter = Some(filter);
+        self
+    }
+
+    /// Constrains generated names to the given naming profile.
+    ///
+    /// Names are normalized to the profile's case, and candidates that still
+    /// violate it (for example by being too long) are re-rolled. While a profile
+    /// is active, `generate()` joins words with the profile's separator unless
+    /// one was set with [`MnemonicGenerator::with_separator`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidSeparator` if the separator `generate()`
+    /// would use is not valid under the profile
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::{MnemonicGenerator, NamingProfile};
+    ///
+    /// let profile = NamingProfile::rfc1123_hostname();
+    /// let generator = MnemonicGenerator::new().with_profile(profile.clone()).unwrap();
+    /// let hostname = generator.generate().expect("Failed to generate mnemonic");
+    /// assert!(profile.is_valid(&hostname));
+    ///
+    /// let underscored = MnemonicGenerator::new().with_separator("_").unwrap();
+    /// assert!(underscored.with_profile(profile).is_err());
+    /// # }
+    /// ```
+    pub fn with_profile(mut self, profile: NamingProfile) -> Result<Self, MnemonicError> {
+        self.profile = Some(profile);
+        self.validate_separator(self.default_separator())?;
+        Ok(self)
+    }
+
+    /// Prepends a fixed prefix, such as an environment name, to every generated name.
+    ///
+    /// The prefix counts towards the length window and is checked by the
+    /// filters and the naming profile like the rest of the name.
+    /// [`MnemonicGenerator::parse`] expects and strips it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"])
+    ///     .with_prefix("prod-")
+    ///     .with_suffix("-eu1");
+    /// assert_eq!(generator.generate().unwrap(), "prod-hopeful_turing-eu1");
+    /// ```
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Appends a fixed suffix, such as a region, to every generated name.
+    ///
+    /// See [`MnemonicGenerator::with_prefix`].
+    pub fn with_suffix(mut self, suffix: &str) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Restricts generated names to a window of total lengths, in characters,
+    /// including the separator and any prefix or suffix.
+    ///
+    /// Words are bucketed by length up front, so candidates are drawn directly
+    /// from the feasible pairs instead of being rejected after the fact. Every
+    /// fitting name, including any middle word, remains equally likely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::new().with_length_range(12..=16);
+    /// let mnemonic = generator.generate().expect("Failed to generate mnemonic");
+    /// assert!((12..=16).contains(&mnemonic.chars().count()));
+    /// # }
+    /// ```
+    pub fn with_length_range(mut self, range: RangeInclusive<usize>) -> Self {
+        self.length_window = Some(LengthWindow::new(
+            range,
+            &self.words.left,
+            &self.words.right,
+        ));
+        self.restrict_length_window()
+    }
+
     /// Generates a mnemonic using the default underscore separator.
     ///
     /// # Errors
     ///
-    /// Returns `MnemonicError::EmptyWordList` if no words are available for generation
+    /// Returns `MnemonicError::EmptyList` if either word list is empty
     ///
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
     /// use mnemonic_generator::MnemonicGenerator;
     ///
     /// let generator = MnemonicGenerator::new();
@@ -682,9 +638,10 @@ impl MnemonicGenerator {
     ///     Ok(mnemonic) => println!("Generated mnemonic: {}", mnemonic),
     ///     Err(e) => eprintln!("Error generating mnemonic: {}", e)
     /// }
+    /// # }
     /// ```
     pub fn generate(&self) -> Result<String, MnemonicError> {
-        self.generate_with_separator("_")
+        self.generate_with_separator(self.default_separator())
     }
 
     /// Generates a mnemonic using a custom separator.
@@ -695,11 +652,15 @@ impl MnemonicGenerator {
     ///
     /// # Errors
     ///
-    /// Returns `MnemonicError::EmptyWordList` if no words are available for generation
+    /// Returns `MnemonicError::EmptyList` if either word list is empty, or
+    /// `MnemonicError::AllCandidatesRejected` if the configured filters reject
+    /// every candidate
     ///
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
     /// use mnemonic_generator::MnemonicGenerator;
     ///
     /// let generator = MnemonicGenerator::new();
@@ -707,30 +668,542 @@ impl MnemonicGenerator {
     ///     Ok(mnemonic) => println!("Generated mnemonic: {}", mnemonic),
     ///     Err(e) => eprintln!("Error generating mnemonic: {}", e)
     /// }
+    /// # }
     /// ```
     pub fn generate_with_separator(&self, separator: &str) -> Result<String, MnemonicError> {
-        if self.left_words.is_empty() || self.right_words.is_empty() {
-            return Err(MnemonicError::EmptyWordList);
+        self.generate_from(&mut crate::rng::default_rng(), separator)
+    }
+
+    /// Generates a mnemonic using the default separator, drawing randomness
+    /// from `rng` instead of the default thread-local generator.
+    ///
+    /// Any [`RngCore`] implementation works, including seeded generators for
+    /// reproducible names in tests.
+    ///
+    /// # Errors
+    ///
+    /// See [`MnemonicGenerator::generate_with_separator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::MnemonicGenerator;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let generator = MnemonicGenerator::new();
+    /// let first = generator.generate_with_rng(&mut StdRng::seed_from_u64(7)).unwrap();
+    /// let second = generator.generate_with_rng(&mut StdRng::seed_from_u64(7)).unwrap();
+    /// assert_eq!(first, second);
+    /// # }
+    /// ```
+    pub fn generate_with_rng<G: RngCore + ?Sized>(
+        &self,
+        rng: &mut G,
+    ) -> Result<String, MnemonicError> {
+        self.generate_from(rng, self.default_separator())
+    }
+
+    /// Draws a pair from `rng` and renders it with `separator`.
+    fn generate_from<G: RngCore + ?Sized>(
+        &self,
+        rng: &mut G,
+        separator: &str,
+    ) -> Result<String, MnemonicError> {
+        let name = self.draw_name(rng, separator)?;
+        self.observe(|observer| observer.on_generated(&name));
+        Ok(name)
+    }
+
+    /// Like [`MnemonicGenerator::generate`], but without reporting the name
+    /// to the observer, for callers that may still turn it down.
+    pub(crate) fn generate_candidate(&self) -> Result<String, MnemonicError> {
+        self.draw_name(&mut crate::rng::default_rng(), self.default_separator())
+    }
+
+    /// Draws a pair from `rng` and renders it with `separator`, without
+    /// reporting it to the observer.
+    fn draw_name<G: RngCore + ?Sized>(
+        &self,
+        rng: &mut G,
+        separator: &str,
+    ) -> Result<String, MnemonicError> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("generate").entered();
+
+        let pick = self.choose(rng, separator)?;
+        trace_event!(
+            debug,
+            left_idx = pick.left,
+            right_idx = pick.right,
+            "generated mnemonic"
+        );
+        Ok(self.render(pick, separator))
+    }
+
+    /// Generates the two words of a mnemonic without joining them.
+    ///
+    /// The returned slices borrow directly from the generator's word lists, so
+    /// no `String` is allocated when no filters or profile are configured.
+    /// Filters are evaluated as if the words were joined by the default
+    /// separator. The words are returned as stored, without the profile's case
+    /// normalization.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::new();
+    /// let (adjective, scientist) = generator.generate_parts().expect("Failed to generate mnemonic");
+    /// println!("{} {}", adjective, scientist);
+    /// # }
+    /// ```
+    pub fn generate_parts(&self) -> Result<(&str, &str), MnemonicError> {
+        let pick = self.choose(&mut crate::rng::default_rng(), self.default_separator())?;
+        Ok((
+            self.words.left.get(pick.left),
+            self.words.right.get(pick.right),
+        ))
+    }
+
+    /// Returns the separator used by `generate()`.
+    fn default_separator(&self) -> &str {
+        match (&self.separator, &self.profile) {
+            (Some(separator), _) => separator,
+            (None, Some(profile)) => profile.separator(),
+            (None, None) => "_",
+        }
+    }
+
+    /// Returns `MnemonicError::EmptyList` naming the first empty word list.
+    pub(crate) fn check_not_empty(&self) -> Result<(), MnemonicError> {
+        if self.words.left.is_empty() {
+            return Err(MnemonicError::EmptyList {
+                list: WordSide::Left.into(),
+            });
         }
+        if self.words.right.is_empty() {
+            return Err(MnemonicError::EmptyList {
+                list: WordSide::Right.into(),
+            });
+        }
+        Ok(())
+    }
 
-        let mut rng = rand::thread_rng();
-        let left_idx = rng.gen_range(0..self.left_words.len());
-        let right_idx = rng.gen_range(0..self.right_words.len());
+    /// Draws candidates until one passes every configured filter.
+    ///
+    /// Candidates are drawn from the words that satisfy every per-word
+    /// constraint. If they keep being rejected, the names that pass are listed
+    /// and drawn from directly, so a narrow combination of filters is slower
+    /// rather than failing.
+    fn choose<G: RngCore + ?Sized>(
+        &self,
+        rng: &mut G,
+        separator: &str,
+    ) -> Result<Pick, MnemonicError> {
+        self.check_not_empty()?;
+
+        let mut attempts = 0;
+        while attempts < MAX_FILTER_ATTEMPTS {
+            let Some(pick) = self.pick_indices(rng, separator) else {
+                break;
+            };
+            attempts += 1;
+            if self.is_reachable(pick, separator) {
+                return Ok(pick);
+            }
+            trace_event!(
+                trace,
+                left_idx = pick.left,
+                right_idx = pick.right,
+                "candidate rejected, retrying"
+            );
+            self.observe(|observer| observer.on_retry());
+        }
 
-        Ok(format!(
-            "{}{}{}",
-            &self.left_words[left_idx], separator, &self.right_words[right_idx]
+        if attempts == MAX_FILTER_ATTEMPTS {
+            // So few names pass the filters that drawing at random keeps
+            // missing them: list the ones that do and draw among those.
+            let picks = self.reachable_picks(separator);
+            if !picks.is_empty() {
+                return Ok(picks[sample::index(rng, picks.len())]);
+            }
+        }
+
+        trace_event!(warn, "every candidate was rejected by the filters");
+        self.observe(|observer| observer.on_exhausted(attempts));
+        Err(MnemonicError::AllCandidatesRejected)
+    }
+
+    /// Joins the picked words, any timestamp, and the checksum between the
+    /// prefix and suffix, normalized to the profile's case, and runs the
+    /// registered transforms.
+    fn render(&self, pick: Pick, separator: &str) -> String {
+        self.render_with_right(pick, self.words.right.get(pick.right), separator)
+    }
+
+    /// Like [`MnemonicGenerator::render`], but with `right` in place of the
+    /// right-hand word.
+    fn render_with_right(&self, pick: Pick, right: &str, separator: &str) -> String {
+        let mut mnemonic = self.prefix.clone();
+        if let Some(timestamp) = self.timestamp_part(separator) {
+            mnemonic.push_str(&timestamp);
+        }
+        let (first, second) = self.ordered(self.words.left.get(pick.left), right);
+        mnemonic.push_str(first);
+        if let Some(middle) = self.middle_part(pick.middle) {
+            mnemonic.push_str(separator);
+            mnemonic.push_str(middle);
+        }
+        mnemonic.push_str(separator);
+        mnemonic.push_str(second);
+        if let Some(checksum) = self.checksum_part(pick.left, pick.right) {
+            mnemonic.push_str(separator);
+            mnemonic.push_str(checksum);
+        }
+        mnemonic.push_str(&self.suffix);
+        let mnemonic = match &self.profile {
+            Some(profile) => profile.apply(&mnemonic),
+            None => mnemonic,
+        };
+        self.apply_transforms(mnemonic)
+    }
+
+    /// Draws the indices of the next candidate from the selection strategy, or
+    /// uniformly among the words that satisfy the per-side length limits,
+    /// leading letters, and word filters, within the length window or shard
+    /// if one is configured. Any middle word is drawn independently of the
+    /// pair.
+    ///
+    /// Returns `None` if no pair can satisfy the length window, limits,
+    /// letters, word filters, or shard.
+    fn pick_indices<G: RngCore + ?Sized>(&self, rng: &mut G, separator: &str) -> Option<Pick> {
+        if let (None, Some(window)) = (&self.selection, &self.length_window) {
+            return self.pick_in_window(rng, window, self.fixed_len(separator), separator);
+        }
+
+        let (left, right) = self.pick_pair(rng)?;
+        Some(Pick {
+            left,
+            middle: self.pick_middle(rng),
+            right,
+        })
+    }
+
+    /// Draws the indices of the next candidate pair, outside of a length
+    /// window.
+    fn pick_pair<G: RngCore + ?Sized>(&self, mut rng: &mut G) -> Option<(usize, usize)> {
+        let (left_len, right_len) = (self.words.left.len(), self.words.right.len());
+        if let Some(selection) = &self.selection {
+            return Some(selection.pick(&mut rng, left_len, right_len));
+        }
+
+        let (left, right) = self.candidates();
+        if let (None, None, Some(shard)) = (&left, &right, &self.shard) {
+            return shard.pick(rng);
+        }
+
+        let mut draw = |candidates: Option<&[usize]>, len| match candidates {
+            Some([]) => None,
+            Some(indices) => Some(indices[sample::index(rng, indices.len())]),
+            None => Some(sample::index(rng, len)),
+        };
+        Some((
+            draw(left.as_deref(), left_len)?,
+            draw(right.as_deref(), right_len)?,
         ))
     }
+
+    /// Returns the indices of the words of each list that satisfy every
+    /// per-word constraint, in ascending order, or `None` for a side without
+    /// any.
+    fn candidates(&self) -> (Candidates<'_>, Candidates<'_>) {
+        let sets = [
+            self.side_lengths.as_ref().map(SideLengths::indices),
+            self.leading_letters.as_ref().map(LeadingLetters::indices),
+            self.allowed_words.as_ref().map(AllowedWords::indices),
+        ];
+        sets.into_iter()
+            .flatten()
+            .fold((None, None), |(left, right), (next_left, next_right)| {
+                (intersect(left, next_left), intersect(right, next_right))
+            })
+    }
+
+    /// Rebuilds the length window over the words that satisfy every per-word
+    /// constraint, after the window or a constraint changes.
+    pub(crate) fn restrict_length_window(mut self) -> Self {
+        let (left, right) = self.candidates();
+        let (left, right) = (left.map(Cow::into_owned), right.map(Cow::into_owned));
+        if let Some(window) = &mut self.length_window {
+            window.restrict(left.as_deref(), right.as_deref());
+        }
+        self
+    }
+
+    /// Returns the number of characters every name has besides its words and
+    /// checksum.
+    fn fixed_len(&self, separator: &str) -> usize {
+        self.prefix.chars().count() + separator.chars().count() + self.suffix.chars().count()
+    }
+
+    /// Returns `true` if any filter that inspects the rendered name is configured.
+    fn has_filters(&self) -> bool {
+        #[cfg(feature = "regex")]
+        if self.regex.is_some() {
+            return true;
+        }
+        self.profanity_filter.is_some() || self.profile.is_some()
+    }
+
+    /// Returns `true` if every pair of words is accepted.
+    pub(crate) fn is_unfiltered(&self) -> bool {
+        self.length_window.is_none()
+            && self.side_lengths.is_none()
+            && self.leading_letters.is_none()
+            && self.shard.is_none()
+            && self.syllable_limit.is_none()
+            && self.pronounceable.is_none()
+            && self.allowed_words.is_none()
+            && !self.has_filters()
+    }
+
+    /// Returns `true` if the candidate passes every configured filter.
+    fn accepts(&self, pick: Pick, separator: &str) -> bool {
+        let (left_idx, right_idx) = (pick.left, pick.right);
+        if let Some(lengths) = &self.side_lengths {
+            if !lengths.fits(left_idx, right_idx) {
+                return false;
+            }
+        }
+        if let Some(letters) = &self.leading_letters {
+            if !letters.fits(left_idx, right_idx) {
+                return false;
+            }
+        }
+        if let Some(shard) = &self.shard {
+            if !shard.fits(left_idx, right_idx) {
+                return false;
+            }
+        }
+        if let Some(limit) = &self.syllable_limit {
+            if !limit.fits(left_idx, right_idx) {
+                return false;
+            }
+        }
+        if let Some(pronounceable) = &self.pronounceable {
+            if !pronounceable.fits(left_idx, right_idx) {
+                return false;
+            }
+        }
+        if let Some(allowed) = &self.allowed_words {
+            if !allowed.fits(left_idx, right_idx) {
+                return false;
+            }
+        }
+        if !self.has_filters() {
+            return true;
+        }
+
+        let mnemonic = self.render(pick, separator);
+        self.accepts_name(pick, &mnemonic)
+    }
+
+    /// Returns `true` if the rendered name of the candidate passes the
+    /// profanity filter, the regular expression, and the naming profile.
+    fn accepts_name(&self, pick: Pick, mnemonic: &str) -> bool {
+        if let Some(filter) = &self.profanity_filter {
+            let (left, right) = (
+                self.words.left.get(pick.left),
+                self.words.right.get(pick.right),
+            );
+            if filter.is_offensive_pair(left, right, mnemonic) {
+                return false;
+            }
+        }
+
+        #[cfg(feature = "regex")]
+        if let Some(regex) = &self.regex {
+            if !regex.is_match(mnemonic) {
+                return false;
+            }
+        }
+
+        match &self.profile {
+            Some(profile) => profile.is_valid(mnemonic),
+            None => true,
+        }
+    }
+}
+
+/// The positions of the words of one name in their lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Pick {
+    pub(crate) left: usize,
+    /// The position of the middle word, or `0` without middle words.
+    pub(crate) middle: usize,
+    pub(crate) right: usize,
+}
+
+impl Pick {
+    /// Picks the words at `left` and `right`, and the first middle word.
+    pub(crate) fn pair(left: usize, right: usize) -> Self {
+        Self {
+            left,
+            middle: 0,
+            right,
+        }
+    }
+}
+
+/// The sorted indices of the words of one list that may be drawn, or `None`
+/// if any word may be.
+type Candidates<'a> = Option<Cow<'a, [usize]>>;
+
+/// Keeps the indices of `set` that are also in `other`, if given.
+fn intersect<'a>(
+    set: Option<Cow<'a, [usize]>>,
+    other: Option<&'a [usize]>,
+) -> Option<Cow<'a, [usize]>> {
+    match (set, other) {
+        (set, None) => set,
+        (None, Some(other)) => Some(Cow::Borrowed(other)),
+        (Some(set), Some(other)) => Some(Cow::Owned(
+            set.iter()
+                .copied()
+                .filter(|idx| other.binary_search(idx).is_ok())
+                .collect(),
+        )),
+    }
 }
 
+#[cfg(any(feature = "default-words", feature = "mini-words"))]
 impl Default for MnemonicGenerator {
     fn default() -> Self {
         Self::new()
     }
 }
 
-#[cfg(test)]
+impl fmt::Debug for MnemonicGenerator {
+    /// Summarizes the configuration, reporting the list sizes rather than the words.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("MnemonicGenerator");
+        debug
+            .field("left_words", &self.words.left.len())
+            .field("right_words", &self.words.right.len())
+            .field("separator", &self.default_separator())
+            .field("reversed", &self.reversed)
+            .field(
+                "middle_words",
+                &self.middle.as_ref().map(|middle| middle.words().len()),
+            )
+            .field("prefix", &self.prefix)
+            .field("suffix", &self.suffix)
+            .field("profile", &self.profile)
+            .field("profanity_filter", &self.profanity_filter.is_some())
+            .field("length_window", &self.length_window.is_some())
+            .field("side_lengths", &self.side_lengths.is_some())
+            .field("leading_letters", &self.leading_letters.is_some())
+            .field("shard", &self.shard.is_some())
+            .field("syllable_limit", &self.syllable_limit.is_some())
+            .field("pronounceable", &self.pronounceable.is_some())
+            .field("word_filters", &self.allowed_words.is_some())
+            .field("checksum", &self.checksum)
+            .field("timestamp", &self.timestamp.is_some())
+            .field("transforms", &self.transforms.len())
+            .field("selection", &self.selection.is_some())
+            .field("observer", &self.observer.is_some());
+        #[cfg(feature = "regex")]
+        debug.field("regex", &self.regex.as_ref().map(regex_lite::Regex::as_str));
+        debug.finish()
+    }
+}
+
+impl PartialEq for MnemonicGenerator {
+    /// Generators are equal if they have the same words and options.
+    ///
+    /// Transforms, selection strategies, and observers cannot be compared by
+    /// value, so they are equal only if they are the same shared instances, as
+    /// in a clone.
+    fn eq(&self, other: &Self) -> bool {
+        let same_selection = match (&self.selection, &other.selection) {
+            (Some(selection), Some(other)) => Arc::ptr_eq(selection, other),
+            (selection, other) => selection.is_none() && other.is_none(),
+        };
+        let same_observer = match (&self.observer, &other.observer) {
+            (Some(observer), Some(other)) => Arc::ptr_eq(observer, other),
+            (observer, other) => observer.is_none() && other.is_none(),
+        };
+        #[cfg(feature = "regex")]
+        let same_regex = self.regex.as_ref().map(regex_lite::Regex::as_str)
+            == other.regex.as_ref().map(regex_lite::Regex::as_str);
+        #[cfg(not(feature = "regex"))]
+        let same_regex = true;
+        (Arc::ptr_eq(&self.words, &other.words) || self.words == other.words)
+            && self.profanity_filter == other.profanity_filter
+            && self.profile == other.profile
+            && self.length_window == other.length_window
+            && self.side_lengths == other.side_lengths
+            && self.leading_letters == other.leading_letters
+            && self.shard == other.shard
+            && self.syllable_limit == other.syllable_limit
+            && self.pronounceable == other.pronounceable
+            && self.allowed_words == other.allowed_words
+            && self.reversed == other.reversed
+            && self.middle == other.middle
+            && self.separator == other.separator
+            && self.prefix == other.prefix
+            && self.suffix == other.suffix
+            && self.checksum == other.checksum
+            && self.timestamp == other.timestamp
+            && self.transforms.len() == other.transforms.len()
+            && self
+                .transforms
+                .iter()
+                .zip(&other.transforms)
+                .all(|(transform, other)| Arc::ptr_eq(transform, other))
+            && same_selection
+            && same_observer
+            && same_regex
+    }
+}
+
+/// Generates a mnemonic from the default word lists without constructing a generator.
+///
+/// The underlying default generator is created lazily on first use and shared
+/// across threads.
+///
+/// # Examples
+///
+/// ```
+/// let mnemonic = mnemonic_generator::generate();
+/// assert!(mnemonic.contains('_'));
+/// ```
+#[cfg(any(feature = "default-words", feature = "mini-words"))]
+pub fn generate() -> String {
+    shared_default()
+        .generate()
+        .expect("default word lists are never empty")
+}
+
+/// Returns the lazily-initialized default generator shared by the whole process.
+#[cfg(any(feature = "default-words", feature = "mini-words"))]
+fn shared_default() -> &'static Arc<MnemonicGenerator> {
+    static DEFAULT_GENERATOR: OnceLock<Arc<MnemonicGenerator>> = OnceLock::new();
+
+    DEFAULT_GENERATOR.get_or_init(|| Arc::new(MnemonicGenerator::new()))
+}
+
+#[cfg(all(test, feature = "default-words"))]
 mod tests {
     use super::*;
 
@@ -767,9 +1240,192 @@ mod tests {
 
     #[test]
     fn error_on_empty_word_list() {
-        let generator = MnemonicGenerator::with_words(vec![], vec![]);
+        let generator = MnemonicGenerator::with_words(Vec::<String>::new(), Vec::<String>::new());
         let result = generator.generate();
         assert!(result.is_err());
-        assert!(matches!(result, Err(MnemonicError::EmptyWordList)));
+        assert!(matches!(
+            result,
+            Err(MnemonicError::EmptyList {
+                list: ListName::Side(WordSide::Left)
+            })
+        ));
+
+        let generator = MnemonicGenerator::with_words(["amazing"], Vec::<String>::new());
+        assert_eq!(
+            generator.generate().unwrap_err().to_string(),
+            "The right word list is empty"
+        );
+    }
+
+    #[test]
+    fn clones_compare_equal_and_debug_is_summarized() {
+        let generator = MnemonicGenerator::new()
+            .with_prefix("ci-")
+            .with_transform(|name: String| name.to_uppercase());
+        assert_eq!(generator.clone(), generator);
+        assert_ne!(generator.clone().with_suffix("-eu1"), generator);
+        assert_eq!(
+            MnemonicGenerator::with_words(["a"], ["b"]),
+            MnemonicGenerator::with_words(["a"], ["b"])
+        );
+
+        let debug = format!("{:?}", generator);
+        assert!(debug.contains("prefix: \"ci-\""));
+        assert!(!debug.contains("turing"));
+    }
+
+    #[test]
+    fn generators_are_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<MnemonicGenerator>();
+        assert_send_sync::<Arc<MnemonicGenerator>>();
+        assert_send_sync::<NonEmptyMnemonicGenerator>();
+        assert_send_sync::<MnemonicError>();
+    }
+
+    #[test]
+    fn errors_expose_their_source() {
+        use std::error::Error;
+
+        let error = MnemonicError::from(std::io::Error::other("disk full"));
+        assert_eq!(error.source().unwrap().to_string(), "disk full");
+        assert!(MnemonicError::AllCandidatesRejected.source().is_none());
+    }
+
+    #[test]
+    fn generate_parts_borrows_words() {
+        let generator = MnemonicGenerator::with_words(["amazing"], ["jordan"]);
+        let (left, right) = generator.generate_parts().expect("Should generate parts");
+        assert_eq!((left, right), ("amazing", "jordan"));
+    }
+
+    #[test]
+    fn generate_with_literal_words() {
+        let generator = MnemonicGenerator::with_words(["amazing"], ["jordan"]);
+        assert_eq!(generator.generate().unwrap(), "amazing_jordan");
+
+        let generator = MnemonicGenerator::with_str_words(&["legend"], &["bird"]);
+        assert_eq!(generator.generate().unwrap(), "legend_bird");
+    }
+
+    #[test]
+    fn profanity_filter_rejects_blocked_combinations() {
+        let generator = MnemonicGenerator::with_words(
+            vec!["boring".to_string()],
+            vec!["wozniak".to_string(), "turing".to_string()],
+        )
+        .with_profanity_filter(ProfanityFilter::new());
+
+        for _ in 0..50 {
+            let mnemonic = generator.generate().expect("Should generate mnemonic");
+            assert_eq!(mnemonic, "boring_turing");
+        }
+    }
+
+    #[test]
+    fn error_when_filter_rejects_everything() {
+        let generator =
+            MnemonicGenerator::with_words(vec!["boring".to_string()], vec!["wozniak".to_string()])
+                .with_profanity_filter(ProfanityFilter::new());
+        assert!(matches!(
+            generator.generate(),
+            Err(MnemonicError::AllCandidatesRejected)
+        ));
+    }
+
+    #[test]
+    fn profile_normalizes_and_filters_candidates() {
+        let generator = MnemonicGenerator::with_words(
+            vec!["Hopeful".to_string()],
+            vec!["Turing".to_string(), "Châtelet".to_string()],
+        )
+        .with_profile(NamingProfile::rfc1123_hostname())
+        .unwrap();
+
+        for _ in 0..50 {
+            let mnemonic = generator.generate().expect("Should generate mnemonic");
+            assert_eq!(mnemonic, "hopeful-turing");
+        }
+    }
+
+    #[test]
+    fn default_generator_borrows_static_lists() {
+        let generator = MnemonicGenerator::new();
+        assert!(matches!(generator.words.left, WordList::Static(_)));
+        assert!(matches!(generator.words.right, WordList::Static(_)));
+        assert_eq!(generator.words.left.len(), words::LEFT_WORDS.len());
+    }
+
+    #[test]
+    fn default_generators_share_word_lists() {
+        let first = MnemonicGenerator::new();
+        let second = MnemonicGenerator::new();
+        assert!(Arc::ptr_eq(&first.words, &second.words));
+        assert!(Arc::ptr_eq(
+            &MnemonicGenerator::shared(),
+            &MnemonicGenerator::shared()
+        ));
+    }
+
+    #[test]
+    fn generate_free_function() {
+        let mnemonic = generate();
+        let parts: Vec<&str> = mnemonic.split('_').collect();
+        assert_eq!(parts.len(), 2);
+    }
+
+    #[test]
+    fn length_range_accounts_for_separator() {
+        let generator = MnemonicGenerator::with_words(
+            vec!["bold".to_string(), "xenodochial".to_string()],
+            vec!["bohr".to_string(), "chandrasekhar".to_string()],
+        )
+        .with_length_range(9..=9);
+
+        for _ in 0..50 {
+            assert_eq!(generator.generate().unwrap(), "bold_bohr");
+        }
+        assert!(matches!(
+            generator.generate_with_separator("--"),
+            Err(MnemonicError::AllCandidatesRejected)
+        ));
+    }
+
+    #[test]
+    fn combined_constraints_do_not_starve_generation() {
+        let generator = MnemonicGenerator::new()
+            .with_length_range(5..=40)
+            .with_words_starting_with('i');
+        let reordered = MnemonicGenerator::new()
+            .with_words_starting_with('i')
+            .with_length_range(5..=40);
+        for _ in 0..20 {
+            for generator in [&generator, &reordered] {
+                let name = generator.generate().unwrap();
+                assert!(name.starts_with('i') && name.contains("_i"), "{}", name);
+            }
+        }
+
+        let generator = MnemonicGenerator::new();
+        let name = generator.generate().unwrap();
+        let rank = generator.rank(&generator.parse(&name).unwrap()).unwrap();
+        let single = generator
+            .shard(rank as usize, generator.combination_count() as usize)
+            .unwrap()
+            .with_left_word_filter(|_: &str| true);
+        assert_eq!(single.generate().unwrap(), name);
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn mnemonic_macro_picks_default_words() {
+        const BUILD_NAME: &str = crate::mnemonic!();
+        let generator = MnemonicGenerator::new();
+        assert!(generator.is_valid_name(BUILD_NAME));
+        assert!(generator.is_valid_name(crate::mnemonic!("CARGO_PKG_NAME")));
+        assert_eq!(
+            crate::mnemonic!("CARGO_PKG_NAME"),
+            crate::mnemonic!("CARGO_PKG_NAME")
+        );
     }
 }
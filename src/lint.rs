@@ -0,0 +1,211 @@
+use std::fmt;
+
+use thiserror::Error;
+
+use crate::distance::levenshtein;
+use crate::word_list::WordList;
+use crate::{MnemonicGenerator, PartOfSpeech};
+
+/// Words longer than this many characters are reported as [`Lint::TooLong`].
+const MAX_WORD_LEN: usize = 12;
+
+/// One of the two word lists of a generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WordSide {
+    /// The left-hand list, adjectives by default.
+    Left,
+    /// The right-hand list, scientist surnames by default.
+    Right,
+}
+
+impl fmt::Display for WordSide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Left => "left",
+            Self::Right => "right",
+        })
+    }
+}
+
+/// A word list named by `MnemonicError::EmptyList`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ListName {
+    /// The left or right list of a [`MnemonicGenerator`].
+    Side(WordSide),
+    /// The words of [`MnemonicGenerator::with_middle_words`].
+    Middle,
+    /// A category of a [`CategoryGenerator`](crate::CategoryGenerator).
+    Category(String),
+    /// A part of speech of a [`Grammar`](crate::Grammar).
+    PartOfSpeech(PartOfSpeech),
+}
+
+impl From<WordSide> for ListName {
+    fn from(side: WordSide) -> Self {
+        Self::Side(side)
+    }
+}
+
+impl fmt::Display for ListName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Side(side) => side.fmt(f),
+            Self::Middle => f.write_str("middle"),
+            Self::Category(name) => write!(f, "{:?} category", name),
+            Self::PartOfSpeech(part) => write!(f, "{}", part.abbreviation()),
+        }
+    }
+}
+
+/// A potential problem with a word list, reported by [`MnemonicGenerator::lint`]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Lint {
+    #[error("{side} words {word:?} and {other:?} differ by a single edit")]
+    NearDuplicate {
+        side: WordSide,
+        word: String,
+        other: String,
+    },
+    #[error("{side} word {word:?} is a prefix of {other:?}")]
+    PrefixOf {
+        side: WordSide,
+        word: String,
+        other: String,
+    },
+    #[error("{side} word {word:?} mixes letters of different scripts")]
+    MixedScripts { side: WordSide, word: String },
+    #[error("{side} word {word:?} is {len} characters long")]
+    TooLong {
+        side: WordSide,
+        word: String,
+        len: usize,
+    },
+}
+
+impl MnemonicGenerator {
+    /// Checks both word lists for words that are easily confused or awkward
+    /// in names.
+    ///
+    /// Reports words one edit apart, words that are a prefix of another word
+    /// of the same list (which makes names ambiguous without a separator),
+    /// words mixing Latin, Greek, Cyrillic, or other letters, and words longer
+    /// than twelve characters. Every pair of words within a list is compared.
+    /// Meant for CI checks of custom lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::{Lint, MnemonicGenerator, WordSide};
+    ///
+    /// let generator = MnemonicGenerator::with_words(["brave", "bravo"], ["turing"]);
+    /// assert_eq!(
+    ///     generator.lint(),
+    ///     [Lint::NearDuplicate {
+    ///         side: WordSide::Left,
+    ///         word: "brave".to_string(),
+    ///         other: "bravo".to_string(),
+    ///     }]
+    /// );
+    /// ```
+    pub fn lint(&self) -> Vec<Lint> {
+        let mut lints = lint_list(WordSide::Left, &self.words.left);
+        lints.extend(lint_list(WordSide::Right, &self.words.right));
+        lints
+    }
+}
+
+/// Lints a single list, reporting each problem pair once.
+fn lint_list(side: WordSide, words: &WordList) -> Vec<Lint> {
+    let words: Vec<&str> = words.iter().collect();
+    let mut lints = Vec::new();
+
+    for (idx, &word) in words.iter().enumerate() {
+        let len = word.chars().count();
+        if len > MAX_WORD_LEN {
+            lints.push(Lint::TooLong {
+                side,
+                word: word.to_string(),
+                len,
+            });
+        }
+        if has_mixed_scripts(word) {
+            lints.push(Lint::MixedScripts {
+                side,
+                word: word.to_string(),
+            });
+        }
+
+        for &other in &words[idx + 1..] {
+            let (shorter, longer) = if word.len() <= other.len() {
+                (word, other)
+            } else {
+                (other, word)
+            };
+            if longer.starts_with(shorter) {
+                lints.push(Lint::PrefixOf {
+                    side,
+                    word: shorter.to_string(),
+                    other: longer.to_string(),
+                });
+            } else if levenshtein(word, other) == 1 {
+                lints.push(Lint::NearDuplicate {
+                    side,
+                    word: word.to_string(),
+                    other: other.to_string(),
+                });
+            }
+        }
+    }
+
+    lints
+}
+
+/// Returns `true` if the word contains letters of more than one script.
+fn has_mixed_scripts(word: &str) -> bool {
+    let mut scripts = word.chars().filter(|c| c.is_alphabetic()).map(script);
+    match scripts.next() {
+        Some(first) => scripts.any(|script| script != first),
+        None => false,
+    }
+}
+
+/// Classifies a letter as Latin (`0`), Greek (`1`), Cyrillic (`2`), or other (`3`).
+fn script(c: char) -> u8 {
+    match c {
+        'A'..='Z' | 'a'..='z' | '\u{00C0}'..='\u{024F}' | '\u{1E00}'..='\u{1EFF}' => 0,
+        '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}' => 1,
+        '\u{0400}'..='\u{052F}' => 2,
+        _ => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_prefixes_scripts_and_length() {
+        let generator = MnemonicGenerator::with_words(
+            ["bold", "bolder", "extraordinarily"],
+            ["turing", "tur\u{0456}ng", "curie"],
+        );
+        let lints = generator.lint();
+        assert!(lints.contains(&Lint::PrefixOf {
+            side: WordSide::Left,
+            word: "bold".to_string(),
+            other: "bolder".to_string(),
+        }));
+        assert!(lints.contains(&Lint::TooLong {
+            side: WordSide::Left,
+            word: "extraordinarily".to_string(),
+            len: 15,
+        }));
+        assert!(lints.contains(&Lint::MixedScripts {
+            side: WordSide::Right,
+            word: "tur\u{0456}ng".to_string(),
+        }));
+        assert_eq!(lints.len(), 4);
+    }
+}
@@ -0,0 +1,322 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+
+#[cfg(any(feature = "lang-de", feature = "lang-es", feature = "lang-fr"))]
+use crate::lang;
+use crate::word_list::{WordList, WordLists};
+use crate::{MnemonicError, MnemonicGenerator};
+
+/// A language for which word lists can be registered.
+///
+/// Locales compare and hash by their [`Locale::tag`], so a custom tag matches
+/// whatever its case and whether it is written with `-` or `_`, like the tags
+/// accepted by `FromStr`.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::Locale;
+///
+/// let locale: Locale = "de".parse().unwrap();
+/// assert_eq!(locale, Locale::De);
+/// assert_eq!(Locale::Custom("pt-BR".to_string()).tag(), "pt-br");
+/// assert_eq!(Locale::Custom("pt_BR".to_string()), "pt-br".parse().unwrap());
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Locale {
+    En,
+    De,
+    Es,
+    Fr,
+    /// Any other language, identified by its BCP 47 tag.
+    Custom(String),
+}
+
+impl Locale {
+    /// Returns the lowercase BCP 47 tag of the locale, with subtags separated
+    /// by `-`.
+    pub fn tag(&self) -> String {
+        match self {
+            Self::En => "en".to_string(),
+            Self::De => "de".to_string(),
+            Self::Es => "es".to_string(),
+            Self::Fr => "fr".to_string(),
+            Self::Custom(tag) => normalize_tag(tag),
+        }
+    }
+}
+
+impl PartialEq for Locale {
+    fn eq(&self, other: &Self) -> bool {
+        self.tag() == other.tag()
+    }
+}
+
+impl Eq for Locale {}
+
+impl Hash for Locale {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.tag().hash(state);
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.tag())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = MnemonicError;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        let tag = normalize_tag(tag);
+        match tag.as_str() {
+            "" => Err(MnemonicError::UnsupportedLocale(tag)),
+            "en" => Ok(Self::En),
+            "de" => Ok(Self::De),
+            "es" => Ok(Self::Es),
+            "fr" => Ok(Self::Fr),
+            _ => Ok(Self::Custom(tag)),
+        }
+    }
+}
+
+/// Lowercases a language tag and separates its subtags with `-`.
+fn normalize_tag(tag: &str) -> String {
+    tag.trim().to_lowercase().replace('_', "-")
+}
+
+/// A collection of localized word lists, keyed by [`Locale`].
+///
+/// A registry starts out with the built-in languages. Additional languages can
+/// be added to a local registry, or to the process-wide registry consulted by
+/// [`MnemonicGenerator::for_locale`].
+///
+/// Words may contain any Unicode characters. Lengths are counted in
+/// characters and case conversion is Unicode-aware throughout the crate, so
+/// non-ASCII words behave like ASCII ones with any separator.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{Locale, LocaleRegistry};
+///
+/// let mut registry = LocaleRegistry::new();
+/// registry.register(Locale::Custom("tr".to_string()), ["güzel", "cesur"], ["aydın", "sancar"]);
+///
+/// let generator = registry.generator(&Locale::Custom("tr".to_string())).unwrap();
+/// let mnemonic = generator.generate_with_separator("·").unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocaleRegistry {
+    lists: HashMap<Locale, Arc<WordLists>>,
+}
+
+impl LocaleRegistry {
+    /// Creates a registry containing the built-in languages.
+    ///
+    /// English is included with the `default-words` feature, which is on by
+    /// default. Spanish, German, and French are included when the
+    /// `lang-es`, `lang-de`, and `lang-fr` features are enabled.
+    pub fn new() -> Self {
+        // Every entry is optional, so the map may never be modified.
+        #[allow(unused_mut)]
+        let mut lists = HashMap::new();
+        #[cfg(feature = "default-words")]
+        lists.insert(Locale::En, WordLists::defaults());
+
+        #[cfg(feature = "lang-de")]
+        lists.insert(
+            Locale::De,
+            WordLists::from_static(lang::de::LEFT_WORDS, lang::de::RIGHT_WORDS),
+        );
+        #[cfg(feature = "lang-es")]
+        lists.insert(
+            Locale::Es,
+            WordLists::from_static(lang::es::LEFT_WORDS, lang::es::RIGHT_WORDS),
+        );
+        #[cfg(feature = "lang-fr")]
+        lists.insert(
+            Locale::Fr,
+            WordLists::from_static(lang::fr::LEFT_WORDS, lang::fr::RIGHT_WORDS),
+        );
+
+        Self { lists }
+    }
+
+    /// Registers or replaces the word lists for a locale.
+    pub fn register<L, R>(&mut self, locale: Locale, left_words: L, right_words: R)
+    where
+        L: IntoIterator,
+        L::Item: Into<String>,
+        R: IntoIterator,
+        R::Item: Into<String>,
+    {
+        let lists = WordLists::new(
+            WordList::Owned(left_words.into_iter().map(Into::into).collect()),
+            WordList::Owned(right_words.into_iter().map(Into::into).collect()),
+        );
+        self.lists.insert(locale, lists);
+    }
+
+    /// Registers static word lists for a locale without copying them.
+    pub fn register_static(
+        &mut self,
+        locale: Locale,
+        left_words: &'static [&'static str],
+        right_words: &'static [&'static str],
+    ) {
+        self.lists
+            .insert(locale, WordLists::from_static(left_words, right_words));
+    }
+
+    /// Returns `true` if word lists are registered for the locale.
+    pub fn contains(&self, locale: &Locale) -> bool {
+        self.lists.contains_key(locale)
+    }
+
+    /// Returns the registered locales in no particular order.
+    pub fn locales(&self) -> impl Iterator<Item = &Locale> {
+        self.lists.keys()
+    }
+
+    /// Creates a generator for the locale.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::UnsupportedLocale` if no word lists are
+    /// registered for it
+    pub fn generator(&self, locale: &Locale) -> Result<MnemonicGenerator, MnemonicError> {
+        self.lists
+            .get(locale)
+            .map(|lists| MnemonicGenerator::from_lists(Arc::clone(lists)))
+            .ok_or_else(|| MnemonicError::UnsupportedLocale(locale.tag()))
+    }
+
+    /// Returns the process-wide registry used by [`MnemonicGenerator::for_locale`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::{Locale, LocaleRegistry, MnemonicGenerator};
+    ///
+    /// let pirate = Locale::Custom("x-pirate".to_string());
+    /// LocaleRegistry::global()
+    ///     .write()
+    ///     .unwrap()
+    ///     .register(pirate.clone(), ["salty", "scurvy"], ["blackbeard", "kidd"]);
+    ///
+    /// let generator = MnemonicGenerator::for_locale(pirate).unwrap();
+    /// ```
+    pub fn global() -> &'static RwLock<LocaleRegistry> {
+        static GLOBAL: OnceLock<RwLock<LocaleRegistry>> = OnceLock::new();
+        GLOBAL.get_or_init(|| RwLock::new(LocaleRegistry::new()))
+    }
+}
+
+impl Default for LocaleRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MnemonicGenerator {
+    /// Creates a generator using the word lists registered for `locale` in the
+    /// process-wide [`LocaleRegistry`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::UnsupportedLocale` if no word lists are
+    /// registered for it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::{Locale, MnemonicGenerator};
+    ///
+    /// let generator = MnemonicGenerator::for_locale(Locale::En).unwrap();
+    /// let mnemonic = generator.generate().unwrap();
+    /// # }
+    /// ```
+    pub fn for_locale(locale: Locale) -> Result<Self, MnemonicError> {
+        LocaleRegistry::global()
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .generator(&locale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NamingProfile;
+
+    #[test]
+    #[cfg(feature = "default-words")]
+    fn english_is_built_in() {
+        let registry = LocaleRegistry::new();
+        assert!(registry.contains(&Locale::En));
+        assert!(matches!(
+            registry.generator(&Locale::Custom("xx".to_string())),
+            Err(MnemonicError::UnsupportedLocale(tag)) if tag == "xx"
+        ));
+    }
+
+    #[test]
+    fn custom_tags_match_however_they_are_written() {
+        let mut registry = LocaleRegistry::new();
+        registry.register(Locale::Custom("pt_BR".to_string()), ["feliz"], ["santos"]);
+
+        let locale: Locale = "pt-BR".parse().unwrap();
+        assert!(registry.contains(&locale));
+        assert_eq!(locale, Locale::Custom(" PT-br".to_string()));
+        assert_eq!(Locale::Custom("EN".to_string()), Locale::En);
+    }
+
+    #[test]
+    fn handles_non_ascii_words() {
+        let mut registry = LocaleRegistry::new();
+        registry.register(Locale::De, ["Fröhlich"], ["Müller"]);
+
+        let generator = registry
+            .generator(&Locale::De)
+            .unwrap()
+            .with_length_range(15..=15);
+        assert_eq!(
+            generator.generate_with_separator("·").unwrap(),
+            "Fröhlich·Müller"
+        );
+        assert_eq!(
+            NamingProfile::new(1, 64)
+                .with_lowercase(true)
+                .apply("Fröhlich·Müller"),
+            "fröhlich·müller"
+        );
+    }
+
+    #[cfg(all(feature = "lang-de", feature = "lang-es", feature = "lang-fr"))]
+    #[test]
+    fn language_packs_are_registered() {
+        for locale in [Locale::De, Locale::Es, Locale::Fr] {
+            let generator = MnemonicGenerator::for_locale(locale).unwrap();
+            assert_eq!(generator.generate().unwrap().split('_').count(), 2);
+        }
+    }
+
+    #[test]
+    fn parses_tags() {
+        assert_eq!("FR".parse::<Locale>().unwrap(), Locale::Fr);
+        assert_eq!(
+            "pt_BR".parse::<Locale>().unwrap(),
+            Locale::Custom("pt-br".to_string())
+        );
+        assert!("".parse::<Locale>().is_err());
+    }
+}
@@ -0,0 +1,252 @@
+use rand::RngCore;
+
+use crate::length::{bucket_by_len, LengthWindow};
+use crate::word_list::WordList;
+use crate::{sample, ListName, MnemonicError, MnemonicGenerator, Pick};
+
+/// The words of the middle slot, with their indices grouped by length so
+/// that they can be drawn alongside a length window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MiddleWords {
+    words: WordList,
+    by_len: Vec<Vec<usize>>,
+}
+
+impl MiddleWords {
+    fn new(words: WordList) -> Self {
+        Self {
+            by_len: bucket_by_len(&words),
+            words,
+        }
+    }
+
+    /// Returns the middle words.
+    pub(crate) fn words(&self) -> &WordList {
+        &self.words
+    }
+
+    /// Draws a middle word together with a pair that fits the window beside
+    /// it, weighting each word by the number of such pairs so that every
+    /// fitting name is equally likely.
+    ///
+    /// Returns `None` if no name fits.
+    fn pick_in<G: RngCore + ?Sized>(
+        &self,
+        rng: &mut G,
+        window: &LengthWindow,
+        fixed_len: usize,
+    ) -> Option<Pick> {
+        let fitting = |len: usize| window.count(fixed_len + len);
+        let total: usize = self
+            .by_len
+            .iter()
+            .enumerate()
+            .map(|(len, words)| words.len() * fitting(len))
+            .sum();
+        if total == 0 {
+            return None;
+        }
+
+        let mut target = sample::index(rng, total);
+        for (len, words) in self.by_len.iter().enumerate() {
+            let pairs = fitting(len);
+            if target < words.len() * pairs {
+                let (left, right) = window.pick(rng, fixed_len + len)?;
+                return Some(Pick {
+                    left,
+                    middle: words[target / pairs],
+                    right,
+                });
+            }
+            target -= words.len() * pairs;
+        }
+        unreachable!("the target is below the total weight")
+    }
+}
+
+impl MnemonicGenerator {
+    /// Inserts a word drawn from `words` between the left and right word of
+    /// every name, as in `happy_crimson_turing`.
+    ///
+    /// The middle word is drawn independently of the outer pair, so it
+    /// multiplies the namespace by the number of middle words:
+    /// [`MnemonicGenerator::combination_count`],
+    /// [`MnemonicGenerator::entropy_bits`], ranks, draining, and saved state
+    /// all cover every middle word, and [`MnemonicGenerator::parse`] reports
+    /// it through [`Mnemonic::middle`](crate::Mnemonic::middle).
+    ///
+    /// The middle word is counted by the length window and checked by the
+    /// filters and the naming profile. Duplicate words are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::EmptyList` if `words` is empty,
+    /// `MnemonicError::EmptyWord` or `MnemonicError::WhitespaceInWord` for an
+    /// invalid word, or `MnemonicError::InvalidSeparator` if a word contains
+    /// the default separator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["happy"], ["turing"])
+    ///     .with_middle_words(["crimson", "teal"])
+    ///     .unwrap();
+    /// assert_eq!(generator.combination_count(), 2);
+    /// let name = generator.generate().unwrap();
+    /// assert!(name == "happy_crimson_turing" || name == "happy_teal_turing");
+    /// assert_eq!(generator.parse("happy_teal_turing").unwrap().middle(), Some("teal"));
+    /// ```
+    pub fn with_middle_words<I>(mut self, words: I) -> Result<Self, MnemonicError>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let words = WordList::validated(words)?;
+        if words.is_empty() {
+            return Err(MnemonicError::EmptyList {
+                list: ListName::Middle,
+            });
+        }
+        self.middle = Some(MiddleWords::new(words));
+        self.validate_separator(self.default_separator())?;
+        Ok(self)
+    }
+
+    /// Returns the number of middle words, or `1` without any, so that it can
+    /// be multiplied into the size of the namespace.
+    pub(crate) fn middle_count(&self) -> usize {
+        self.middle.as_ref().map_or(1, |middle| middle.words.len())
+    }
+
+    /// Returns the middle word at `middle_idx`, if middle words are configured.
+    pub(crate) fn middle_part(&self, middle_idx: usize) -> Option<&str> {
+        self.middle
+            .as_ref()
+            .map(|middle| middle.words.get(middle_idx))
+    }
+
+    /// Returns the number of characters the middle word at `middle_idx` adds,
+    /// including its separator.
+    pub(crate) fn middle_len(&self, middle_idx: usize, separator: &str) -> usize {
+        self.middle_part(middle_idx).map_or(0, |middle| {
+            separator.chars().count() + middle.chars().count()
+        })
+    }
+
+    /// Draws the index of the middle word, or returns `0` without middle
+    /// words.
+    pub(crate) fn pick_middle<G: RngCore + ?Sized>(&self, rng: &mut G) -> usize {
+        self.middle
+            .as_ref()
+            .map_or(0, |middle| sample::index(rng, middle.words.len()))
+    }
+
+    /// Draws a name that fits the length window, with `fixed_len` characters
+    /// besides the words and any middle word.
+    ///
+    /// Returns `None` if no name fits.
+    pub(crate) fn pick_in_window<G: RngCore + ?Sized>(
+        &self,
+        rng: &mut G,
+        window: &LengthWindow,
+        fixed_len: usize,
+        separator: &str,
+    ) -> Option<Pick> {
+        match &self.middle {
+            Some(middle) => middle.pick_in(rng, window, fixed_len + separator.chars().count()),
+            None => {
+                let (left, right) = window.pick(rng, fixed_len)?;
+                Some(Pick::pair(left, right))
+            }
+        }
+    }
+
+    /// Finds the position of the middle word that renders as `rendered`.
+    pub(crate) fn find_middle(&self, rendered: &str) -> Option<usize> {
+        let middle = self.middle.as_ref()?;
+        middle
+            .words
+            .iter()
+            .position(|word| self.normalize(word) == rendered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{DrainingGenerator, ListName, MnemonicError, MnemonicGenerator};
+
+    #[test]
+    fn middle_words_round_trip() {
+        let generator = MnemonicGenerator::with_words(["happy", "brave"], ["turing", "bohr"])
+            .with_middle_words(["crimson", "teal", "amber"])
+            .unwrap()
+            .with_reversed_order()
+            .unwrap()
+            .with_separator("-")
+            .unwrap();
+        for rank in 0..12 {
+            let mnemonic = generator.unrank(rank).unwrap();
+            let parts: Vec<&str> = mnemonic.as_str().split('-').collect();
+            assert_eq!(
+                parts,
+                [
+                    mnemonic.right(),
+                    mnemonic.middle().unwrap(),
+                    mnemonic.left()
+                ]
+            );
+            assert_eq!(generator.parse(mnemonic.as_str()).unwrap(), mnemonic);
+            assert_eq!(generator.rank(&mnemonic).unwrap(), rank);
+        }
+        assert!(generator.unrank(12).is_err());
+        assert!(matches!(
+            generator.parse("turing-indigo-happy"),
+            Err(MnemonicError::InvalidMnemonic { position: 7, .. })
+        ));
+    }
+
+    #[test]
+    fn middle_words_are_drawn_independently() {
+        let generator = MnemonicGenerator::with_words(["happy", "brave"], ["turing", "bohr"]);
+        let colored = generator
+            .clone()
+            .with_middle_words(["crimson", "teal", "amber"])
+            .unwrap();
+        assert_eq!(colored.combination_count(), 12);
+        assert!(colored.entropy_bits() > generator.entropy_bits() + 1.5);
+
+        let mut draining = DrainingGenerator::new(colored);
+        let names: HashSet<String> = (0..12).map(|_| draining.generate().unwrap()).collect();
+        assert_eq!(names.len(), 12);
+        assert!(draining.generate().is_err());
+
+        // Only the short middle word leaves room for the pair.
+        let windowed = MnemonicGenerator::with_words(["a"], ["b"])
+            .with_middle_words(["x", "yyy"])
+            .unwrap()
+            .with_length_range(5..=5);
+        assert_eq!(windowed.combination_count(), 1);
+        for _ in 0..20 {
+            assert_eq!(windowed.generate().unwrap(), "a_x_b");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_middle_words() {
+        let generator = MnemonicGenerator::with_words(["happy"], ["turing"]);
+        assert!(matches!(
+            generator.clone().with_middle_words(Vec::<String>::new()),
+            Err(MnemonicError::EmptyList {
+                list: ListName::Middle
+            })
+        ));
+        assert!(matches!(
+            generator.with_middle_words(["dark_red"]),
+            Err(MnemonicError::InvalidSeparator { .. })
+        ));
+    }
+}
@@ -0,0 +1,32 @@
+//! Minimal built-in word lists, enabled by the `mini-words` feature.
+//!
+//! Sixteen short adjectives and sixteen short surnames, all drawn from the
+//! full default lists, for microcontrollers and size-sensitive WebAssembly
+//! builds. They are used by [`MnemonicGenerator::new`](crate::MnemonicGenerator::new)
+//! only when the `default-words` feature is disabled.
+
+/// Adjectives used as the left-hand side of default mnemonics.
+pub(crate) static LEFT_WORDS: &[&str] = &[
+    "bold", "brave", "clever", "cool", "eager", "funny", "happy", "jolly", "keen", "kind", "lucid",
+    "nifty", "serene", "sharp", "sleepy", "zen",
+];
+
+/// Surnames of notable scientists and hackers used as the right-hand side of
+/// default mnemonics.
+pub(crate) static RIGHT_WORDS: &[&str] = &[
+    "bohr", "curie", "darwin", "dirac", "euler", "fermi", "gauss", "hopper", "kepler", "lovelace",
+    "newton", "noether", "pasteur", "raman", "tesla", "turing",
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::MnemonicGenerator;
+
+    #[test]
+    fn defaults_to_the_mini_lists() {
+        let generator = MnemonicGenerator::new();
+        assert_eq!(generator.combination_count(), 256);
+        assert!(generator.lint().is_empty());
+        assert_eq!(generator.parse("zen_turing").unwrap().right(), "turing");
+    }
+}
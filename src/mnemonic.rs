@@ -0,0 +1,334 @@
+use std::fmt;
+use std::hash::Hasher;
+#[cfg(feature = "default-words")]
+use std::str::FromStr;
+
+use crate::named::Fnv1aHasher;
+#[cfg(feature = "default-words")]
+use crate::shared_default;
+use crate::{MnemonicError, MnemonicGenerator, NamingProfile, WordSide};
+
+/// A name that has been parsed back into the words it was generated from.
+///
+/// Displays as the full name, including any prefix and suffix, exactly as it
+/// was parsed.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "default-words")]
+/// # {
+/// use mnemonic_generator::MnemonicGenerator;
+///
+/// let generator = MnemonicGenerator::new();
+/// let mnemonic = generator.parse("hopeful_turing").unwrap();
+/// assert_eq!((mnemonic.left(), mnemonic.right()), ("hopeful", "turing"));
+/// assert_eq!(mnemonic.to_string(), "hopeful_turing");
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
+pub struct Mnemonic {
+    name: String,
+    left: String,
+    middle: Option<String>,
+    right: String,
+}
+
+impl Mnemonic {
+    /// Creates a mnemonic from a rendered name and the words it consists of.
+    pub(crate) fn new(name: String, left: &str, right: &str) -> Self {
+        Self {
+            name,
+            left: left.to_string(),
+            middle: None,
+            right: right.to_string(),
+        }
+    }
+
+    /// Sets the middle word the name consists of, if any.
+    pub(crate) fn with_middle(mut self, middle: Option<&str>) -> Self {
+        self.middle = middle.map(str::to_string);
+        self
+    }
+
+    /// Returns the full name.
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the left word as it appears in the generator's word list.
+    pub fn left(&self) -> &str {
+        &self.left
+    }
+
+    /// Returns the middle word as it appears in the generator's middle words,
+    /// if the generator has any.
+    pub fn middle(&self) -> Option<&str> {
+        self.middle.as_deref()
+    }
+
+    /// Returns the right word as it appears in the generator's word list.
+    pub fn right(&self) -> &str {
+        &self.right
+    }
+
+    /// Hashes the full name with 64-bit FNV-1a over its UTF-8 bytes.
+    ///
+    /// Unlike the `Hash` implementation, whose output depends on the hasher
+    /// and is randomized per process by `std`'s `HashMap`, this hash is fixed:
+    /// the same name hashes to the same value in every process and release, so
+    /// it can pick a shard or bucket that other services agree on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let mnemonic = MnemonicGenerator::new().parse("hopeful_turing").unwrap();
+    /// assert_eq!(mnemonic.stable_hash64(), 0x4db3_0a75_ec12_9d36);
+    /// let shard = mnemonic.stable_hash64() % 16;
+    /// # }
+    /// ```
+    pub fn stable_hash64(&self) -> u64 {
+        let mut hasher = Fnv1aHasher::default();
+        hasher.write(self.name.as_bytes());
+        hasher.finish()
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+impl AsRef<str> for Mnemonic {
+    fn as_ref(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(feature = "default-words")]
+impl FromStr for Mnemonic {
+    type Err = MnemonicError;
+
+    /// Parses a name produced by the default generator.
+    ///
+    /// Use [`MnemonicGenerator::parse`] for names from customized generators.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        shared_default().parse(name)
+    }
+}
+
+impl From<Mnemonic> for String {
+    fn from(mnemonic: Mnemonic) -> Self {
+        mnemonic.name
+    }
+}
+
+impl MnemonicGenerator {
+    /// Parses a name produced by `generate()` back into its words.
+    ///
+    /// The name must carry the configured prefix, suffix, timestamp, and
+    /// checksum, use the default separator, and consist of words from this
+    /// generator's lists in the profile's case. Registered transforms cannot be
+    /// undone and are not taken into account.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidMnemonic` describing why the name does
+    /// not belong to this generator and the byte offset where parsing failed,
+    /// or `MnemonicError::ChecksumMismatch` if its checksum does not match its
+    /// words
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::new().with_prefix("prod-");
+    /// let mnemonic = generator.parse("prod-hopeful_turing").unwrap();
+    /// assert_eq!(mnemonic.right(), "turing");
+    ///
+    /// assert!(generator.parse("hopeful_turing").is_err());
+    /// # }
+    /// ```
+    pub fn parse(&self, name: &str) -> Result<Mnemonic, MnemonicError> {
+        let invalid = |part: &str, reason: String| MnemonicError::InvalidMnemonic {
+            name: name.to_string(),
+            position: offset_of(name, part),
+            reason,
+        };
+
+        let prefix = self.normalize(&self.prefix);
+        let suffix = self.normalize(&self.suffix);
+        let separator = self.normalize(self.default_separator());
+
+        let words = name
+            .strip_prefix(prefix.as_str())
+            .ok_or_else(|| invalid(name, format!("missing prefix {:?}", prefix)))?;
+        let words = words
+            .strip_suffix(suffix.as_str())
+            .ok_or_else(|| invalid(&name[name.len()..], format!("missing suffix {:?}", suffix)))?;
+        let (_, words) = self
+            .split_timestamp(words, &separator)
+            .ok_or_else(|| invalid(words, "missing or unknown timestamp words".to_string()))?;
+        let (words, checksum) = match self.checksum {
+            Some(_) => {
+                let (words, checksum) = words.rsplit_once(separator.as_str()).ok_or_else(|| {
+                    invalid(&words[words.len()..], "missing checksum".to_string())
+                })?;
+                (words, Some(checksum))
+            }
+            None => (words, None),
+        };
+        let (first, second) = words
+            .split_once(separator.as_str())
+            .ok_or_else(|| invalid(words, format!("missing separator {:?}", separator)))?;
+        let (middle, second) = match self.middle {
+            Some(_) => {
+                let (middle, second) = second
+                    .split_once(separator.as_str())
+                    .ok_or_else(|| invalid(second, format!("missing separator {:?}", separator)))?;
+                (Some(middle), second)
+            }
+            None => (None, second),
+        };
+        let (left, right) = self.ordered(first, second);
+
+        let (left_idx, left) = self
+            .find_word(WordSide::Left, left)
+            .ok_or_else(|| invalid(left, format!("unknown left word {:?}", left)))?;
+        let (right_idx, right) = self
+            .find_word(WordSide::Right, right)
+            .ok_or_else(|| invalid(right, format!("unknown right word {:?}", right)))?;
+
+        let middle = match middle {
+            Some(middle) => {
+                let middle_idx = self
+                    .find_middle(middle)
+                    .ok_or_else(|| invalid(middle, format!("unknown middle word {:?}", middle)))?;
+                self.middle_part(middle_idx)
+            }
+            None => None,
+        };
+
+        if let Some(checksum) = checksum {
+            if !self.checksum_matches(left_idx, right_idx, checksum) {
+                return Err(MnemonicError::ChecksumMismatch(name.to_string()));
+            }
+        }
+
+        Ok(Mnemonic::new(name.to_string(), left, right).with_middle(middle))
+    }
+
+    /// Returns `true` if [`MnemonicGenerator::parse`] accepts the name.
+    pub fn is_valid_name(&self, name: &str) -> bool {
+        self.parse(name).is_ok()
+    }
+
+    /// Normalizes part of a name to the profile's case.
+    pub(crate) fn normalize(&self, part: &str) -> String {
+        match &self.profile {
+            Some(profile) => profile.apply(part),
+            None => part.to_string(),
+        }
+    }
+
+    /// Finds the position and spelling of the word of one side that renders as
+    /// `rendered`, by binary search over the list's sorted index.
+    pub(crate) fn find_word(&self, side: WordSide, rendered: &str) -> Option<(usize, &str)> {
+        let idx = if self
+            .profile
+            .as_ref()
+            .is_some_and(NamingProfile::is_lowercase)
+        {
+            self.words.folded_position(side, rendered)?
+        } else {
+            self.words.position(side, rendered)?
+        };
+        Some((idx, self.words.side(side).get(idx)))
+    }
+}
+
+/// Returns the byte offset of `part` within `name`, of which it must be a slice.
+pub(crate) fn offset_of(name: &str, part: &str) -> usize {
+    part.as_ptr() as usize - name.as_ptr() as usize
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use crate::{MnemonicError, MnemonicGenerator, NamingProfile};
+
+    #[test]
+    fn round_trips_generated_names() {
+        let generator = MnemonicGenerator::with_words(["Hopeful", "brave"], ["Turing", "hopper"])
+            .with_profile(NamingProfile::rfc1123_hostname())
+            .unwrap()
+            .with_prefix("Prod-")
+            .with_suffix("-eu1");
+        for _ in 0..20 {
+            let name = generator.generate().unwrap();
+            assert!(name.starts_with("prod-") && name.ends_with("-eu1"));
+            assert_eq!(generator.parse(&name).unwrap().to_string(), name);
+        }
+        assert_eq!(
+            generator.parse("prod-hopeful-turing-eu1").unwrap().left(),
+            "Hopeful"
+        );
+    }
+
+    #[test]
+    fn parses_default_names_from_str() {
+        let mnemonic: crate::Mnemonic = "hopeful_turing".parse().unwrap();
+        assert_eq!(mnemonic.left(), "hopeful");
+        assert!("hopeful-turing".parse::<crate::Mnemonic>().is_err());
+    }
+
+    #[test]
+    fn stable_hash_covers_the_full_name() {
+        let mnemonic: crate::Mnemonic = "hopeful_turing".parse().unwrap();
+        assert_eq!(mnemonic.stable_hash64(), 0x4db3_0a75_ec12_9d36);
+        let suffixed = MnemonicGenerator::new()
+            .with_suffix("-eu1")
+            .parse("hopeful_turing-eu1")
+            .unwrap();
+        assert_ne!(suffixed.stable_hash64(), mnemonic.stable_hash64());
+    }
+
+    #[test]
+    fn rejects_foreign_names() {
+        let generator = MnemonicGenerator::new().with_suffix("-eu1");
+        assert!(matches!(
+            generator.parse("hopeful_turing"),
+            Err(MnemonicError::InvalidMnemonic { reason, .. }) if reason.contains("suffix")
+        ));
+        assert!(!generator.is_valid_name("hopeful_nobody-eu1"));
+        assert!(generator.is_valid_name("hopeful_turing-eu1"));
+    }
+
+    #[test]
+    fn reports_where_parsing_failed() {
+        let generator = MnemonicGenerator::new().with_prefix("prod-");
+        assert!(matches!(
+            generator.parse("prod-hopeful_nobody"),
+            Err(MnemonicError::InvalidMnemonic { position: 13, .. })
+        ));
+        assert_eq!(
+            generator
+                .parse("dev-hopeful_turing")
+                .unwrap_err()
+                .to_string(),
+            "\"dev-hopeful_turing\" is not a valid mnemonic: missing prefix \"prod-\" at byte 0"
+        );
+    }
+}
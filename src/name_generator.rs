@@ -0,0 +1,144 @@
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::{
+    CategoryGenerator, DrainingGenerator, GeneratorPool, Grammar, HistoryGenerator, MnemonicError,
+    MnemonicGenerator, NonEmptyMnemonicGenerator, SeededGenerator,
+};
+
+/// A source of names, implemented by every generator of the crate so that
+/// applications can hold a `Box<dyn NameGenerator>` and choose the kind of
+/// generator at runtime.
+///
+/// Stateful generators, such as [`SeededGenerator`], [`DrainingGenerator`],
+/// and [`HistoryGenerator`], need `&mut self` to advance; wrap them in a
+/// [`Mutex`] to use them through this trait.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Mutex;
+///
+/// use mnemonic_generator::{MnemonicGenerator, NameGenerator, SeededGenerator};
+///
+/// let words = || MnemonicGenerator::with_words(["happy", "brave"], ["turing", "hopper"]);
+/// let reproducible = std::env::var("NAME_SEED").is_ok();
+/// let generator: Box<dyn NameGenerator> = if reproducible {
+///     Box::new(Mutex::new(SeededGenerator::new(words(), 42)))
+/// } else {
+///     Box::new(words())
+/// };
+/// assert!(generator.generate().unwrap().contains('_'));
+/// ```
+pub trait NameGenerator: Send + Sync {
+    /// Generates the next name.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of the underlying generator, such as
+    /// `MnemonicError::EmptyList` or `MnemonicError::NamespaceExhausted`
+    fn generate(&self) -> Result<String, MnemonicError>;
+}
+
+impl NameGenerator for MnemonicGenerator {
+    fn generate(&self) -> Result<String, MnemonicError> {
+        MnemonicGenerator::generate(self)
+    }
+}
+
+impl NameGenerator for NonEmptyMnemonicGenerator {
+    fn generate(&self) -> Result<String, MnemonicError> {
+        Ok(NonEmptyMnemonicGenerator::generate(self))
+    }
+}
+
+impl NameGenerator for CategoryGenerator {
+    fn generate(&self) -> Result<String, MnemonicError> {
+        CategoryGenerator::generate(self)
+    }
+}
+
+impl NameGenerator for Grammar {
+    fn generate(&self) -> Result<String, MnemonicError> {
+        Grammar::generate(self)
+    }
+}
+
+impl NameGenerator for GeneratorPool {
+    /// Checks out a shard for a single name, see [`GeneratorPool::checkout`].
+    fn generate(&self) -> Result<String, MnemonicError> {
+        self.checkout().generate()
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl NameGenerator for crate::RateLimitedGenerator {
+    /// Generates a name if the rate limit allows it right now, see
+    /// [`RateLimitedGenerator::try_generate`](crate::RateLimitedGenerator::try_generate).
+    fn generate(&self) -> Result<String, MnemonicError> {
+        self.try_generate()
+    }
+}
+
+impl NameGenerator for Mutex<SeededGenerator> {
+    fn generate(&self) -> Result<String, MnemonicError> {
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .generate()
+    }
+}
+
+impl NameGenerator for Mutex<DrainingGenerator> {
+    fn generate(&self) -> Result<String, MnemonicError> {
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .generate()
+    }
+}
+
+impl NameGenerator for Mutex<HistoryGenerator> {
+    fn generate(&self) -> Result<String, MnemonicError> {
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .generate()
+    }
+}
+
+impl<G: NameGenerator + ?Sized> NameGenerator for Arc<G> {
+    fn generate(&self) -> Result<String, MnemonicError> {
+        (**self).generate()
+    }
+}
+
+impl<G: NameGenerator + ?Sized> NameGenerator for Box<G> {
+    fn generate(&self) -> Result<String, MnemonicError> {
+        (**self).generate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generators_are_interchangeable() {
+        let words = || MnemonicGenerator::with_words(["happy"], ["turing"]);
+        let generators: Vec<Box<dyn NameGenerator>> = vec![
+            Box::new(words()),
+            Box::new(words().into_shared()),
+            Box::new(Mutex::new(SeededGenerator::new(words(), 7))),
+            Box::new(Mutex::new(HistoryGenerator::new(words(), 0))),
+            Box::new(GeneratorPool::new(words().into_shared(), 2)),
+        ];
+        for generator in &generators {
+            assert_eq!(generator.generate().unwrap(), "happy_turing");
+        }
+
+        let draining: Box<dyn NameGenerator> =
+            Box::new(Mutex::new(DrainingGenerator::new(words())));
+        assert_eq!(draining.generate().unwrap(), "happy_turing");
+        assert!(matches!(
+            draining.generate(),
+            Err(MnemonicError::NamespaceExhausted { issued: 1 })
+        ));
+    }
+}
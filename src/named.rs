@@ -0,0 +1,109 @@
+use std::borrow::Cow;
+#[cfg(feature = "default-words")]
+use std::hash::{Hash, Hasher};
+
+pub(crate) use mnemonic_generator_words::Fnv1aHasher;
+
+#[cfg(feature = "default-words")]
+use crate::shared_default;
+
+/// A type whose instances carry a friendly mnemonic name.
+///
+/// With the `macros` feature enabled, `#[derive(MnemonicNamed)]` implements
+/// this trait either from the value's `Hash` implementation or from a lazily
+/// initialized `OnceLock<String>` field marked `#[mnemonic_name]`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "default-words")]
+/// # {
+/// use std::borrow::Cow;
+///
+/// use mnemonic_generator::{name_for, Named};
+///
+/// struct Actor {
+///     id: u64,
+/// }
+///
+/// impl Named for Actor {
+///     fn name(&self) -> Cow<'_, str> {
+///         Cow::Owned(name_for(&self.id))
+///     }
+/// }
+///
+/// assert_eq!(Actor { id: 1 }.name(), Actor { id: 1 }.name());
+/// # }
+/// ```
+pub trait Named {
+    /// Returns the name of this instance.
+    fn name(&self) -> Cow<'_, str>;
+}
+
+/// Derives a name from the default word lists by hashing `value`.
+///
+/// Equal values always get the same name. The value is hashed with 64-bit
+/// FNV-1a, which does not depend on the process, the platform, or the Rust
+/// release, so names are reproducible as long as the value's `Hash`
+/// implementation and the default word lists are. Pin the lists with
+/// [`WordlistVersion`](crate::WordlistVersion) through
+/// [`MnemonicGenerator::new_versioned`](crate::MnemonicGenerator::new_versioned)
+/// and hash yourself if names must outlive list updates.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "default-words")]
+/// # {
+/// let name = mnemonic_generator::name_for("job-42");
+/// assert_eq!(name, mnemonic_generator::name_for("job-42"));
+/// # }
+/// ```
+#[cfg(feature = "default-words")]
+pub fn name_for<T: Hash + ?Sized>(value: &T) -> String {
+    let mut hasher = Fnv1aHasher::default();
+    value.hash(&mut hasher);
+
+    let generator = shared_default();
+    generator
+        .unrank(hasher.finish() % generator.rank_count())
+        .expect("rank is within the default namespace")
+        .into()
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_names_are_stable() {
+        assert_eq!(name_for("job-42"), "gallant_chatelet");
+        assert_eq!(name_for(&1u64), "blissful_hellman");
+        assert_eq!(name_for(&(7u8, [1usize, 2])), "exciting_cerf");
+        assert!(crate::MnemonicGenerator::new().is_valid_name(&name_for(&7u8)));
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn derives_named() {
+        use std::sync::OnceLock;
+
+        use crate::MnemonicNamed;
+
+        #[derive(Hash, MnemonicNamed)]
+        struct Job<T> {
+            id: T,
+        }
+
+        #[derive(Default, MnemonicNamed)]
+        struct Worker {
+            #[mnemonic_name]
+            name: OnceLock<String>,
+        }
+
+        assert_eq!(Job { id: 7 }.name(), name_for(&Job { id: 7 }));
+        let worker = Worker::default();
+        assert_eq!(worker.name(), worker.name());
+        assert!(matches!(worker.name(), Cow::Borrowed(_)));
+    }
+}
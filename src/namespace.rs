@@ -0,0 +1,362 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+
+use crate::seeded::expand_seed;
+use crate::{sample, Mnemonic, MnemonicError, MnemonicGenerator, Pick, WordSide};
+
+impl MnemonicGenerator {
+    /// Returns the number of distinct names this generator can produce with
+    /// its default separator.
+    ///
+    /// Every middle word multiplies the count. Names excluded by the length
+    /// window, the syllable limit, the pronounceability check, the profanity
+    /// filter, or the naming profile are not counted. When any of these are
+    /// configured, every name is checked, so the cost grows with the product
+    /// of the list sizes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["amazing", "legend"], ["jordan", "bird", "larry"]);
+    /// assert_eq!(generator.combination_count(), 6);
+    /// ```
+    pub fn combination_count(&self) -> u128 {
+        if self.is_unfiltered() {
+            return self.pick_count();
+        }
+
+        let separator = self.default_separator();
+        self.picks()
+            .filter(|&pick| self.is_reachable(pick, separator))
+            .count() as u128
+    }
+
+    /// Returns the entropy of a uniformly drawn name in bits, that is the
+    /// base-2 logarithm of [`MnemonicGenerator::combination_count`].
+    ///
+    /// Returns `0.0` for a generator that cannot produce any name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::new();
+    /// assert!(generator.entropy_bits() > 14.0);
+    /// # }
+    /// ```
+    pub fn entropy_bits(&self) -> f64 {
+        match self.combination_count() {
+            0 => 0.0,
+            count => (count as f64).log2(),
+        }
+    }
+
+    /// Returns the position of a mnemonic among all combinations of words,
+    /// ordered by the position of the left word, then of any middle word, and
+    /// then of the right word in their lists.
+    ///
+    /// Ranks cover every combination of words, including those that the
+    /// configured filters would reject, and range from `0` to the product of
+    /// the list sizes. They stay stable as long as the word lists do not
+    /// change.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidMnemonic` if a word is not part of this
+    /// generator's lists, or if the mnemonic lacks the middle word this
+    /// generator expects
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["amazing", "legend"], ["jordan", "bird", "larry"]);
+    /// let mnemonic = generator.parse("legend_bird").unwrap();
+    /// assert_eq!(generator.rank(&mnemonic).unwrap(), 4);
+    /// assert_eq!(generator.unrank(4).unwrap(), mnemonic);
+    /// ```
+    pub fn rank(&self, mnemonic: &Mnemonic) -> Result<u64, MnemonicError> {
+        let position = |side, word: &str| {
+            self.find_word(side, &self.normalize(word))
+                .map(|(idx, _)| idx)
+                .ok_or_else(|| MnemonicError::InvalidMnemonic {
+                    name: mnemonic.to_string(),
+                    position: mnemonic.as_str().find(word).unwrap_or(0),
+                    reason: format!("unknown {} word {:?}", side, word),
+                })
+        };
+        let left = position(WordSide::Left, mnemonic.left())?;
+        let right = position(WordSide::Right, mnemonic.right())?;
+        let middle = match (&self.middle, mnemonic.middle()) {
+            (None, _) => 0,
+            (Some(_), Some(word)) => self.find_middle(&self.normalize(word)).ok_or_else(|| {
+                MnemonicError::InvalidMnemonic {
+                    name: mnemonic.to_string(),
+                    position: mnemonic.as_str().find(word).unwrap_or(0),
+                    reason: format!("unknown middle word {:?}", word),
+                }
+            })?,
+            (Some(_), None) => {
+                return Err(MnemonicError::InvalidMnemonic {
+                    name: mnemonic.to_string(),
+                    position: 0,
+                    reason: "missing middle word".to_string(),
+                })
+            }
+        };
+        Ok(self.rank_of(Pick {
+            left,
+            middle,
+            right,
+        }))
+    }
+
+    /// Returns the mnemonic at the given position, the inverse of
+    /// [`MnemonicGenerator::rank`].
+    ///
+    /// The name is rendered exactly as `generate()` would render it, but the
+    /// configured filters are not consulted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::RankOutOfRange` if `rank` is not smaller than the
+    /// number of combinations of words
+    pub fn unrank(&self, rank: u64) -> Result<Mnemonic, MnemonicError> {
+        let count = self.rank_count();
+        if rank >= count {
+            return Err(MnemonicError::RankOutOfRange { rank, count });
+        }
+        Ok(self.mnemonic(self.pick_at(rank), self.default_separator()))
+    }
+
+    /// Returns the mnemonics of one page of the namespace, in rank order.
+    ///
+    /// Page `page` holds the ranks from `page * page_size` up to the next page,
+    /// so a UI can browse every combination of words without materializing
+    /// them all. Like [`MnemonicGenerator::unrank`], this covers combinations
+    /// that the configured filters would reject. Pages past the end are empty and the
+    /// last page may be short.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["amazing", "legend"], ["jordan", "bird", "larry"]);
+    /// let page: Vec<String> = generator.page(1, 4).into_iter().map(String::from).collect();
+    /// assert_eq!(page, ["legend_bird", "legend_larry"]);
+    /// assert!(generator.page(2, 4).is_empty());
+    /// ```
+    pub fn page(&self, page: usize, page_size: usize) -> Vec<Mnemonic> {
+        let count = self.rank_count();
+        let Some(start) = (page as u64).checked_mul(page_size as u64) else {
+            return Vec::new();
+        };
+        let end = start.saturating_add(page_size as u64).min(count);
+
+        (start..end)
+            .map(|rank| self.unrank(rank).expect("rank is below the rank count"))
+            .collect()
+    }
+
+    /// Returns every name this generator can produce, with the default
+    /// separator, in a random order determined by `seed`.
+    ///
+    /// The names are those counted by [`MnemonicGenerator::combination_count`],
+    /// each exactly once, so fixtures and documentation examples can list a
+    /// namespace that looks random but is the same on every run. The order
+    /// follows the stability guarantee of [`SeededGenerator`](crate::SeededGenerator):
+    /// a seed and configuration yield the same order on every platform and
+    /// across patch releases. Timestamp words still reflect the current time.
+    ///
+    /// Every name is held in memory at once, and with filters configured
+    /// every combination is checked, so the cost grows with the product of the list
+    /// sizes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["amazing", "legend"], ["jordan", "bird", "larry"]);
+    /// let names = generator.generate_shuffled_all(7);
+    /// assert_eq!(names.len(), 6);
+    /// assert_eq!(names, generator.generate_shuffled_all(7));
+    /// ```
+    pub fn generate_shuffled_all(&self, seed: u64) -> Vec<String> {
+        let separator = self.default_separator();
+        let mut picks = self.reachable_picks(separator);
+
+        // Fisher-Yates, drawing indices with `sample::index` so that the order
+        // does not depend on rand's shuffling code.
+        let mut rng = ChaCha12Rng::from_seed(expand_seed(seed));
+        for idx in (1..picks.len()).rev() {
+            picks.swap(idx, sample::index(&mut rng, idx + 1));
+        }
+
+        picks
+            .into_iter()
+            .map(|pick| self.render(pick, separator))
+            .collect()
+    }
+
+    /// Returns the number of combinations of words, the product of the list
+    /// sizes.
+    pub(crate) fn pick_count(&self) -> u128 {
+        self.words.left.len() as u128 * self.middle_count() as u128 * self.words.right.len() as u128
+    }
+
+    /// Returns the number of ranks, the product of the list sizes.
+    pub(crate) fn rank_count(&self) -> u64 {
+        self.words.left.len() as u64 * self.middle_count() as u64 * self.words.right.len() as u64
+    }
+
+    /// Returns the combination of words at `rank`, which must be below the
+    /// rank count.
+    pub(crate) fn pick_at(&self, rank: u64) -> Pick {
+        let (middle_len, right_len) = (self.middle_count() as u64, self.words.right.len() as u64);
+        Pick {
+            left: (rank / right_len / middle_len) as usize,
+            middle: (rank / right_len % middle_len) as usize,
+            right: (rank % right_len) as usize,
+        }
+    }
+
+    /// Returns the rank of a combination of words, the inverse of
+    /// [`MnemonicGenerator::pick_at`].
+    pub(crate) fn rank_of(&self, pick: Pick) -> u64 {
+        (pick.left as u64 * self.middle_count() as u64 + pick.middle as u64)
+            * self.words.right.len() as u64
+            + pick.right as u64
+    }
+
+    /// Returns every combination of words in rank order.
+    pub(crate) fn picks(&self) -> impl Iterator<Item = Pick> + '_ {
+        (0..self.rank_count()).map(|rank| self.pick_at(rank))
+    }
+
+    /// Returns the mnemonic of a combination of words, rendered with
+    /// `separator`.
+    pub(crate) fn mnemonic(&self, pick: Pick, separator: &str) -> Mnemonic {
+        Mnemonic::new(
+            self.render(pick, separator),
+            self.words.left.get(pick.left),
+            self.words.right.get(pick.right),
+        )
+        .with_middle(self.middle_part(pick.middle))
+    }
+
+    /// Returns every combination that can be produced with the given
+    /// separator, in rank order.
+    pub(crate) fn reachable_picks(&self, separator: &str) -> Vec<Pick> {
+        self.picks()
+            .filter(|&pick| self.is_reachable(pick, separator))
+            .collect()
+    }
+
+    /// Returns `true` if the combination can be produced with the given
+    /// separator.
+    pub(crate) fn is_reachable(&self, pick: Pick, separator: &str) -> bool {
+        if let Some(window) = &self.length_window {
+            let (left, right) = (
+                self.words.left.get(pick.left),
+                self.words.right.get(pick.right),
+            );
+            let fixed_len = self.fixed_len(separator)
+                + self.middle_len(pick.middle, separator)
+                + self.checksum_len(pick.left, pick.right, separator);
+            if !window.fits(left, right, fixed_len) {
+                return false;
+            }
+        }
+        self.accepts(pick, separator)
+    }
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use crate::{Mnemonic, MnemonicError, MnemonicGenerator, NamingProfile, ProfanityFilter};
+
+    #[test]
+    fn pages_cover_every_rank_once() {
+        let generator = MnemonicGenerator::new();
+        let count = generator.combination_count() as usize;
+        let names: Vec<Mnemonic> = (0..)
+            .map(|page| generator.page(page, 1000))
+            .take_while(|page| !page.is_empty())
+            .flatten()
+            .collect();
+        assert_eq!(names.len(), count);
+        assert_eq!(names[1234], generator.unrank(1234).unwrap());
+        assert!(generator.page(usize::MAX, usize::MAX).is_empty());
+    }
+
+    #[test]
+    fn shuffles_the_whole_namespace_by_seed() {
+        let generator = MnemonicGenerator::new().with_profanity_filter(ProfanityFilter::new());
+        let names = generator.generate_shuffled_all(42);
+        assert_eq!(names.len() as u128, generator.combination_count());
+        assert_eq!(names, generator.generate_shuffled_all(42));
+        assert_ne!(names, generator.generate_shuffled_all(43));
+
+        let mut sorted = names.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), names.len());
+        assert_ne!(sorted, names);
+
+        // The order is part of the stability guarantee.
+        let generator = MnemonicGenerator::with_words(["a", "b"], ["x", "y", "z"]);
+        assert_eq!(
+            generator.generate_shuffled_all(7),
+            ["b_y", "b_x", "a_y", "a_z", "a_x", "b_z"]
+        );
+    }
+
+    #[test]
+    fn counts_default_namespace() {
+        let generator = MnemonicGenerator::new();
+        assert_eq!(generator.combination_count(), 108 * 237);
+        assert!((generator.entropy_bits() - (108.0f64 * 237.0).log2()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn excludes_filtered_pairs() {
+        let generator = MnemonicGenerator::with_words(["boring", "happy"], ["wozniak", "turing"])
+            .with_profanity_filter(ProfanityFilter::new());
+        assert_eq!(generator.combination_count(), 3);
+
+        let generator = MnemonicGenerator::with_words(["bold", "xenodochial"], ["bohr"])
+            .with_length_range(0..=9)
+            .with_profile(NamingProfile::rfc1123_hostname())
+            .unwrap();
+        assert_eq!(generator.combination_count(), 1);
+    }
+
+    #[test]
+    fn rank_round_trips() {
+        let generator = MnemonicGenerator::new().with_prefix("prod-");
+        for rank in [0, 1, 236, 237, 108 * 237 - 1] {
+            let mnemonic = generator.unrank(rank).unwrap();
+            assert_eq!(generator.parse(mnemonic.as_str()).unwrap(), mnemonic);
+            assert_eq!(generator.rank(&mnemonic).unwrap(), rank);
+        }
+        assert!(matches!(
+            generator.unrank(108 * 237),
+            Err(MnemonicError::RankOutOfRange { count, .. }) if count == 108 * 237
+        ));
+    }
+
+    #[test]
+    fn empty_namespace_has_no_entropy() {
+        let generator = MnemonicGenerator::with_words(Vec::<String>::new(), ["turing"]);
+        assert_eq!(generator.combination_count(), 0);
+        assert_eq!(generator.entropy_bits(), 0.0);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{MnemonicError, MnemonicGenerator};
+
+/// A generator that always produces a name.
+///
+/// Both word lists are known to be non-empty and no filter can reject a
+/// candidate, so [`NonEmptyMnemonicGenerator::generate`] returns the name
+/// directly instead of a `Result`. The check happens once, when the generator
+/// is constructed.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{MnemonicGenerator, NonEmptyMnemonicGenerator};
+///
+/// let generator = NonEmptyMnemonicGenerator::try_with_words(["happy"], ["turing"]).unwrap();
+/// assert_eq!(generator.generate(), "happy_turing");
+///
+/// let empty = MnemonicGenerator::with_words(Vec::<String>::new(), ["turing"]);
+/// assert!(NonEmptyMnemonicGenerator::try_from(empty).is_err());
+/// ```
+pub struct NonEmptyMnemonicGenerator {
+    generator: MnemonicGenerator,
+}
+
+impl NonEmptyMnemonicGenerator {
+    /// Creates a generator over the built-in word lists, which are never empty.
+    ///
+    /// Requires the `default-words` or `mini-words` feature.
+    #[cfg(any(feature = "default-words", feature = "mini-words"))]
+    pub fn new() -> Self {
+        Self {
+            generator: MnemonicGenerator::new(),
+        }
+    }
+
+    /// Creates a generator over custom word lists, validated as by
+    /// [`MnemonicGenerator::try_with_words`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::EmptyList` if either list is empty, or
+    /// `MnemonicError::EmptyWord` or `MnemonicError::WhitespaceInWord` for
+    /// the first invalid word found
+    pub fn try_with_words<L, R>(left_words: L, right_words: R) -> Result<Self, MnemonicError>
+    where
+        L: IntoIterator,
+        L::Item: Into<String>,
+        R: IntoIterator,
+        R::Item: Into<String>,
+    {
+        MnemonicGenerator::try_with_words(left_words, right_words)?.try_into()
+    }
+
+    /// Generates a mnemonic using the generator's default separator.
+    pub fn generate(&self) -> String {
+        self.generator
+            .generate()
+            .expect("validated generator always produces a name")
+    }
+
+    /// Generates a mnemonic using a custom separator.
+    pub fn generate_with_separator(&self, separator: &str) -> String {
+        self.generator
+            .generate_with_separator(separator)
+            .expect("validated generator always produces a name")
+    }
+
+    /// Returns the wrapped generator.
+    pub fn generator(&self) -> &MnemonicGenerator {
+        &self.generator
+    }
+
+    /// Unwraps the generator, for use with the fallible APIs.
+    pub fn into_inner(self) -> MnemonicGenerator {
+        self.generator
+    }
+}
+
+#[cfg(any(feature = "default-words", feature = "mini-words"))]
+impl Default for NonEmptyMnemonicGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<MnemonicGenerator> for NonEmptyMnemonicGenerator {
+    type Error = MnemonicError;
+
+    /// Checks that the generator can always produce a name.
+    ///
+    /// Length windows, syllable limits, pronounceability, profanity filters,
+    /// and naming profiles may reject candidates, so generators using any of
+    /// them are refused with `MnemonicError::InvalidConfig`.
+    fn try_from(generator: MnemonicGenerator) -> Result<Self, Self::Error> {
+        generator.check_not_empty()?;
+        if !generator.is_unfiltered() {
+            return Err(MnemonicError::InvalidConfig(
+                "filters may reject every candidate".to_string(),
+            ));
+        }
+        Ok(Self { generator })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ListName, WordSide};
+
+    #[test]
+    fn rejects_generators_that_can_fail() {
+        assert!(matches!(
+            NonEmptyMnemonicGenerator::try_with_words(["happy"], Vec::<String>::new()),
+            Err(MnemonicError::EmptyList {
+                list: ListName::Side(WordSide::Right)
+            })
+        ));
+
+        let filtered =
+            MnemonicGenerator::with_words(["happy"], ["turing"]).with_length_range(3..=4);
+        assert!(matches!(
+            NonEmptyMnemonicGenerator::try_from(filtered),
+            Err(MnemonicError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn keeps_prefixes_and_separators() {
+        let generator = MnemonicGenerator::with_words(["happy"], ["turing"]).with_prefix("ci-");
+        let generator = NonEmptyMnemonicGenerator::try_from(generator).unwrap();
+        assert_eq!(generator.generate(), "ci-happy_turing");
+        assert_eq!(generator.generate_with_separator("."), "ci-happy.turing");
+    }
+}
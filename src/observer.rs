@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use crate::MnemonicGenerator;
+
+/// Receives events about generation, for feeding metrics such as Prometheus
+/// or statsd counters without this crate depending on a metrics library.
+///
+/// Every method has an empty default, so an observer only implements the
+/// events it counts. Methods are called synchronously on the generating
+/// thread and should return quickly.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+///
+/// use mnemonic_generator::{GenerationObserver, MnemonicGenerator};
+///
+/// #[derive(Default)]
+/// struct Counter(AtomicUsize);
+///
+/// impl GenerationObserver for Counter {
+///     fn on_generated(&self, _name: &str) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let counter = Arc::new(Counter::default());
+/// let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"])
+///     .with_observer(Arc::clone(&counter));
+/// generator.generate().unwrap();
+/// assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+/// ```
+pub trait GenerationObserver: Send + Sync {
+    /// Called with every name handed out, and not with candidates that are
+    /// turned down, for example because a registry reports them as taken.
+    fn on_generated(&self, name: &str) {
+        let _ = name;
+    }
+
+    /// Called whenever a candidate is rejected, for example by the profanity
+    /// filter or because it was recently used.
+    fn on_retry(&self) {}
+
+    /// Called when a registry reports a candidate as already taken.
+    fn on_collision(&self, name: &str) {
+        let _ = name;
+    }
+
+    /// Called when generation gives up after `attempts` candidates.
+    fn on_exhausted(&self, attempts: usize) {
+        let _ = attempts;
+    }
+}
+
+impl<O: GenerationObserver + ?Sized> GenerationObserver for Arc<O> {
+    fn on_generated(&self, name: &str) {
+        (**self).on_generated(name);
+    }
+
+    fn on_retry(&self) {
+        (**self).on_retry();
+    }
+
+    fn on_collision(&self, name: &str) {
+        (**self).on_collision(name);
+    }
+
+    fn on_exhausted(&self, attempts: usize) {
+        (**self).on_exhausted(attempts);
+    }
+}
+
+impl MnemonicGenerator {
+    /// Reports generation events to `observer`, replacing any previous one.
+    ///
+    /// Pass an `Arc` to keep a handle to the observer, as in the example of
+    /// [`GenerationObserver`].
+    pub fn with_observer<O: GenerationObserver + 'static>(mut self, observer: O) -> Self {
+        self.observer = Some(Arc::new(observer));
+        self
+    }
+
+    /// Calls `event` with the observer, if one is registered.
+    pub(crate) fn observe(&self, event: impl FnOnce(&dyn GenerationObserver)) {
+        if let Some(observer) = &self.observer {
+            event(observer.as_ref());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::{MnemonicError, ProfanityFilter};
+
+    #[derive(Default)]
+    struct Events(Mutex<Vec<String>>);
+
+    impl GenerationObserver for Events {
+        fn on_generated(&self, name: &str) {
+            self.0.lock().unwrap().push(format!("generated {}", name));
+        }
+
+        fn on_retry(&self) {
+            self.0.lock().unwrap().push("retry".to_string());
+        }
+
+        fn on_collision(&self, name: &str) {
+            self.0.lock().unwrap().push(format!("collision {}", name));
+        }
+
+        fn on_exhausted(&self, attempts: usize) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("exhausted {}", attempts));
+        }
+    }
+
+    #[test]
+    fn reports_collisions_and_exhaustion() {
+        let events = Arc::new(Events::default());
+        let generator = MnemonicGenerator::with_words(["amazing"], ["jordan"])
+            .with_observer(Arc::clone(&events));
+        let taken: HashSet<String> = ["amazing_jordan".to_string()].into();
+        assert!(generator.generate_available(&taken, 1).is_err());
+        assert_eq!(
+            *events.0.lock().unwrap(),
+            [
+                "collision amazing_jordan",
+                "collision amazing_jordan",
+                "exhausted 2",
+            ]
+        );
+
+        events.0.lock().unwrap().clear();
+        let taken = HashSet::<String>::new();
+        assert!(generator.generate_available(&taken, 1).is_ok());
+        assert_eq!(*events.0.lock().unwrap(), ["generated amazing_jordan"]);
+    }
+
+    #[test]
+    fn reports_names_from_every_entry_point() {
+        let events = Arc::new(Events::default());
+        let generator = MnemonicGenerator::with_words(["amazing"], ["jordan"])
+            .with_observer(Arc::clone(&events));
+        let mut out = String::new();
+        generator.generate_into(&mut out).unwrap();
+        generator.generate_display().unwrap();
+        generator.generate_best_of(3).unwrap();
+        assert_eq!(*events.0.lock().unwrap(), ["generated amazing_jordan"; 3]);
+    }
+
+    #[test]
+    fn reports_filter_retries() {
+        let events = Arc::new(Events::default());
+        let generator = MnemonicGenerator::with_words(["boring"], ["wozniak"])
+            .with_profanity_filter(ProfanityFilter::new())
+            .with_observer(Arc::clone(&events));
+        assert!(matches!(
+            generator.generate(),
+            Err(MnemonicError::AllCandidatesRejected)
+        ));
+        let events = events.0.lock().unwrap();
+        assert_eq!(
+            events.iter().filter(|event| *event == "retry").count(),
+            1000
+        );
+        assert_eq!(events.last().unwrap(), "exhausted 1000");
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{MnemonicError, MnemonicGenerator};
+
+impl MnemonicGenerator {
+    /// Puts the right-hand word first, as in `turing_hopeful`, for teams whose
+    /// conventions put the noun first.
+    ///
+    /// The order applies everywhere names are rendered or read: generation,
+    /// [`MnemonicGenerator::parse`], suggestions, ranks, and saved state. The
+    /// words keep their sides, so [`Mnemonic::left`](crate::Mnemonic::left)
+    /// still returns the adjective.
+    ///
+    /// For more than two words in any order, see
+    /// [`MnemonicGenerator::with_categories`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidSeparator` if the default separator
+    /// overlaps the word that now comes first
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"])
+    ///     .with_reversed_order()
+    ///     .unwrap();
+    /// assert_eq!(generator.generate().unwrap(), "turing_hopeful");
+    /// assert_eq!(generator.parse("turing_hopeful").unwrap().left(), "hopeful");
+    /// ```
+    pub fn with_reversed_order(mut self) -> Result<Self, MnemonicError> {
+        self.reversed = true;
+        self.validate_separator(self.default_separator())?;
+        Ok(self)
+    }
+
+    /// Returns the left and right item in the order they appear in names.
+    pub(crate) fn ordered<T>(&self, left: T, right: T) -> (T, T) {
+        if self.reversed {
+            (right, left)
+        } else {
+            (left, right)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Checksum, MnemonicError, MnemonicGenerator};
+
+    #[test]
+    fn reversed_names_round_trip() {
+        let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "bohr"])
+            .with_reversed_order()
+            .unwrap()
+            .with_prefix("eu-")
+            .with_checksum(Checksum::Word)
+            .with_separator("-")
+            .unwrap();
+        for _ in 0..20 {
+            let mut name = String::new();
+            generator.generate_into(&mut name).unwrap();
+            let mnemonic = generator.parse(&name).unwrap();
+            let words = format!("eu-{}-{}-", mnemonic.right(), mnemonic.left());
+            assert!(name.starts_with(&words), "{}", name);
+        }
+        assert!(generator.parse("eu-hopeful-turing").is_err());
+
+        let suggestion = &generator.suggest("eu-turnig-hopefull-x", 1)[0];
+        assert!(suggestion.as_str().starts_with("eu-turing-hopeful-"));
+        assert!(generator.parse(suggestion.as_str()).is_ok());
+    }
+
+    #[test]
+    fn revalidates_the_separator() {
+        let generator = MnemonicGenerator::with_words(["x"], ["xa"])
+            .with_separator("aa")
+            .unwrap();
+        assert!(matches!(
+            generator.with_reversed_order(),
+            Err(MnemonicError::InvalidSeparator { .. })
+        ));
+    }
+}
@@ -0,0 +1,62 @@
+//! Parallel batch generation, enabled by the `rayon` feature.
+
+use rayon::prelude::*;
+
+use crate::{MnemonicError, MnemonicGenerator};
+
+impl MnemonicGenerator {
+    /// Generates `n` mnemonics in parallel using the default separator.
+    ///
+    /// Work is split across rayon's thread pool, and each worker draws from its
+    /// own thread-local RNG so threads never contend on shared state. Names are
+    /// drawn independently, so the batch may contain duplicates.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "hopper"]);
+    /// let names = generator.generate_many_parallel(10_000).expect("Failed to generate mnemonics");
+    /// assert_eq!(names.len(), 10_000);
+    /// ```
+    pub fn generate_many_parallel(&self, n: usize) -> Result<Vec<String>, MnemonicError> {
+        let separator = self.default_separator();
+
+        (0..n)
+            .into_par_iter()
+            .map_init(crate::rng::default_rng, |rng, _| {
+                let pick = self.choose(rng, separator)?;
+                let name = self.render(pick, separator);
+                self.observe(|observer| observer.on_generated(&name));
+                Ok(name)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_requested_count() {
+        let generator = MnemonicGenerator::with_words(["amazing", "legend"], ["jordan", "bird"]);
+        let names = generator.generate_many_parallel(1_000).unwrap();
+        assert_eq!(names.len(), 1_000);
+        assert!(names.iter().all(|name| name.split('_').count() == 2));
+    }
+
+    #[test]
+    fn propagates_errors() {
+        let generator = MnemonicGenerator::with_words(Vec::<String>::new(), ["jordan"]);
+        assert!(matches!(
+            generator.generate_many_parallel(10),
+            Err(MnemonicError::EmptyList { .. })
+        ));
+    }
+}
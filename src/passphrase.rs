@@ -0,0 +1,160 @@
+use std::fmt;
+
+use crate::{MnemonicError, MnemonicGenerator};
+
+/// A phrase of several mnemonics together with its entropy, returned by
+/// [`MnemonicGenerator::generate_with_entropy`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Passphrase {
+    phrase: String,
+    word_count: usize,
+    bits: f64,
+}
+
+impl Passphrase {
+    /// Returns the phrase.
+    pub fn as_str(&self) -> &str {
+        &self.phrase
+    }
+
+    /// Returns the number of words in the phrase, counting middle words,
+    /// checksums, and timestamp words but not prefixes or suffixes.
+    pub fn word_count(&self) -> usize {
+        self.word_count
+    }
+
+    /// Returns the entropy of the phrase in bits.
+    pub fn bits(&self) -> f64 {
+        self.bits
+    }
+}
+
+impl fmt::Display for Passphrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.phrase)
+    }
+}
+
+impl From<Passphrase> for String {
+    fn from(passphrase: Passphrase) -> Self {
+        passphrase.phrase
+    }
+}
+
+impl MnemonicGenerator {
+    /// Generates a phrase with at least `min_bits` bits of entropy by joining
+    /// as many mnemonics as needed with the default separator.
+    ///
+    /// The phrase is made of whole mnemonics, so its words alternate between
+    /// the left and right lists and the configured filters keep applying to
+    /// every pair. Each mnemonic contributes
+    /// [`MnemonicGenerator::entropy_bits`], and the returned [`Passphrase`]
+    /// reports the total, which may exceed `min_bits` by up to one mnemonic.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidConfig` if the generator can produce only
+    /// one name and `min_bits` is not zero, or the same errors as
+    /// [`MnemonicGenerator::generate`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::new().with_separator("-").unwrap();
+    /// let passphrase = generator.generate_with_entropy(60).unwrap();
+    /// assert!(passphrase.bits() >= 60.0);
+    /// assert_eq!(passphrase.as_str().split('-').count(), passphrase.word_count());
+    /// # }
+    /// ```
+    pub fn generate_with_entropy(&self, min_bits: u32) -> Result<Passphrase, MnemonicError> {
+        self.check_not_empty()?;
+        let bits_per_name = self.entropy_bits();
+        let names = if min_bits == 0 {
+            1
+        } else if bits_per_name > 0.0 {
+            (f64::from(min_bits) / bits_per_name).ceil().max(1.0) as usize
+        } else {
+            return Err(MnemonicError::InvalidConfig(format!(
+                "a generator with a single name cannot reach {} bits of entropy",
+                min_bits
+            )));
+        };
+
+        let separator = self.default_separator();
+        let mut rng = crate::rng::default_rng();
+        let mut phrase = String::new();
+        for i in 0..names {
+            if i > 0 {
+                phrase.push_str(separator);
+            }
+            phrase.push_str(&self.generate_from(&mut rng, separator)?);
+        }
+
+        Ok(Passphrase {
+            phrase,
+            word_count: names * self.words_per_name(),
+            bits: names as f64 * bits_per_name,
+        })
+    }
+
+    /// Returns the number of words in each rendered name: the pair and any
+    /// middle word, checksum, and timestamp words.
+    fn words_per_name(&self) -> usize {
+        2 + usize::from(self.middle.is_some())
+            + usize::from(self.checksum.is_some())
+            + self.timestamp_width()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Checksum;
+
+    #[test]
+    fn chooses_enough_words() {
+        // 4 × 8 names carry exactly 5 bits each.
+        let generator = MnemonicGenerator::with_words(
+            ["a", "b", "c", "d"],
+            ["w", "x", "y", "z", "1", "2", "3", "4"],
+        );
+        let passphrase = generator.generate_with_entropy(11).unwrap();
+        assert_eq!(passphrase.word_count(), 6);
+        assert_eq!(passphrase.bits(), 15.0);
+        assert_eq!(passphrase.as_str().split('_').count(), 6);
+
+        let passphrase = generator.generate_with_entropy(10).unwrap();
+        assert_eq!(passphrase.word_count(), 4);
+        assert_eq!(generator.generate_with_entropy(0).unwrap().word_count(), 2);
+    }
+
+    #[test]
+    fn counts_every_rendered_word() {
+        let generator = MnemonicGenerator::with_words(["a", "b"], ["x", "y"])
+            .with_middle_words(["m"])
+            .unwrap()
+            .with_checksum(Checksum::Word)
+            .with_prefix("eu-");
+        let passphrase = generator.generate_with_entropy(3).unwrap();
+        assert_eq!(passphrase.word_count(), 8);
+        let words = passphrase.as_str().trim_start_matches("eu-");
+        assert_eq!(words.split('_').count(), 8, "{}", passphrase);
+    }
+
+    #[test]
+    fn single_name_has_no_entropy() {
+        let generator = MnemonicGenerator::with_words(["happy"], ["turing"]);
+        assert!(matches!(
+            generator.generate_with_entropy(1),
+            Err(MnemonicError::InvalidConfig(_))
+        ));
+        assert_eq!(
+            generator.generate_with_entropy(0).unwrap().as_str(),
+            "happy_turing"
+        );
+    }
+}
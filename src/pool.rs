@@ -0,0 +1,166 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, TryLockError};
+use std::thread;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::{MnemonicError, MnemonicGenerator};
+
+/// A shared generator with a fixed number of independently seeded random
+/// number generators, for servers producing names at a high rate.
+///
+/// [`GeneratorPool::checkout`] hands out one of the shards, skipping any that
+/// another thread holds, so concurrent callers only contend when every shard
+/// is in use. Each shard is a [`StdRng`] seeded separately from the operating
+/// system, so shards never share a random stream.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{GeneratorPool, MnemonicGenerator};
+///
+/// let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "hopper"]);
+/// let pool = GeneratorPool::per_core(generator.into_shared());
+/// let name = pool.checkout().generate().unwrap();
+/// assert!(name.contains('_'));
+/// ```
+#[derive(Debug)]
+pub struct GeneratorPool {
+    generator: Arc<MnemonicGenerator>,
+    shards: Box<[Mutex<StdRng>]>,
+    next: AtomicUsize,
+}
+
+/// A shard of a [`GeneratorPool`], checked out for exclusive use until dropped.
+#[derive(Debug)]
+pub struct PooledGenerator<'a> {
+    generator: &'a MnemonicGenerator,
+    rng: MutexGuard<'a, StdRng>,
+}
+
+impl GeneratorPool {
+    /// Creates a pool with `shards` random number generators. A count of zero
+    /// is treated as one.
+    pub fn new(generator: Arc<MnemonicGenerator>, shards: usize) -> Self {
+        let shards = (0..shards.max(1))
+            .map(|_| Mutex::new(StdRng::from_os_rng()))
+            .collect();
+        Self {
+            generator,
+            shards,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Creates a pool with one shard per available CPU core.
+    pub fn per_core(generator: Arc<MnemonicGenerator>) -> Self {
+        let cores = thread::available_parallelism().map_or(1, |cores| cores.get());
+        Self::new(generator, cores)
+    }
+
+    /// Returns the number of shards.
+    pub fn shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Returns the shared generator.
+    pub fn generator(&self) -> &Arc<MnemonicGenerator> {
+        &self.generator
+    }
+
+    /// Checks out a shard for generating names.
+    ///
+    /// Shards are tried in round-robin order starting after the last one
+    /// handed out, and the first free one is returned. Only if every shard is
+    /// in use does this block, waiting for the shard it started at.
+    pub fn checkout(&self) -> PooledGenerator<'_> {
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        let len = self.shards.len();
+        for offset in 0..len {
+            match self.shards[(start + offset) % len].try_lock() {
+                Ok(rng) => return self.pooled(rng),
+                Err(TryLockError::Poisoned(poisoned)) => return self.pooled(poisoned.into_inner()),
+                Err(TryLockError::WouldBlock) => {}
+            }
+        }
+
+        trace_event!(debug, shards = len, "every shard is in use, waiting");
+        let rng = self.shards[start % len]
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        self.pooled(rng)
+    }
+
+    /// Wraps a locked shard.
+    fn pooled<'a>(&'a self, rng: MutexGuard<'a, StdRng>) -> PooledGenerator<'a> {
+        PooledGenerator {
+            generator: &self.generator,
+            rng,
+        }
+    }
+}
+
+impl PooledGenerator<'_> {
+    /// Generates a mnemonic using the default separator.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate`]
+    pub fn generate(&mut self) -> Result<String, MnemonicError> {
+        self.generator.generate_with_rng(&mut *self.rng)
+    }
+
+    /// Generates a mnemonic using a custom separator.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate_with_separator`]
+    pub fn generate_with_separator(&mut self, separator: &str) -> Result<String, MnemonicError> {
+        self.generator.generate_from(&mut *self.rng, separator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::RngCore;
+
+    use super::*;
+
+    #[test]
+    fn concurrent_checkouts_use_distinct_shards() {
+        let pool = GeneratorPool::new(
+            MnemonicGenerator::with_words(["hopeful"], ["turing"]).into_shared(),
+            2,
+        );
+        let mut first = pool.checkout();
+        let mut second = pool.checkout();
+        assert_ne!(first.rng.next_u64(), second.rng.next_u64());
+        assert_eq!(first.generate().unwrap(), "hopeful_turing");
+        assert_eq!(
+            second.generate_with_separator("-").unwrap(),
+            "hopeful-turing"
+        );
+        drop(first);
+        drop(second);
+        assert_eq!(pool.checkout().generate().unwrap(), "hopeful_turing");
+    }
+
+    #[test]
+    fn pool_is_shared_across_threads() {
+        let pool = GeneratorPool::new(
+            MnemonicGenerator::with_words(["a", "b"], ["x"]).into_shared(),
+            0,
+        );
+        assert_eq!(pool.shards(), 1);
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..100 {
+                        assert!(pool.checkout().generate().unwrap().ends_with("_x"));
+                    }
+                });
+            }
+        });
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{MnemonicError, MnemonicGenerator};
+
+impl MnemonicGenerator {
+    /// Generates a mnemonic using the default separator that satisfies `pred`.
+    ///
+    /// Each attempt generates a name exactly as [`MnemonicGenerator::generate`]
+    /// does, so the configured filters always apply, and then passes it to
+    /// `pred`. The first accepted name is returned. `pred` is called at most
+    /// `max_attempts` times.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::MaxAttemptsExceeded` if `pred` rejected
+    /// `max_attempts` names, or any error of [`MnemonicGenerator::generate`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["bold", "hopeful"], ["bohr", "turing"]);
+    /// let name = generator
+    ///     .generate_where(|name| name.starts_with('b'), 1000)
+    ///     .unwrap();
+    /// assert!(name.starts_with('b'));
+    /// ```
+    pub fn generate_where(
+        &self,
+        pred: impl Fn(&str) -> bool,
+        max_attempts: usize,
+    ) -> Result<String, MnemonicError> {
+        for _ in 0..max_attempts {
+            let name = self.generate_candidate()?;
+            if pred(&name) {
+                self.observe(|observer| observer.on_generated(&name));
+                return Ok(name);
+            }
+            self.observe(|observer| observer.on_retry());
+        }
+
+        self.observe(|observer| observer.on_exhausted(max_attempts));
+        Err(MnemonicError::MaxAttemptsExceeded {
+            attempts: max_attempts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn retries_until_accepted() {
+        let generator = MnemonicGenerator::with_words(["a", "b"], ["x", "y"]);
+        let name = generator
+            .generate_where(|name| name == "b_y", 1000)
+            .unwrap();
+        assert_eq!(name, "b_y");
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let generator = MnemonicGenerator::with_words(["a"], ["x"]);
+        let calls = Cell::new(0);
+        let result = generator.generate_where(
+            |_| {
+                calls.set(calls.get() + 1);
+                false
+            },
+            5,
+        );
+        assert!(matches!(
+            result,
+            Err(MnemonicError::MaxAttemptsExceeded { attempts: 5 })
+        ));
+        assert_eq!(calls.get(), 5);
+    }
+}
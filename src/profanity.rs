@@ -0,0 +1,178 @@
+/// Terms that are never allowed to appear anywhere in a generated name.
+///
+/// Matching is performed on a normalized form of the whole name (lowercased,
+/// separators removed, common character substitutions undone), so a term is
+/// caught even when it only appears across the boundary of two words.
+const DEFAULT_TERMS: &[&str] = &[
+    "anal", "anus", "bastard", "bitch", "bollock", "boner", "butthole", "clit", "cock", "coon",
+    "cum", "cunt", "dildo", "dyke", "fag", "fuck", "gook", "hitler", "jizz", "kike", "kkk",
+    "molest", "nazi", "negro", "nigga", "nigger", "nude", "orgasm", "pedo", "penis", "piss",
+    "porn", "pussy", "rape", "rapist", "retard", "scrotum", "semen", "sex", "shit", "slut", "tits",
+    "twat", "vagina", "wank", "whore",
+];
+
+/// Word combinations that are harmless on their own but must not be paired.
+///
+/// Mirrors the exception list of the original Docker names generator.
+const DEFAULT_BLOCKED_COMBINATIONS: &[(&str, &str)] = &[
+    // Steve Wozniak is not boring
+    ("boring", "wozniak"),
+];
+
+/// A filter that screens generated names for offensive content.
+///
+/// The filter checks both individual terms (matched anywhere in the normalized
+/// name, including across word boundaries) and explicit word combinations.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::ProfanityFilter;
+///
+/// let filter = ProfanityFilter::new().with_term("badword");
+/// assert!(filter.is_offensive("bad_word"));
+/// assert!(filter.is_offensive("boring_wozniak"));
+/// assert!(!filter.is_offensive("hopeful_turing"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfanityFilter {
+    terms: Vec<String>,
+    blocked_combinations: Vec<(String, String)>,
+}
+
+impl ProfanityFilter {
+    /// Creates a filter using the embedded list of offensive terms and combinations.
+    pub fn new() -> Self {
+        Self {
+            terms: DEFAULT_TERMS.iter().map(|term| term.to_string()).collect(),
+            blocked_combinations: DEFAULT_BLOCKED_COMBINATIONS
+                .iter()
+                .map(|(left, right)| (left.to_string(), right.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Creates a filter without any embedded terms, for callers that supply their own.
+    pub fn empty() -> Self {
+        Self {
+            terms: Vec::new(),
+            blocked_combinations: Vec::new(),
+        }
+    }
+
+    /// Adds a term that must not appear anywhere in a generated name.
+    ///
+    /// The term is normalized the same way as the names it is matched against,
+    /// so `"Bad Word"` and `"badword"` are equivalent.
+    pub fn with_term(mut self, term: &str) -> Self {
+        let term = normalize(term);
+        if !term.is_empty() {
+            self.terms.push(term);
+        }
+        self
+    }
+
+    /// Adds several terms at once. See [`ProfanityFilter::with_term`].
+    pub fn with_terms<I, S>(self, terms: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        terms
+            .into_iter()
+            .fold(self, |filter, term| filter.with_term(term.as_ref()))
+    }
+
+    /// Blocks a specific pairing of a left and a right word.
+    pub fn with_blocked_combination(mut self, left: &str, right: &str) -> Self {
+        self.blocked_combinations
+            .push((left.to_lowercase(), right.to_lowercase()));
+        self
+    }
+
+    /// Returns `true` if the given name contains any blocked term.
+    pub fn is_offensive(&self, name: &str) -> bool {
+        let normalized = normalize(name);
+        if self.terms.iter().any(|term| normalized.contains(term)) {
+            return true;
+        }
+
+        let lower = name.to_lowercase();
+        self.blocked_combinations.iter().any(|(left, right)| {
+            lower
+                .strip_prefix(left.as_str())
+                .and_then(|rest| rest.strip_suffix(right.as_str()))
+                .is_some_and(|separator| !separator.chars().any(char::is_alphanumeric))
+        })
+    }
+
+    /// Returns the blocked terms, normalized.
+    pub(crate) fn terms(&self) -> &[String] {
+        &self.terms
+    }
+
+    /// Returns the blocked pairings of a left and a right word, lowercased.
+    pub(crate) fn blocked_combinations(&self) -> &[(String, String)] {
+        &self.blocked_combinations
+    }
+
+    /// Returns `true` if pairing `left` with `right` is offensive under this filter.
+    pub(crate) fn is_offensive_pair(&self, left: &str, right: &str, name: &str) -> bool {
+        self.blocked_combinations
+            .iter()
+            .any(|(l, r)| l.eq_ignore_ascii_case(left) && r.eq_ignore_ascii_case(right))
+            || self.is_offensive(name)
+    }
+}
+
+impl Default for ProfanityFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Lowercases the input, undoes common character substitutions and drops
+/// everything that is not a letter, so separators cannot hide a term.
+fn normalize(input: &str) -> String {
+    input
+        .chars()
+        .filter_map(|c| match c.to_ascii_lowercase() {
+            '0' => Some('o'),
+            '1' | '!' => Some('i'),
+            '3' => Some('e'),
+            '4' | '@' => Some('a'),
+            '5' | '$' => Some('s'),
+            '7' => Some('t'),
+            c if c.is_alphabetic() => Some(c),
+            _ => None,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_terms_across_word_boundaries() {
+        let filter = ProfanityFilter::empty().with_term("cathat");
+        assert!(filter.is_offensive("cat_hat"));
+        assert!(filter.is_offensive("CAT-H4T"));
+        assert!(!filter.is_offensive("cat_dog"));
+    }
+
+    #[test]
+    fn detects_blocked_combinations() {
+        let filter = ProfanityFilter::new();
+        assert!(filter.is_offensive("boring_wozniak"));
+        assert!(filter.is_offensive_pair("boring", "wozniak", "boring-wozniak"));
+        assert!(!filter.is_offensive("boring_turing"));
+    }
+
+    #[test]
+    fn empty_filter_accepts_everything() {
+        let filter = ProfanityFilter::empty();
+        assert!(!filter.is_offensive("boring_wozniak"));
+    }
+}
@@ -0,0 +1,256 @@
+use thiserror::Error;
+
+/// A set of constraints that generated names must satisfy, such as the rules
+/// for DNS hostnames or S3 bucket names.
+///
+/// A profile constrains the allowed characters, case, length, and leading or
+/// trailing characters of a name. When attached to a generator with
+/// [`MnemonicGenerator::with_profile`](crate::MnemonicGenerator::with_profile),
+/// names are normalized to the profile's case and candidates that still violate
+/// it are re-rolled.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::NamingProfile;
+///
+/// let profile = NamingProfile::rfc1123_hostname();
+/// assert!(profile.is_valid("hopeful-turing"));
+/// assert!(!profile.is_valid("hopeful_turing"));
+/// assert!(!profile.is_valid("-hopeful-turing"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamingProfile {
+    min_len: usize,
+    max_len: usize,
+    lowercase: bool,
+    allowed_symbols: String,
+    alphanumeric_edges: bool,
+    separator: String,
+}
+
+/// The ways in which a name can violate a [`NamingProfile`]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ProfileViolation {
+    #[error("Name is {len} characters long, shorter than the minimum of {min}")]
+    TooShort { len: usize, min: usize },
+    #[error("Name is {len} characters long, longer than the maximum of {max}")]
+    TooLong { len: usize, max: usize },
+    #[error("Character {0:?} is not allowed")]
+    InvalidCharacter(char),
+    #[error("Uppercase character {0:?} is not allowed")]
+    UppercaseNotAllowed(char),
+    #[error("Name must start and end with a letter or digit, found {0:?}")]
+    InvalidEdge(char),
+}
+
+impl NamingProfile {
+    /// Creates a permissive profile accepting ASCII letters and digits of any
+    /// case between `min_len` and `max_len` characters, joined by `-`.
+    ///
+    /// Use the `with_*` methods to tighten it further.
+    pub fn new(min_len: usize, max_len: usize) -> Self {
+        Self {
+            min_len,
+            max_len,
+            lowercase: false,
+            allowed_symbols: String::new(),
+            alphanumeric_edges: false,
+            separator: "-".to_string(),
+        }
+    }
+
+    /// Kubernetes label values: at most 63 characters of `[A-Za-z0-9-_.]`,
+    /// beginning and ending with an alphanumeric character.
+    pub fn kubernetes_label() -> Self {
+        Self::new(0, 63)
+            .with_allowed_symbols("-_.")
+            .with_alphanumeric_edges(true)
+            .with_separator("-")
+    }
+
+    /// RFC 1123 hostname labels: 1 to 63 lowercase letters, digits, or `-`,
+    /// beginning and ending with an alphanumeric character.
+    pub fn rfc1123_hostname() -> Self {
+        Self::new(1, 63)
+            .with_lowercase(true)
+            .with_allowed_symbols("-")
+            .with_alphanumeric_edges(true)
+            .with_separator("-")
+    }
+
+    /// S3 bucket names: 3 to 63 lowercase letters, digits, `.`, or `-`,
+    /// beginning and ending with a letter or digit.
+    pub fn s3_bucket() -> Self {
+        Self::new(3, 63)
+            .with_lowercase(true)
+            .with_allowed_symbols(".-")
+            .with_alphanumeric_edges(true)
+            .with_separator("-")
+    }
+
+    /// GitHub repository names: 1 to 100 characters of `[A-Za-z0-9-_.]`.
+    pub fn github_repo() -> Self {
+        Self::new(1, 100)
+            .with_allowed_symbols("-_.")
+            .with_separator("-")
+    }
+
+    /// Sets the inclusive length bounds, counted in characters.
+    pub fn with_length(mut self, min_len: usize, max_len: usize) -> Self {
+        self.min_len = min_len;
+        self.max_len = max_len;
+        self
+    }
+
+    /// Requires names to be lowercase. Generated names are lowercased before validation.
+    pub fn with_lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    /// Sets the non-alphanumeric characters allowed in a name.
+    pub fn with_allowed_symbols(mut self, symbols: &str) -> Self {
+        self.allowed_symbols = symbols.to_string();
+        self
+    }
+
+    /// Requires names to begin and end with an ASCII letter or digit.
+    pub fn with_alphanumeric_edges(mut self, required: bool) -> Self {
+        self.alphanumeric_edges = required;
+        self
+    }
+
+    /// Sets the separator used by `generate()` when this profile is active.
+    pub fn with_separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Returns the separator used by `generate()` when this profile is active.
+    pub fn separator(&self) -> &str {
+        &self.separator
+    }
+
+    /// Returns the inclusive length bounds.
+    pub(crate) fn length_bounds(&self) -> (usize, usize) {
+        (self.min_len, self.max_len)
+    }
+
+    /// Returns `true` if names must be lowercase.
+    pub(crate) fn is_lowercase(&self) -> bool {
+        self.lowercase
+    }
+
+    /// Returns the non-alphanumeric characters allowed in a name.
+    pub(crate) fn allowed_symbols(&self) -> &str {
+        &self.allowed_symbols
+    }
+
+    /// Returns `true` if names must begin and end with a letter or digit.
+    pub(crate) fn requires_alphanumeric_edges(&self) -> bool {
+        self.alphanumeric_edges
+    }
+
+    /// Normalizes a name to the profile's case.
+    pub fn apply(&self, name: &str) -> String {
+        if self.lowercase {
+            name.to_lowercase()
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// Checks a name against every rule of the profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first `ProfileViolation` encountered
+    pub fn validate(&self, name: &str) -> Result<(), ProfileViolation> {
+        let len = name.chars().count();
+        if len < self.min_len {
+            return Err(ProfileViolation::TooShort {
+                len,
+                min: self.min_len,
+            });
+        }
+        if len > self.max_len {
+            return Err(ProfileViolation::TooLong {
+                len,
+                max: self.max_len,
+            });
+        }
+
+        for c in name.chars() {
+            if self.lowercase && c.is_ascii_uppercase() {
+                return Err(ProfileViolation::UppercaseNotAllowed(c));
+            }
+            if !self.allows_char(c) {
+                return Err(ProfileViolation::InvalidCharacter(c));
+            }
+        }
+
+        if self.alphanumeric_edges {
+            let edges = [name.chars().next(), name.chars().last()];
+            if let Some(c) = edges
+                .into_iter()
+                .flatten()
+                .find(|c| !c.is_ascii_alphanumeric())
+            {
+                return Err(ProfileViolation::InvalidEdge(c));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the name satisfies every rule of the profile.
+    pub fn is_valid(&self, name: &str) -> bool {
+        self.validate(name).is_ok()
+    }
+
+    /// Returns `true` if the character may appear anywhere in a name.
+    pub(crate) fn allows_char(&self, c: char) -> bool {
+        c.is_ascii_alphanumeric() || self.allowed_symbols.contains(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc1123_rejects_uppercase_and_underscores() {
+        let profile = NamingProfile::rfc1123_hostname();
+        assert_eq!(
+            profile.validate("Hopeful-turing"),
+            Err(ProfileViolation::UppercaseNotAllowed('H'))
+        );
+        assert_eq!(
+            profile.validate("hopeful_turing"),
+            Err(ProfileViolation::InvalidCharacter('_'))
+        );
+        assert_eq!(profile.apply("Hopeful-Turing"), "hopeful-turing");
+    }
+
+    #[test]
+    fn s3_enforces_length_and_edges() {
+        let profile = NamingProfile::s3_bucket();
+        assert_eq!(
+            profile.validate("ab"),
+            Err(ProfileViolation::TooShort { len: 2, min: 3 })
+        );
+        assert_eq!(
+            profile.validate("hopeful-turing."),
+            Err(ProfileViolation::InvalidEdge('.'))
+        );
+        assert!(profile.is_valid("hopeful.turing"));
+    }
+
+    #[test]
+    fn kubernetes_label_allows_mixed_case() {
+        let profile = NamingProfile::kubernetes_label();
+        assert!(profile.is_valid("Hopeful_Turing"));
+        assert!(!profile.is_valid(&"a".repeat(64)));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::syllables::VOWELS;
+use crate::word_list::WordList;
+use crate::MnemonicGenerator;
+
+/// The longest run of consonants a pronounceable word may contain, as in "strength".
+const MAX_CONSONANT_RUN: usize = 4;
+
+/// The longest run of vowels a pronounceable word may contain, as in "beautiful".
+const MAX_VOWEL_RUN: usize = 3;
+
+/// The pronounceability of every word of both lists, precomputed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PronounceableWords {
+    left: Vec<bool>,
+    right: Vec<bool>,
+}
+
+impl PronounceableWords {
+    /// Checks every word in both lists.
+    pub(crate) fn new(left_words: &WordList, right_words: &WordList) -> Self {
+        Self {
+            left: left_words.iter().map(is_pronounceable).collect(),
+            right: right_words.iter().map(is_pronounceable).collect(),
+        }
+    }
+
+    /// Returns `true` if both words at the given indices are pronounceable.
+    pub(crate) fn fits(&self, left_idx: usize, right_idx: usize) -> bool {
+        self.left[left_idx] && self.right[right_idx]
+    }
+}
+
+/// Returns `true` if a word looks like something people can say aloud.
+///
+/// A pronounceable word consists of letters only, contains a vowel, and has
+/// no long runs of consonants or vowels. Abbreviations such as "http" or
+/// "k8s" fail the check.
+fn is_pronounceable(word: &str) -> bool {
+    let mut has_vowel = false;
+    let (mut consonants, mut vowels) = (0, 0);
+    for c in word.chars().flat_map(char::to_lowercase) {
+        if !c.is_alphabetic() {
+            return false;
+        }
+        if VOWELS.contains(c) {
+            has_vowel = true;
+            (consonants, vowels) = (0, vowels + 1);
+        } else {
+            (consonants, vowels) = (consonants + 1, 0);
+        }
+        if consonants > MAX_CONSONANT_RUN || vowels > MAX_VOWEL_RUN {
+            return false;
+        }
+    }
+    has_vowel
+}
+
+impl MnemonicGenerator {
+    /// Only generates names whose words pass a pronounceability heuristic
+    /// based on vowel and consonant patterns.
+    ///
+    /// Useful for custom lists full of abbreviations: words without vowels,
+    /// with digits or symbols, or with long runs of consonants are skipped.
+    /// Candidates with such words are re-rolled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["fast", "k8s"], ["http", "kernel"])
+    ///     .with_pronounceable_words();
+    /// assert_eq!(generator.generate().unwrap(), "fast_kernel");
+    /// ```
+    pub fn with_pronounceable_words(mut self) -> Self {
+        self.pronounceable = Some(PronounceableWords::new(&self.words.left, &self.words.right));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_abbreviations() {
+        for word in ["turing", "strength", "beautiful", "Müller"] {
+            assert!(is_pronounceable(word), "{}", word);
+        }
+        for word in ["http", "k8s", "xml", "tcp-ip", "aeiou", ""] {
+            assert!(!is_pronounceable(word), "{}", word);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Fixed-width positional encoding of integers, with one word per digit.
+
+/// Returns the number of base-`base` digits needed to write any value of up to
+/// `bits` bits.
+pub(crate) fn width(base: u128, bits: u32) -> usize {
+    let mut width = 0;
+    let mut capacity: u128 = 1;
+    // `capacity` holds `base^width`; stop once it exceeds the largest value.
+    while bits == 128 || capacity >> bits == 0 {
+        width += 1;
+        match capacity.checked_mul(base) {
+            Some(next) => capacity = next,
+            None => break,
+        }
+    }
+    width
+}
+
+/// Writes `value` as `width` base-`base` digits, most significant first.
+///
+/// Digits beyond `width` are dropped.
+pub(crate) fn to_digits(value: u128, base: u128, width: usize) -> Vec<usize> {
+    let mut digits = vec![0; width];
+    let mut rest = value;
+    for digit in digits.iter_mut().rev() {
+        *digit = (rest % base) as usize;
+        rest /= base;
+    }
+    digits
+}
+
+/// Reads base-`base` digits, most significant first.
+///
+/// Returns `None` if the value does not fit in a `u128`.
+pub(crate) fn from_digits(digits: impl IntoIterator<Item = usize>, base: u128) -> Option<u128> {
+    digits.into_iter().try_fold(0u128, |value, digit| {
+        value.checked_mul(base)?.checked_add(digit as u128)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_width() {
+        assert_eq!(width(256, 32), 4);
+        assert_eq!(width(237, 32), 5);
+        assert_eq!(width(2, 128), 128);
+        assert_eq!(width(237, 128), 17);
+    }
+
+    #[test]
+    fn round_trips_digits() {
+        let digits = to_digits(123_456, 10, 8);
+        assert_eq!(digits, [0, 0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(from_digits(digits, 10), Some(123_456));
+        assert_eq!(from_digits([1; 200], 2), None);
+    }
+}
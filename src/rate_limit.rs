@@ -0,0 +1,215 @@
+//! Rate-limited asynchronous generation, enabled by the `tokio` feature.
+
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::{AsyncNameRegistry, MnemonicError, MnemonicGenerator};
+
+/// A generator wrapper that caps how many names, or registry lookups, can be
+/// made per second.
+///
+/// Throughput is limited by a token bucket: every generated candidate takes a
+/// token, tokens are refilled at a steady rate, and up to `burst` unused tokens
+/// are kept for short bursts. Give each tenant of a shared naming service its
+/// own wrapper around the same shared generator, so that one tenant cannot
+/// exhaust the namespace or flood the collision registry.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{MnemonicGenerator, RateLimitedGenerator};
+///
+/// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+/// let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"]).into_shared();
+/// let tenant = RateLimitedGenerator::new(generator, 100).with_burst(10);
+/// assert_eq!(tenant.generate().await.unwrap(), "hopeful_turing");
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct RateLimitedGenerator {
+    generator: Arc<MnemonicGenerator>,
+    bucket: Mutex<TokenBucket>,
+}
+
+/// The state of a token bucket.
+#[derive(Debug)]
+struct TokenBucket {
+    per_second: f64,
+    burst: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    /// Takes a token, or returns how long to wait until one is available.
+    fn take(&mut self, now: Instant) -> Result<(), Duration> {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_second).min(self.burst);
+        self.updated = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.per_second,
+            ))
+        }
+    }
+}
+
+impl RateLimitedGenerator {
+    /// Wraps `generator`, allowing `per_second` candidates per second on
+    /// average, with bursts of the same size.
+    ///
+    /// A rate of zero is treated as one.
+    pub fn new(generator: Arc<MnemonicGenerator>, per_second: u32) -> Self {
+        let per_second = f64::from(per_second.max(1));
+        Self {
+            generator,
+            bucket: Mutex::new(TokenBucket {
+                per_second,
+                burst: per_second,
+                tokens: per_second,
+                updated: Instant::now(),
+            }),
+        }
+    }
+
+    /// Sets how many candidates can be generated at once after a quiet
+    /// period, and refills the bucket. A burst of zero is treated as one.
+    pub fn with_burst(self, burst: u32) -> Self {
+        {
+            let mut bucket = self.lock_bucket();
+            bucket.burst = f64::from(burst.max(1));
+            bucket.tokens = bucket.burst;
+        }
+        self
+    }
+
+    /// Returns the wrapped generator.
+    pub fn generator(&self) -> &Arc<MnemonicGenerator> {
+        &self.generator
+    }
+
+    /// Generates a mnemonic using the default separator, waiting for a token
+    /// if the rate limit has been reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate`]
+    pub async fn generate(&self) -> Result<String, MnemonicError> {
+        self.acquire().await;
+        self.generator.generate()
+    }
+
+    /// Generates a mnemonic using the default separator if the rate limit
+    /// allows it right now.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::RateLimited` if no token is available, or the
+    /// same errors as [`MnemonicGenerator::generate`]
+    pub fn try_generate(&self) -> Result<String, MnemonicError> {
+        self.lock_bucket()
+            .take(Instant::now())
+            .map_err(|retry_after| MnemonicError::RateLimited { retry_after })?;
+        self.generator.generate()
+    }
+
+    /// Rate-limited variant of
+    /// [`MnemonicGenerator::generate_available_async`].
+    ///
+    /// Every candidate, including re-rolls after a collision, takes a token,
+    /// so the registry sees at most the configured rate of lookups.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate_available_async`]
+    pub async fn generate_available<R: AsyncNameRegistry + ?Sized>(
+        &self,
+        registry: &R,
+        max_retries: usize,
+    ) -> Result<String, MnemonicError> {
+        let attempts = max_retries.saturating_add(1);
+        for _attempt in 0..attempts {
+            self.acquire().await;
+            let name = self.generator.generate_candidate()?;
+            if !registry.is_taken(&name).await {
+                self.generator
+                    .observe(|observer| observer.on_generated(&name));
+                return Ok(name);
+            }
+            trace_event!(
+                debug,
+                name,
+                attempt = _attempt,
+                "name already taken, retrying"
+            );
+            self.generator
+                .observe(|observer| observer.on_collision(&name));
+        }
+
+        trace_event!(warn, attempts, "no available name found");
+        self.generator
+            .observe(|observer| observer.on_exhausted(attempts));
+        Err(MnemonicError::NoAvailableName { attempts })
+    }
+
+    /// Waits until a token can be taken.
+    async fn acquire(&self) {
+        loop {
+            let wait = match self.lock_bucket().take(Instant::now()) {
+                Ok(()) => return,
+                Err(wait) => wait,
+            };
+            trace_event!(debug, ?wait, "rate limit reached, waiting");
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Locks the bucket. Its state is consistent after every update, so a
+    /// poisoned lock is recovered.
+    fn lock_bucket(&self) -> MutexGuard<'_, TokenBucket> {
+        self.bucket.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limited(per_second: u32, burst: u32) -> RateLimitedGenerator {
+        let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"]).into_shared();
+        RateLimitedGenerator::new(generator, per_second).with_burst(burst)
+    }
+
+    #[test]
+    fn rejects_requests_beyond_the_burst() {
+        let tenant = limited(1, 2);
+        assert!(tenant.try_generate().is_ok());
+        assert!(tenant.try_generate().is_ok());
+        assert!(matches!(
+            tenant.try_generate(),
+            Err(MnemonicError::RateLimited { retry_after }) if retry_after <= Duration::from_secs(1)
+        ));
+    }
+
+    #[test]
+    fn waits_for_tokens_to_refill() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let tenant = limited(50, 1);
+        let started = std::time::Instant::now();
+        runtime.block_on(async {
+            for _ in 0..3 {
+                tenant.generate().await.unwrap();
+            }
+        });
+        assert!(started.elapsed() >= Duration::from_millis(35));
+    }
+}
@@ -0,0 +1,121 @@
+//! A Redis-backed name registry, enabled by the `redis` feature.
+
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use crate::{MnemonicError, NameRegistry};
+
+/// A [`NameRegistry`] that claims names in Redis, shared by every instance
+/// of a service.
+///
+/// Checking a name claims it atomically with `SET key 1 NX`, so two
+/// instances can never both receive the same name. If Redis cannot be
+/// reached, [`MnemonicGenerator::generate_available`](crate::MnemonicGenerator::generate_available)
+/// fails with `MnemonicError::Redis` rather than hand out a name that may be
+/// in use.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use mnemonic_generator::{MnemonicGenerator, RedisNameRegistry};
+///
+/// let registry = RedisNameRegistry::open("redis://127.0.0.1/")
+///     .expect("Failed to connect to Redis")
+///     .with_key_prefix("names:")
+///     .with_ttl(Duration::from_secs(24 * 60 * 60));
+/// let name = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "hopper"])
+///     .generate_available(&registry, 100)
+///     .expect("Failed to claim a name");
+/// ```
+pub struct RedisNameRegistry {
+    connection: Mutex<redis::Connection>,
+    key_prefix: String,
+    ttl: Option<Duration>,
+}
+
+impl RedisNameRegistry {
+    /// Connects to the Redis server at `url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::Redis` if the URL is invalid or the server
+    /// cannot be reached
+    pub fn open(url: &str) -> Result<Self, MnemonicError> {
+        let connection = redis::Client::open(url)?.get_connection()?;
+        Ok(Self {
+            connection: Mutex::new(connection),
+            key_prefix: String::new(),
+            ttl: None,
+        })
+    }
+
+    /// Stores names under keys starting with `prefix`, to keep them apart
+    /// from other data in the same database.
+    pub fn with_key_prefix(mut self, prefix: &str) -> Self {
+        self.key_prefix = prefix.to_string();
+        self
+    }
+
+    /// Releases claimed names after `ttl`, so they can be issued again.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Claims a name, returning `Ok(true)` if it was still available.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::Redis` if the command fails
+    pub fn claim(&self, name: &str) -> Result<bool, MnemonicError> {
+        let mut command = redis::cmd("SET");
+        command.arg(self.key(name)).arg(1).arg("NX");
+        if let Some(ttl) = self.ttl {
+            command.arg("PX").arg(ttl.as_millis().max(1) as u64);
+        }
+
+        let mut connection = self
+            .connection
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let reply: Option<String> = command.query(&mut *connection)?;
+        Ok(reply.is_some())
+    }
+
+    /// Returns the key a name is stored under.
+    fn key(&self, name: &str) -> String {
+        format!("{}{}", self.key_prefix, name)
+    }
+}
+
+impl NameRegistry for RedisNameRegistry {
+    /// Claims the name, reporting it as taken if it was already claimed or
+    /// Redis could not be reached.
+    fn is_taken(&self, name: &str) -> bool {
+        self.try_is_taken(name).unwrap_or(true)
+    }
+
+    /// Claims the name, reporting it as taken if it was already claimed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::Redis` if the command fails
+    fn try_is_taken(&self, name: &str) -> Result<bool, MnemonicError> {
+        self.claim(name).map(|claimed| !claimed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_invalid_urls() {
+        assert!(matches!(
+            RedisNameRegistry::open("not a url"),
+            Err(MnemonicError::Redis(_))
+        ));
+    }
+}
@@ -0,0 +1,134 @@
+//! Regular expression acceptance filters, enabled by the `regex` feature.
+
+use regex_lite::Regex;
+
+use crate::{MnemonicError, MnemonicGenerator, WordFilter};
+
+/// A [`WordFilter`] allowing the words that match a regular expression.
+///
+/// Unlike [`MnemonicGenerator::with_regex`], which matches rendered names,
+/// the pattern is matched against single words.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{MnemonicGenerator, WordPattern};
+///
+/// let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "bohr"])
+///     .with_right_word_filter(WordPattern::new("^[a-m]").unwrap());
+/// assert!(generator.generate().unwrap().ends_with("_bohr"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct WordPattern {
+    regex: Regex,
+}
+
+impl WordPattern {
+    /// Compiles `pattern`, which may match anywhere in a word unless anchored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::Regex` if `pattern` is not a valid regular
+    /// expression
+    pub fn new(pattern: &str) -> Result<Self, MnemonicError> {
+        Ok(Self {
+            regex: Regex::new(pattern)?,
+        })
+    }
+}
+
+impl WordFilter for WordPattern {
+    fn allows(&self, word: &str) -> bool {
+        self.regex.is_match(word)
+    }
+}
+
+impl MnemonicGenerator {
+    /// Only emits names matching the regular expression `pattern`.
+    ///
+    /// The pattern is matched against the full rendered name, including any
+    /// prefix and suffix, and may match anywhere in it; anchor it with `^` and
+    /// `$` to match the whole name. Attaching a pattern replaces any previously
+    /// attached one.
+    ///
+    /// Names are not drawn from the matching pairs directly: candidates that
+    /// do not match are re-rolled, and after 1000 rejections generation scans
+    /// the namespace for the matching pairs and draws one of them. Names stay
+    /// uniform over the matches, but a pattern that only matches a small share
+    /// of the namespace makes every call slow. Prefer
+    /// [`WordPattern`] word filters, which are applied once up front, when the
+    /// pattern constrains single words.
+    ///
+    /// Before returning, every pair of words is checked until one renders, with
+    /// the default separator and the options configured so far, to a name the
+    /// pattern and the other filters accept. A pattern that can never match is
+    /// therefore reported here instead of by every later call to
+    /// [`MnemonicGenerator::generate`]. The check scans the whole namespace in
+    /// the worst case, so attach the pattern after the other options.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::Regex` if `pattern` is not a valid regular
+    /// expression, or `MnemonicError::InvalidConfig` if no name can match it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "bohr"])
+    ///     .with_regex("^[a-z]{5}_")
+    ///     .unwrap();
+    /// assert!(generator.generate().unwrap().starts_with("brave_"));
+    ///
+    /// let impossible = MnemonicGenerator::with_words(["hopeful"], ["turing"]).with_regex("^x");
+    /// assert!(impossible.is_err());
+    /// ```
+    pub fn with_regex(mut self, pattern: &str) -> Result<Self, MnemonicError> {
+        self.regex = Some(Regex::new(pattern)?);
+
+        let separator = self.default_separator();
+        let feasible = self.picks().any(|pick| self.is_reachable(pick, separator));
+        if !feasible {
+            return Err(MnemonicError::InvalidConfig(format!(
+                "no name can match the pattern {:?}",
+                pattern
+            )));
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_the_rendered_name() {
+        let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "bohr"])
+            .with_prefix("eu-")
+            .with_regex(r"^eu-\w+_bohr$")
+            .unwrap();
+        for _ in 0..20 {
+            assert!(generator.generate().unwrap().ends_with("_bohr"));
+        }
+        assert_eq!(generator.combination_count(), 2);
+    }
+
+    #[test]
+    fn rejects_invalid_and_impossible_patterns() {
+        let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"]);
+        assert!(matches!(
+            generator.clone().with_regex("(unclosed"),
+            Err(MnemonicError::Regex(_))
+        ));
+        assert!(matches!(
+            generator.with_regex("^turing"),
+            Err(MnemonicError::InvalidConfig(reason)) if reason.contains("^turing")
+        ));
+        assert!(matches!(
+            WordPattern::new("(unclosed"),
+            Err(MnemonicError::Regex(_))
+        ));
+    }
+}
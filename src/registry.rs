@@ -0,0 +1,184 @@
+use std::collections::{BTreeSet, HashSet};
+use std::future::Future;
+use std::hash::BuildHasher;
+
+use crate::{MnemonicError, MnemonicGenerator};
+
+/// A source of truth for names that are already in use.
+///
+/// Implement this for whatever backs your naming service (a database table, an
+/// in-memory set, a cache) and pass it to
+/// [`MnemonicGenerator::generate_available`] to re-roll until a free name is
+/// found.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{MnemonicGenerator, NameRegistry};
+///
+/// struct Reserved;
+///
+/// impl NameRegistry for Reserved {
+///     fn is_taken(&self, name: &str) -> bool {
+///         name.starts_with("boring")
+///     }
+/// }
+///
+/// let generator = MnemonicGenerator::with_words(["boring", "happy"], ["turing"]);
+/// let name = generator.generate_available(&Reserved, 100).expect("Failed to find a free name");
+/// assert_eq!(name, "happy_turing");
+/// ```
+pub trait NameRegistry {
+    /// Returns `true` if the name is already in use.
+    fn is_taken(&self, name: &str) -> bool;
+
+    /// Fallible variant of [`NameRegistry::is_taken`], used by
+    /// [`MnemonicGenerator::generate_available`].
+    ///
+    /// Registries that can fail to answer, such as `RedisNameRegistry`,
+    /// override this to report the failure instead of guessing. The default
+    /// implementation never fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error that kept the registry from answering
+    fn try_is_taken(&self, name: &str) -> Result<bool, MnemonicError> {
+        Ok(self.is_taken(name))
+    }
+}
+
+/// An asynchronous variant of [`NameRegistry`] for registries backed by
+/// network services.
+pub trait AsyncNameRegistry {
+    /// Resolves to `true` if the name is already in use.
+    fn is_taken(&self, name: &str) -> impl Future<Output = bool> + Send;
+}
+
+impl<S: BuildHasher> NameRegistry for HashSet<String, S> {
+    fn is_taken(&self, name: &str) -> bool {
+        self.contains(name)
+    }
+}
+
+impl NameRegistry for BTreeSet<String> {
+    fn is_taken(&self, name: &str) -> bool {
+        self.contains(name)
+    }
+}
+
+impl MnemonicGenerator {
+    /// Generates a name that the registry does not consider taken.
+    ///
+    /// A first candidate is drawn and then re-rolled up to `max_retries` times
+    /// while the registry reports it as taken.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::NoAvailableName` if every attempt was taken, any
+    /// error returned by [`MnemonicGenerator::generate`], or the first error
+    /// returned by [`NameRegistry::try_is_taken`]
+    pub fn generate_available<R: NameRegistry + ?Sized>(
+        &self,
+        registry: &R,
+        max_retries: usize,
+    ) -> Result<String, MnemonicError> {
+        let attempts = max_retries.saturating_add(1);
+        for _attempt in 0..attempts {
+            let name = self.generate_candidate()?;
+            if !registry.try_is_taken(&name)? {
+                self.observe(|observer| observer.on_generated(&name));
+                return Ok(name);
+            }
+            trace_event!(
+                debug,
+                name,
+                attempt = _attempt,
+                "name already taken, retrying"
+            );
+            self.observe(|observer| observer.on_collision(&name));
+        }
+
+        trace_event!(warn, attempts, "no available name found");
+        self.observe(|observer| observer.on_exhausted(attempts));
+        Err(MnemonicError::NoAvailableName { attempts })
+    }
+
+    /// Asynchronous variant of [`MnemonicGenerator::generate_available`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate_available`]
+    pub async fn generate_available_async<R: AsyncNameRegistry + ?Sized>(
+        &self,
+        registry: &R,
+        max_retries: usize,
+    ) -> Result<String, MnemonicError> {
+        let attempts = max_retries.saturating_add(1);
+        for _attempt in 0..attempts {
+            let name = self.generate_candidate()?;
+            if !registry.is_taken(&name).await {
+                self.observe(|observer| observer.on_generated(&name));
+                return Ok(name);
+            }
+            trace_event!(
+                debug,
+                name,
+                attempt = _attempt,
+                "name already taken, retrying"
+            );
+            self.observe(|observer| observer.on_collision(&name));
+        }
+
+        trace_event!(warn, attempts, "no available name found");
+        self.observe(|observer| observer.on_exhausted(attempts));
+        Err(MnemonicError::NoAvailableName { attempts })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_taken_names() {
+        let generator = MnemonicGenerator::with_words(["amazing", "legend"], ["jordan"]);
+        let taken: HashSet<String> = ["amazing_jordan".to_string()].into();
+
+        for _ in 0..20 {
+            let name = generator.generate_available(&taken, 100).unwrap();
+            assert_eq!(name, "legend_jordan");
+        }
+    }
+
+    #[test]
+    fn errors_when_every_name_is_taken() {
+        let generator = MnemonicGenerator::with_words(["amazing"], ["jordan"]);
+        let taken: BTreeSet<String> = ["amazing_jordan".to_string()].into();
+
+        assert!(matches!(
+            generator.generate_available(&taken, 3),
+            Err(MnemonicError::NoAvailableName { attempts: 4 })
+        ));
+    }
+
+    #[test]
+    fn registry_errors_stop_generation() {
+        struct Unreachable;
+
+        impl NameRegistry for Unreachable {
+            fn is_taken(&self, _name: &str) -> bool {
+                true
+            }
+
+            fn try_is_taken(&self, _name: &str) -> Result<bool, MnemonicError> {
+                Err(std::io::Error::other("registry unreachable").into())
+            }
+        }
+
+        let generator = MnemonicGenerator::with_words(["amazing"], ["jordan"]);
+        assert!(matches!(
+            generator.generate_available(&Unreachable, 3),
+            Err(MnemonicError::Io(_))
+        ));
+    }
+}
@@ -0,0 +1,214 @@
+//! Loading word lists over HTTP, enabled by the `http` feature.
+
+use std::io::Read;
+use std::time::Duration;
+
+use crate::{MnemonicError, MnemonicGenerator};
+
+/// Maximum time allowed for fetching a single word list.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum accepted size of a single word list, in bytes.
+const MAX_WORD_LIST_BYTES: usize = 1024 * 1024;
+
+impl MnemonicGenerator {
+    /// Creates a generator from two newline-delimited word lists served over HTTP.
+    ///
+    /// Blank lines and lines starting with `#` are ignored. Each list is fetched
+    /// with a timeout and validated before the generator is built.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::FetchTimeout` if a request takes too long,
+    /// `MnemonicError::Fetch` if a request fails or returns a non-success
+    /// status, and `MnemonicError::InvalidWordList` if a response is not a
+    /// usable word list
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), mnemonic_generator::MnemonicError> {
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::from_urls(
+    ///     "https://example.com/adjectives.txt",
+    ///     "https://example.com/nouns.txt",
+    /// )
+    /// .await?;
+    /// let mnemonic = generator.generate()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn from_urls(left_url: &str, right_url: &str) -> Result<Self, MnemonicError> {
+        let client = reqwest::Client::builder()
+            .timeout(FETCH_TIMEOUT)
+            .build()
+            .map_err(|e| fetch_error(left_url, e))?;
+
+        let left_words = fetch(&client, left_url).await?;
+        let right_words = fetch(&client, right_url).await?;
+        Ok(Self::with_words(left_words, right_words))
+    }
+
+    /// Blocking variant of [`MnemonicGenerator::from_urls`] for synchronous callers.
+    ///
+    /// Must not be called from within an async runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::from_urls`]
+    pub fn from_urls_blocking(left_url: &str, right_url: &str) -> Result<Self, MnemonicError> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(FETCH_TIMEOUT)
+            .build()
+            .map_err(|e| fetch_error(left_url, e))?;
+
+        let left_words = fetch_blocking(&client, left_url)?;
+        let right_words = fetch_blocking(&client, right_url)?;
+        Ok(Self::with_words(left_words, right_words))
+    }
+}
+
+/// Fetches and parses a single word list.
+async fn fetch(client: &reqwest::Client, url: &str) -> Result<Vec<String>, MnemonicError> {
+    let mut response = client
+        .get(url)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(|e| fetch_error(url, e))?;
+
+    // Stop reading as soon as the body outgrows the limit, so an oversized
+    // or endless response is never buffered in full.
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(|e| fetch_error(url, e))? {
+        body.extend_from_slice(&chunk);
+        if body.len() > MAX_WORD_LIST_BYTES {
+            break;
+        }
+    }
+    parse_word_list(url, &body)
+}
+
+/// Fetches and parses a single word list without an async runtime.
+fn fetch_blocking(
+    client: &reqwest::blocking::Client,
+    url: &str,
+) -> Result<Vec<String>, MnemonicError> {
+    let response = client
+        .get(url)
+        .send()
+        .and_then(reqwest::blocking::Response::error_for_status)
+        .map_err(|e| fetch_error(url, e))?;
+
+    // One byte past the limit is enough to tell that the body is too large.
+    let mut body = Vec::new();
+    response
+        .take(MAX_WORD_LIST_BYTES as u64 + 1)
+        .read_to_end(&mut body)
+        .map_err(|e| MnemonicError::Fetch {
+            url: url.to_string(),
+            source: Box::new(e),
+        })?;
+    parse_word_list(url, &body)
+}
+
+/// Maps a transport error to the matching `MnemonicError`.
+fn fetch_error(url: &str, error: reqwest::Error) -> MnemonicError {
+    if error.is_timeout() {
+        MnemonicError::FetchTimeout {
+            url: url.to_string(),
+        }
+    } else {
+        MnemonicError::Fetch {
+            url: url.to_string(),
+            source: Box::new(error),
+        }
+    }
+}
+
+/// Parses a newline-delimited word list, validating its size and content.
+fn parse_word_list(url: &str, body: &[u8]) -> Result<Vec<String>, MnemonicError> {
+    let invalid = |reason: &str| MnemonicError::InvalidWordList {
+        url: url.to_string(),
+        reason: reason.to_string(),
+    };
+
+    if body.len() > MAX_WORD_LIST_BYTES {
+        return Err(invalid("response exceeds the maximum word list size"));
+    }
+    let text = std::str::from_utf8(body).map_err(|_| invalid("response is not valid UTF-8"))?;
+
+    let words: Vec<String> = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect();
+
+    if words.is_empty() {
+        return Err(invalid("response contains no words"));
+    }
+    if words
+        .iter()
+        .any(|word| word.chars().any(char::is_whitespace))
+    {
+        return Err(invalid("a line contains more than one word"));
+    }
+
+    Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    use super::*;
+
+    #[test]
+    fn parses_newline_delimited_lists() {
+        let words = parse_word_list("test", b"# adjectives\namazing\r\n\n  legend \n").unwrap();
+        assert_eq!(words, vec!["amazing", "legend"]);
+    }
+
+    #[test]
+    fn rejects_invalid_content() {
+        assert!(matches!(
+            parse_word_list("test", b"\n# nothing here\n"),
+            Err(MnemonicError::InvalidWordList { .. })
+        ));
+        assert!(matches!(
+            parse_word_list("test", b"two words\n"),
+            Err(MnemonicError::InvalidWordList { .. })
+        ));
+        assert!(matches!(
+            parse_word_list("test", &[0xff, 0xfe]),
+            Err(MnemonicError::InvalidWordList { .. })
+        ));
+    }
+
+    #[test]
+    fn stops_reading_oversized_responses() {
+        // Serves a body without a length that never ends on its own.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/words.txt", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                line.clear();
+            }
+            let mut stream = stream;
+            let _ = stream.write_all(b"HTTP/1.1 200 OK\r\nconnection: close\r\n\r\n");
+            while stream.write_all(&[b'a', b'\n'].repeat(4096)).is_ok() {}
+        });
+
+        let client = reqwest::blocking::Client::new();
+        assert!(matches!(
+            fetch_blocking(&client, &url),
+            Err(MnemonicError::InvalidWordList { .. })
+        ));
+    }
+}
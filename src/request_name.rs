@@ -0,0 +1,221 @@
+//! Web framework integration, enabled by the `axum` and `actix` features.
+
+use std::fmt;
+#[cfg(feature = "axum")]
+use std::sync::Arc;
+
+use crate::MnemonicGenerator;
+
+/// A mnemonic attached to an incoming request, for correlating log lines.
+///
+/// Extracting it more than once during the same request yields the same name.
+/// The name is drawn from the shared generator in the application state: an
+/// `Arc<MnemonicGenerator>` reachable through `FromRef` with axum, or a
+/// `web::Data<MnemonicGenerator>` with actix, which falls back to
+/// [`MnemonicGenerator::shared`] if none is registered.
+///
+/// The matching middleware, [`RequestName::axum_middleware`] or
+/// [`RequestName::actix_middleware`], names every request up front and echoes
+/// the name in the [`RequestName::HEADER`] response header.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "axum", feature = "default-words"))]
+/// # {
+/// use std::sync::Arc;
+///
+/// use axum::routing::get;
+/// use axum::{middleware, Router};
+/// use mnemonic_generator::{MnemonicGenerator, RequestName};
+///
+/// async fn handler(name: RequestName) -> String {
+///     format!("handled {}", name)
+/// }
+///
+/// let generator = MnemonicGenerator::new().into_shared();
+/// let app: Router = Router::new()
+///     .route("/", get(handler))
+///     .layer(middleware::from_fn_with_state(
+///         Arc::clone(&generator),
+///         RequestName::axum_middleware,
+///     ))
+///     .with_state(generator);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RequestName(pub String);
+
+impl RequestName {
+    /// The response header carrying the name of the request.
+    pub const HEADER: &'static str = "x-request-name";
+
+    /// Returns the name.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Generates a new name with the given generator.
+    fn generate(generator: &MnemonicGenerator) -> Result<Self, crate::MnemonicError> {
+        generator.generate().map(Self)
+    }
+}
+
+impl fmt::Display for RequestName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(feature = "axum")]
+impl<S> axum::extract::FromRequestParts<S> for RequestName
+where
+    Arc<MnemonicGenerator>: axum::extract::FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = (axum::http::StatusCode, String);
+
+    async fn from_request_parts(
+        parts: &mut axum::http::request::Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        if let Some(name) = parts.extensions.get::<Self>() {
+            return Ok(name.clone());
+        }
+
+        let generator: Arc<MnemonicGenerator> = axum::extract::FromRef::from_ref(state);
+        let name = Self::generate(&generator).map_err(|error| {
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                error.to_string(),
+            )
+        })?;
+        parts.extensions.insert(name.clone());
+        Ok(name)
+    }
+}
+
+#[cfg(feature = "axum")]
+impl RequestName {
+    /// Axum middleware naming every request, for use with
+    /// `axum::middleware::from_fn_with_state`.
+    pub async fn axum_middleware(
+        axum::extract::State(generator): axum::extract::State<Arc<MnemonicGenerator>>,
+        mut request: axum::extract::Request,
+        next: axum::middleware::Next,
+    ) -> axum::response::Response {
+        use axum::response::IntoResponse;
+
+        let name = match Self::generate(&generator) {
+            Ok(name) => name,
+            Err(error) => {
+                return (
+                    axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                    error.to_string(),
+                )
+                    .into_response()
+            }
+        };
+        request.extensions_mut().insert(name.clone());
+
+        let mut response = next.run(request).await;
+        if let Ok(value) = axum::http::HeaderValue::from_str(name.as_str()) {
+            response.headers_mut().insert(Self::HEADER, value);
+        }
+        response
+    }
+}
+
+#[cfg(feature = "actix")]
+impl RequestName {
+    /// Actix middleware naming every request, for use with
+    /// `actix_web::middleware::from_fn`.
+    pub async fn actix_middleware(
+        request: actix_web::dev::ServiceRequest,
+        next: actix_web::middleware::Next<impl actix_web::body::MessageBody>,
+    ) -> Result<actix_web::dev::ServiceResponse<impl actix_web::body::MessageBody>, actix_web::Error>
+    {
+        let name = Self::for_actix(request.request())?;
+        let mut response = next.call(request).await?;
+        if let Ok(value) = actix_web::http::header::HeaderValue::from_str(name.as_str()) {
+            response.headers_mut().insert(
+                actix_web::http::header::HeaderName::from_static(Self::HEADER),
+                value,
+            );
+        }
+        Ok(response)
+    }
+
+    /// Returns the name stored in the request, generating and storing one first
+    /// if necessary.
+    fn for_actix(request: &actix_web::HttpRequest) -> Result<Self, actix_web::Error> {
+        use actix_web::HttpMessage;
+
+        if let Some(name) = request.extensions().get::<Self>() {
+            return Ok(name.clone());
+        }
+
+        let name = match request.app_data::<actix_web::web::Data<MnemonicGenerator>>() {
+            Some(generator) => Self::generate(generator),
+            None => Self::generate(&MnemonicGenerator::shared()),
+        }
+        .map_err(actix_web::error::ErrorInternalServerError)?;
+        request.extensions_mut().insert(name.clone());
+        Ok(name)
+    }
+}
+
+#[cfg(feature = "actix")]
+impl actix_web::FromRequest for RequestName {
+    type Error = actix_web::Error;
+    type Future = std::future::Ready<Result<Self, Self::Error>>;
+
+    fn from_request(
+        request: &actix_web::HttpRequest,
+        _payload: &mut actix_web::dev::Payload,
+    ) -> Self::Future {
+        std::future::ready(Self::for_actix(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "axum")]
+    #[test]
+    fn axum_extracts_one_name_per_request() {
+        use std::future::Future;
+        use std::pin::pin;
+        use std::task::{Context, Poll, Waker};
+
+        use axum::extract::FromRequestParts;
+
+        let generator = Arc::new(MnemonicGenerator::with_words(["hopeful"], ["turing"]));
+        let (mut parts, ()) = axum::http::Request::new(()).into_parts();
+        let mut extract = || {
+            let future = pin!(RequestName::from_request_parts(&mut parts, &generator));
+            match future.poll(&mut Context::from_waker(Waker::noop())) {
+                Poll::Ready(name) => name.unwrap(),
+                Poll::Pending => unreachable!("extraction never waits"),
+            }
+        };
+
+        let name = extract();
+        assert_eq!(name.as_str(), "hopeful_turing");
+        assert_eq!(extract(), name);
+    }
+
+    #[cfg(feature = "actix")]
+    #[test]
+    fn actix_uses_registered_generator() {
+        let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"]);
+        let request = actix_web::test::TestRequest::default()
+            .app_data(actix_web::web::Data::new(generator))
+            .to_http_request();
+
+        let name = RequestName::for_actix(&request).unwrap();
+        assert_eq!(name.to_string(), "hopeful_turing");
+        assert_eq!(RequestName::for_actix(&request).unwrap(), name);
+    }
+}
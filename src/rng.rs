@@ -0,0 +1,90 @@
+//! The random number generator used when the caller does not supply one.
+//!
+//! By default this is rand's thread-local generator. The `fastrand` feature
+//! switches to `fastrand`'s thread-local wyrand generator, and the `small-rng`
+//! feature to a thread-local [`SmallRng`](rand::rngs::SmallRng) seeded once per
+//! thread from the operating system. If both are enabled, `fastrand` is used.
+//! Neither is suitable where names must be unpredictable.
+
+#[cfg(any(feature = "fastrand", feature = "small-rng"))]
+use rand::RngCore;
+
+/// Returns a handle to the default random number generator of this thread.
+#[cfg(not(any(feature = "fastrand", feature = "small-rng")))]
+pub(crate) fn default_rng() -> rand::rngs::ThreadRng {
+    rand::rng()
+}
+
+/// Returns a handle to the default random number generator of this thread.
+#[cfg(feature = "fastrand")]
+pub(crate) fn default_rng() -> FastRng {
+    FastRng
+}
+
+/// Returns a handle to the default random number generator of this thread.
+#[cfg(all(feature = "small-rng", not(feature = "fastrand")))]
+pub(crate) fn default_rng() -> LocalSmallRng {
+    LocalSmallRng
+}
+
+/// Draws from `fastrand`'s thread-local generator.
+#[cfg(feature = "fastrand")]
+pub(crate) struct FastRng;
+
+#[cfg(feature = "fastrand")]
+impl RngCore for FastRng {
+    fn next_u32(&mut self) -> u32 {
+        fastrand::u32(..)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        fastrand::u64(..)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        fastrand::fill(dst);
+    }
+}
+
+#[cfg(all(feature = "small-rng", not(feature = "fastrand")))]
+thread_local! {
+    static SMALL_RNG: std::cell::RefCell<rand::rngs::SmallRng> =
+        std::cell::RefCell::new(rand::SeedableRng::from_os_rng());
+}
+
+/// Draws from a thread-local `SmallRng`.
+#[cfg(all(feature = "small-rng", not(feature = "fastrand")))]
+pub(crate) struct LocalSmallRng;
+
+#[cfg(all(feature = "small-rng", not(feature = "fastrand")))]
+impl RngCore for LocalSmallRng {
+    fn next_u32(&mut self) -> u32 {
+        SMALL_RNG.with(|rng| rng.borrow_mut().next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        SMALL_RNG.with(|rng| rng.borrow_mut().next_u64())
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        SMALL_RNG.with(|rng| rng.borrow_mut().fill_bytes(dst));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::*;
+    use crate::MnemonicGenerator;
+
+    #[test]
+    fn default_rng_drives_generation() {
+        let mut rng = default_rng();
+        let draws: Vec<u64> = (0..4).map(|_| rng.random()).collect();
+        assert!(draws.windows(2).any(|pair| pair[0] != pair[1]));
+
+        let generator = MnemonicGenerator::with_words(["happy"], ["turing"]);
+        assert_eq!(generator.generate().unwrap(), "happy_turing");
+    }
+}
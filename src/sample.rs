@@ -0,0 +1,74 @@
+use rand::RngCore;
+
+/// Returns a uniformly distributed index below `len`, which must be non-zero.
+///
+/// Uses Lemire's widening multiplication with rejection on `next_u64`, so the
+/// result depends only on the generator's output: it is the same on 32- and
+/// 64-bit platforms and does not change with rand's distribution code. The
+/// stability guarantee of [`crate::SeededGenerator`] relies on this.
+pub(crate) fn index<R: RngCore + ?Sized>(rng: &mut R, len: usize) -> usize {
+    index_u64(rng, len as u64) as usize
+}
+
+/// Like [`index`], for ranges that may not fit in a `usize` or `u64`.
+///
+/// Ranges that fit in a `u64` draw exactly what [`index`] draws. Wider ones
+/// take the top bits of two `next_u64` calls and reject values that are out
+/// of range.
+pub(crate) fn index_u128<R: RngCore + ?Sized>(rng: &mut R, len: u128) -> u128 {
+    if let Ok(len) = u64::try_from(len) {
+        return u128::from(index_u64(rng, len));
+    }
+    let shift = (len - 1).leading_zeros();
+    loop {
+        let bits = (u128::from(rng.next_u64()) << 64) | u128::from(rng.next_u64());
+        let candidate = bits >> shift;
+        if candidate < len {
+            return candidate;
+        }
+    }
+}
+
+/// Lemire's method over a non-zero `u64` range.
+fn index_u64<R: RngCore + ?Sized>(rng: &mut R, len: u64) -> u64 {
+    let threshold = len.wrapping_neg() % len;
+    loop {
+        let product = u128::from(rng.next_u64()) * u128::from(len);
+        if product as u64 >= threshold {
+            return (product >> 64) as u64;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha12Rng;
+
+    use super::*;
+
+    #[test]
+    fn indices_are_in_range_and_cover_it() {
+        let mut rng = ChaCha12Rng::seed_from_u64(1);
+        let mut seen = [false; 7];
+        for _ in 0..200 {
+            seen[index(&mut rng, 7)] = true;
+        }
+        assert!(seen.iter().all(|&seen| seen));
+        assert_eq!(index(&mut rng, 1), 0);
+    }
+
+    #[test]
+    fn wide_indices_match_narrow_ones_and_stay_in_range() {
+        let (mut narrow, mut wide) = (ChaCha12Rng::seed_from_u64(2), ChaCha12Rng::seed_from_u64(2));
+        for _ in 0..20 {
+            assert_eq!(
+                index(&mut narrow, 1000) as u128,
+                index_u128(&mut wide, 1000)
+            );
+        }
+
+        let len = u128::from(u64::MAX) * 3;
+        assert!((0..200).all(|_| index_u128(&mut wide, len) < len));
+    }
+}
@@ -0,0 +1,1751 @@
+use crate::word_list::{WordList, WordLists};
+use crate::{words, MnemonicGenerator};
+
+/// Biographical details about the person behind one of the default right-hand words.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::scientist_info;
+///
+/// let turing = scientist_info("turing").unwrap();
+/// assert_eq!(turing.name, "Alan Turing");
+/// assert_eq!(turing.first_name, Some("alan"));
+/// assert_eq!(turing.wikipedia_url, Some("https://en.wikipedia.org/wiki/Alan_Turing"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PersonInfo {
+    /// The word as it appears in the default word list.
+    pub surname: &'static str,
+    /// The person's full name.
+    pub name: &'static str,
+    /// The person's first name in lowercase ASCII, as used by
+    /// [`MnemonicGenerator::generate_full_name`](crate::MnemonicGenerator::generate_full_name),
+    /// or `None` for people known by a single name.
+    pub first_name: Option<&'static str>,
+    /// A short summary of why they are notable.
+    pub description: &'static str,
+    /// Link to the person's Wikipedia article, if there is one.
+    pub wikipedia_url: Option<&'static str>,
+}
+
+/// Looks up the person behind a word from the default right-hand list.
+///
+/// Matching ignores ASCII case. Returns `None` for words that are not part of
+/// the default list, including words from custom lists and language packs.
+pub fn scientist_info(surname: &str) -> Option<PersonInfo> {
+    PEOPLE
+        .iter()
+        .find(|person| person.surname.eq_ignore_ascii_case(surname))
+        .copied()
+}
+
+impl MnemonicGenerator {
+    /// Creates a generator pairing custom left-hand words with the built-in
+    /// scientist surnames.
+    ///
+    /// Needs only the `scientists` feature, so a build can ship its own
+    /// adjectives without the default ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::{scientist_info, MnemonicGenerator};
+    ///
+    /// let generator = MnemonicGenerator::with_scientists(["sunny", "stormy"]);
+    /// let mnemonic = generator.parse(&generator.generate().unwrap()).unwrap();
+    /// assert!(scientist_info(mnemonic.right()).is_some());
+    /// ```
+    pub fn with_scientists<L>(left_words: L) -> Self
+    where
+        L: IntoIterator,
+        L::Item: Into<String>,
+    {
+        Self::from_lists(WordLists::new(
+            WordList::Owned(left_words.into_iter().map(Into::into).collect()),
+            WordList::Static(words::RIGHT_WORDS),
+        ))
+    }
+}
+
+/// One entry per word of `words::RIGHT_WORDS`, in the same order.
+static PEOPLE: &[PersonInfo] = &[
+    PersonInfo {
+        surname: "agnesi",
+        name: "Maria Gaetana Agnesi",
+        first_name: Some("maria"),
+        description: "Italian mathematician, philosopher, theologian and humanitarian. She was the first woman to write a mathematics handbook and the first woman appointed as a Mathematics Professor at a University.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Maria_Gaetana_Agnesi"),
+    },
+    PersonInfo {
+        surname: "albattani",
+        name: "Muhammad ibn Jābir al-Ḥarrānī al-Battānī",
+        first_name: Some("muhammad"),
+        description: "Muhammad ibn Jābir al-Ḥarrānī al-Battānī was a founding father of astronomy.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Mu%E1%B8%A5ammad_ibn_J%C4%81bir_al-%E1%B8%A4arr%C4%81n%C4%AB_al-Batt%C4%81n%C4%AB"),
+    },
+    PersonInfo {
+        surname: "allen",
+        name: "Frances E. Allen",
+        first_name: Some("frances"),
+        description: "Frances E. Allen, became the first female IBM Fellow in 1989. In 2006, she became the first female recipient of the ACM's Turing Award.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Frances_E._Allen"),
+    },
+    PersonInfo {
+        surname: "almeida",
+        name: "June Almeida",
+        first_name: Some("june"),
+        description: "Scottish virologist who took the first pictures of the rubella virus",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/June_Almeida"),
+    },
+    PersonInfo {
+        surname: "antonelli",
+        name: "Kathleen Antonelli",
+        first_name: Some("kathleen"),
+        description: "Kathleen Antonelli, American computer programmer and one of the six original programmers of the ENIAC",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Kathleen_Antonelli"),
+    },
+    PersonInfo {
+        surname: "archimedes",
+        name: "Archimedes",
+        first_name: None,
+        description: "Archimedes was a physicist, engineer and mathematician who invented too many things to list them here.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Archimedes"),
+    },
+    PersonInfo {
+        surname: "ardinghelli",
+        name: "Maria Ardinghelli",
+        first_name: Some("maria"),
+        description: "Italian translator, mathematician and physicist",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Maria_Ardinghelli"),
+    },
+    PersonInfo {
+        surname: "aryabhata",
+        name: "Aryabhata",
+        first_name: None,
+        description: "Ancient Indian mathematician-astronomer during 476-550 CE",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Aryabhata"),
+    },
+    PersonInfo {
+        surname: "austin",
+        name: "Wanda Austin",
+        first_name: Some("wanda"),
+        description: "Wanda Austin is the President and CEO of The Aerospace Corporation, a leading architect for the US security space programs.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Wanda_Austin"),
+    },
+    PersonInfo {
+        surname: "babbage",
+        name: "Charles Babbage",
+        first_name: Some("charles"),
+        description: "Charles Babbage invented the concept of a programmable computer.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Charles_Babbage"),
+    },
+    PersonInfo {
+        surname: "banach",
+        name: "Stefan Banach",
+        first_name: Some("stefan"),
+        description: "Polish mathematician, was one of the founders of modern functional analysis.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Stefan_Banach"),
+    },
+    PersonInfo {
+        surname: "banzai",
+        name: "Buckaroo Banzai",
+        first_name: Some("buckaroo"),
+        description: "Buckaroo Banzai and his mentor Dr. Hikita perfected the \"oscillation overthruster\", a device that allows one to pass through solid matter.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/The_Adventures_of_Buckaroo_Banzai_Across_the_8th_Dimension"),
+    },
+    PersonInfo {
+        surname: "bardeen",
+        name: "John Bardeen",
+        first_name: Some("john"),
+        description: "John Bardeen co-invented the transistor",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/John_Bardeen"),
+    },
+    PersonInfo {
+        surname: "bartik",
+        name: "Jean Bartik",
+        first_name: Some("jean"),
+        description: "Jean Bartik, born Betty Jean Jennings, was one of the original programmers for the ENIAC computer.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Jean_Bartik"),
+    },
+    PersonInfo {
+        surname: "bassi",
+        name: "Laura Bassi",
+        first_name: Some("laura"),
+        description: "Laura Bassi, the world's first female professor",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Laura_Bassi"),
+    },
+    PersonInfo {
+        surname: "beaver",
+        name: "Hugh Beaver",
+        first_name: Some("hugh"),
+        description: "Hugh Beaver, British engineer, founder of the Guinness Book of World Records",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Hugh_Beaver"),
+    },
+    PersonInfo {
+        surname: "bell",
+        name: "Alexander Graham Bell",
+        first_name: Some("alexander"),
+        description: "an eminent Scottish-born scientist, inventor, engineer and innovator who is credited with inventing the first practical telephone",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Alexander_Graham_Bell"),
+    },
+    PersonInfo {
+        surname: "benz",
+        name: "Karl Friedrich Benz",
+        first_name: Some("karl"),
+        description: "a German automobile engineer. Inventor of the first practical motorcar.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Karl_Benz"),
+    },
+    PersonInfo {
+        surname: "bhabha",
+        name: "Homi J Bhabha",
+        first_name: Some("homi"),
+        description: "was an Indian nuclear physicist, founding director, and professor of physics at the Tata Institute of Fundamental Research. Colloquially known as \"father of Indian nuclear programme\"",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Homi_J._Bhabha"),
+    },
+    PersonInfo {
+        surname: "bhaskara",
+        name: "Bhaskara II",
+        first_name: None,
+        description: "Ancient Indian mathematician-astronomer whose work on calculus predates Newton and Leibniz by over half a millennium",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Bh%C4%81skara_II#Calculus"),
+    },
+    PersonInfo {
+        surname: "black",
+        name: "Sue Black",
+        first_name: Some("sue"),
+        description: "British computer scientist and campaigner. She has been instrumental in saving Bletchley Park, the site of World War II codebreaking",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Sue_Black_(computer_scientist)"),
+    },
+    PersonInfo {
+        surname: "blackburn",
+        name: "Elizabeth Helen Blackburn",
+        first_name: Some("elizabeth"),
+        description: "Australian-American Nobel laureate; best known for co-discovering telomerase.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Elizabeth_Blackburn"),
+    },
+    PersonInfo {
+        surname: "blackwell",
+        name: "Elizabeth Blackwell",
+        first_name: Some("elizabeth"),
+        description: "American doctor and first American woman to receive a medical degree",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Elizabeth_Blackwell"),
+    },
+    PersonInfo {
+        surname: "bohr",
+        name: "Niels Bohr",
+        first_name: Some("niels"),
+        description: "Niels Bohr is the father of quantum theory.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Niels_Bohr"),
+    },
+    PersonInfo {
+        surname: "booth",
+        name: "Kathleen Booth",
+        first_name: Some("kathleen"),
+        description: "Kathleen Booth, she's credited with writing the first assembly language.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Kathleen_Booth"),
+    },
+    PersonInfo {
+        surname: "borg",
+        name: "Anita Borg",
+        first_name: Some("anita"),
+        description: "Anita Borg was the founding director of the Institute for Women and Technology (IWT).",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Anita_Borg"),
+    },
+    PersonInfo {
+        surname: "bose",
+        name: "Satyendra Nath Bose",
+        first_name: Some("satyendra"),
+        description: "He provided the foundation for Bose–Einstein statistics and the theory of the Bose–Einstein condensate.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Satyendra_Nath_Bose"),
+    },
+    PersonInfo {
+        surname: "bouman",
+        name: "Katherine Louise Bouman",
+        first_name: Some("katherine"),
+        description: "Katherine Louise Bouman is an imaging scientist and Assistant Professor of Computer Science at the California Institute of Technology. She researches computational methods for imaging, and developed an algorithm that made possible the picture first visualization of a black hole using the Event Horizon Telescope.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Katie_Bouman"),
+    },
+    PersonInfo {
+        surname: "boyd",
+        name: "Evelyn Boyd Granville",
+        first_name: Some("evelyn"),
+        description: "She was one of the first African-American woman to receive a Ph.D. in mathematics; she earned it in 1949 from Yale University.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Evelyn_Boyd_Granville"),
+    },
+    PersonInfo {
+        surname: "brahmagupta",
+        name: "Brahmagupta",
+        first_name: None,
+        description: "Ancient Indian mathematician during 598-670 CE who gave rules to compute with zero",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Brahmagupta#Zero"),
+    },
+    PersonInfo {
+        surname: "brattain",
+        name: "Walter Houser Brattain",
+        first_name: Some("walter"),
+        description: "Walter Houser Brattain co-invented the transistor",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Walter_Houser_Brattain"),
+    },
+    PersonInfo {
+        surname: "brown",
+        name: "Emmett Brown",
+        first_name: Some("emmett"),
+        description: "Emmett Brown invented time travel. (thanks Brian Goff)",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Emmett_Brown"),
+    },
+    PersonInfo {
+        surname: "buck",
+        name: "Linda Brown Buck",
+        first_name: Some("linda"),
+        description: "American biologist and Nobel laureate best known for her genetic and molecular analyses of the mechanisms of smell.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Linda_B._Buck"),
+    },
+    PersonInfo {
+        surname: "burnell",
+        name: "Dame Susan Jocelyn Bell Burnell",
+        first_name: Some("jocelyn"),
+        description: "Northern Irish astrophysicist who discovered radio pulsars and was the first to analyse them.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Jocelyn_Bell_Burnell"),
+    },
+    PersonInfo {
+        surname: "cannon",
+        name: "Annie Jump Cannon",
+        first_name: Some("annie"),
+        description: "pioneering female astronomer who classified hundreds of thousands of stars and created the system we use to understand stars today.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Annie_Jump_Cannon"),
+    },
+    PersonInfo {
+        surname: "carson",
+        name: "Rachel Carson",
+        first_name: Some("rachel"),
+        description: "American marine biologist and conservationist, her book Silent Spring and other writings are credited with advancing the global environmental movement.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Rachel_Carson"),
+    },
+    PersonInfo {
+        surname: "cartwright",
+        name: "Dame Mary Lucy Cartwright",
+        first_name: Some("mary"),
+        description: "British mathematician who was one of the first to study what is now known as chaos theory. Also known for Cartwright's theorem which finds applications in signal processing.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Mary_Cartwright"),
+    },
+    PersonInfo {
+        surname: "carver",
+        name: "George Washington Carver",
+        first_name: Some("george"),
+        description: "American agricultural scientist and inventor. He was the most prominent black scientist of the early 20th century.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/George_Washington_Carver"),
+    },
+    PersonInfo {
+        surname: "cerf",
+        name: "Vinton Gray Cerf",
+        first_name: Some("vinton"),
+        description: "American Internet pioneer, recognised as one of \"the fathers of the Internet\". With Robert Elliot Kahn, he designed TCP and IP, the primary data communication protocols of the Internet and other computer networks.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Vint_Cerf"),
+    },
+    PersonInfo {
+        surname: "chandrasekhar",
+        name: "Subrahmanyan Chandrasekhar",
+        first_name: Some("subrahmanyan"),
+        description: "Astrophysicist known for his mathematical theory on different stages and evolution in structures of the stars. He has won nobel prize for physics",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Subrahmanyan_Chandrasekhar"),
+    },
+    PersonInfo {
+        surname: "chaplygin",
+        name: "Sergey Alexeyevich Chaplygin",
+        first_name: Some("sergey"),
+        description: "Sergey Alexeyevich Chaplygin (Russian: Серге́й Алексе́евич Чаплы́гин; April 5, 1869 – October 8, 1942) was a Russian and Soviet physicist, mathematician, and mechanical engineer. He is known for mathematical formulas such as Chaplygin's equation and for a hypothetical substance in cosmology called Chaplygin gas, named after him.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Sergey_Chaplygin"),
+    },
+    PersonInfo {
+        surname: "chatelet",
+        name: "Émilie du Châtelet",
+        first_name: Some("emilie"),
+        description: "French natural philosopher, mathematician, physicist, and author during the early 1730s, known for her translation of and commentary on Isaac Newton's book Principia containing basic laws of physics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/%C3%89milie_du_Ch%C3%A2telet"),
+    },
+    PersonInfo {
+        surname: "chatterjee",
+        name: "Asima Chatterjee",
+        first_name: Some("asima"),
+        description: "Asima Chatterjee was an Indian organic chemist noted for her research on vinca alkaloids, development of drugs for treatment of epilepsy and malaria",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Asima_Chatterjee"),
+    },
+    PersonInfo {
+        surname: "chaum",
+        name: "David Lee Chaum",
+        first_name: Some("david"),
+        description: "American computer scientist and cryptographer. Known for his seminal contributions in the field of anonymous communication.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/David_Chaum"),
+    },
+    PersonInfo {
+        surname: "chebyshev",
+        name: "Pafnuty Chebyshev",
+        first_name: Some("pafnuty"),
+        description: "Russian mathematician. He is known fo his works on probability, statistics, mechanics, analytical geometry and number theory",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Pafnuty_Chebyshev"),
+    },
+    PersonInfo {
+        surname: "clarke",
+        name: "Joan Clarke",
+        first_name: Some("joan"),
+        description: "Bletchley Park code breaker during the Second World War who pioneered techniques that remained top secret for decades. Also an accomplished numismatist",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Joan_Clarke"),
+    },
+    PersonInfo {
+        surname: "cohen",
+        name: "Bram Cohen",
+        first_name: Some("bram"),
+        description: "American computer programmer and author of the BitTorrent peer-to-peer protocol.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Bram_Cohen"),
+    },
+    PersonInfo {
+        surname: "colden",
+        name: "Jane Colden",
+        first_name: Some("jane"),
+        description: "American botanist widely considered the first female American botanist",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Jane_Colden"),
+    },
+    PersonInfo {
+        surname: "cori",
+        name: "Gerty Theresa Cori",
+        first_name: Some("gerty"),
+        description: "American biochemist who became the third woman—and first American woman—to win a Nobel Prize in science, and the first woman to be awarded the Nobel Prize in Physiology or Medicine. Cori was born in Prague.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Gerty_Cori"),
+    },
+    PersonInfo {
+        surname: "cray",
+        name: "Seymour Roger Cray",
+        first_name: Some("seymour"),
+        description: "Seymour Roger Cray was an American electrical engineer and supercomputer architect who designed a series of computers that were the fastest in the world for decades.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Seymour_Cray"),
+    },
+    PersonInfo {
+        surname: "curran",
+        name: "Joan and Samuel Curran",
+        first_name: Some("joan"),
+        description: "This entry reflects a husband and wife team who worked together: Joan Curran was a Welsh scientist who developed radar and invented chaff, a radar countermeasure. Samuel Curran was an Irish physicist who worked alongside his wife during WWII and invented the proximity fuse.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Samuel_Curran"),
+    },
+    PersonInfo {
+        surname: "curie",
+        name: "Marie Curie",
+        first_name: Some("marie"),
+        description: "Marie Curie discovered radioactivity.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Marie_Curie"),
+    },
+    PersonInfo {
+        surname: "darwin",
+        name: "Charles Darwin",
+        first_name: Some("charles"),
+        description: "Charles Darwin established the principles of natural evolution.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Charles_Darwin"),
+    },
+    PersonInfo {
+        surname: "davinci",
+        name: "Leonardo Da Vinci",
+        first_name: Some("leonardo"),
+        description: "Leonardo Da Vinci invented too many things to list here.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Leonardo_da_Vinci"),
+    },
+    PersonInfo {
+        surname: "dewdney",
+        name: "Alexander Keewatin Dewdney",
+        first_name: Some("alexander"),
+        description: "A. K. (Alexander Keewatin) Dewdney, Canadian mathematician, computer scientist, author and filmmaker. Contributor to Scientific American's \"Computer Recreations\" from 1984 to 1991. Author of Core War (program), The Planiverse, The Armchair Universe, The Magic Machine, The New Turing Omnibus, and more.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Alexander_Dewdney"),
+    },
+    PersonInfo {
+        surname: "dhawan",
+        name: "Satish Dhawan",
+        first_name: Some("satish"),
+        description: "Indian mathematician and aerospace engineer, known for leading the successful and indigenous development of the Indian space programme.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Satish_Dhawan"),
+    },
+    PersonInfo {
+        surname: "diffie",
+        name: "Bailey Whitfield Diffie",
+        first_name: Some("bailey"),
+        description: "American cryptographer and one of the pioneers of public-key cryptography.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Whitfield_Diffie"),
+    },
+    PersonInfo {
+        surname: "dijkstra",
+        name: "Edsger Wybe Dijkstra",
+        first_name: Some("edsger"),
+        description: "Edsger Wybe Dijkstra was a Dutch computer scientist and mathematical scientist.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Edsger_W._Dijkstra"),
+    },
+    PersonInfo {
+        surname: "dirac",
+        name: "Paul Adrien Maurice Dirac",
+        first_name: Some("paul"),
+        description: "English theoretical physicist who made fundamental contributions to the early development of both quantum mechanics and quantum electrodynamics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Paul_Dirac"),
+    },
+    PersonInfo {
+        surname: "driscoll",
+        name: "Agnes Meyer Driscoll",
+        first_name: Some("agnes"),
+        description: "American cryptanalyst during World Wars I and II who successfully cryptanalysed a number of Japanese ciphers. She was also the co-developer of one of the cipher machines of the US Navy, the CM.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Agnes_Meyer_Driscoll"),
+    },
+    PersonInfo {
+        surname: "dubinsky",
+        name: "Donna Dubinsky",
+        first_name: Some("donna"),
+        description: "played an integral role in the development of personal digital assistants (PDAs) serving as CEO of Palm, Inc. and co-founding Handspring.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Donna_Dubinsky"),
+    },
+    PersonInfo {
+        surname: "easley",
+        name: "Annie Easley",
+        first_name: Some("annie"),
+        description: "She was a leading member of the team which developed software for the Centaur rocket stage and one of the first African-Americans in her field.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Annie_Easley"),
+    },
+    PersonInfo {
+        surname: "edison",
+        name: "Thomas Alva Edison",
+        first_name: Some("thomas"),
+        description: "Thomas Alva Edison, prolific inventor",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Thomas_Edison"),
+    },
+    PersonInfo {
+        surname: "einstein",
+        name: "Albert Einstein",
+        first_name: Some("albert"),
+        description: "Albert Einstein invented the general theory of relativity.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Albert_Einstein"),
+    },
+    PersonInfo {
+        surname: "elbakyan",
+        name: "Alexandra Asanovna Elbakyan",
+        first_name: Some("alexandra"),
+        description: "Alexandra Asanovna Elbakyan (Russian: Алекса́ндра Аса́новна Элбакя́н) is a Kazakhstani graduate student, computer programmer, internet pirate in hiding, and the creator of the site Sci-Hub. Nature has listed her in 2016 in the top ten people that mattered in science, and Ars Technica has compared her to Aaron Swartz.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Alexandra_Elbakyan"),
+    },
+    PersonInfo {
+        surname: "elgamal",
+        name: "Taher A. ElGamal",
+        first_name: Some("taher"),
+        description: "Egyptian cryptographer best known for the ElGamal discrete log cryptosystem and the ElGamal digital signature scheme.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Taher_Elgamal"),
+    },
+    PersonInfo {
+        surname: "elion",
+        name: "Gertrude Elion",
+        first_name: Some("gertrude"),
+        description: "American biochemist, pharmacologist and the 1988 recipient of the Nobel Prize in Medicine",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Gertrude_Elion"),
+    },
+    PersonInfo {
+        surname: "ellis",
+        name: "James Henry Ellis",
+        first_name: Some("james"),
+        description: "British engineer and cryptographer employed by the GCHQ. Best known for conceiving for the first time, the idea of public-key cryptography.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/James_H._Ellis"),
+    },
+    PersonInfo {
+        surname: "engelbart",
+        name: "Douglas Engelbart",
+        first_name: Some("douglas"),
+        description: "Douglas Engelbart gave the mother of all demos:",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Douglas_Engelbart"),
+    },
+    PersonInfo {
+        surname: "euclid",
+        name: "Euclid",
+        first_name: None,
+        description: "Euclid invented geometry.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Euclid"),
+    },
+    PersonInfo {
+        surname: "euler",
+        name: "Leonhard Euler",
+        first_name: Some("leonhard"),
+        description: "Leonhard Euler invented large parts of modern mathematics.",
+        wikipedia_url: Some("https://de.wikipedia.org/wiki/Leonhard_Euler"),
+    },
+    PersonInfo {
+        surname: "faraday",
+        name: "Michael Faraday",
+        first_name: Some("michael"),
+        description: "British scientist who contributed to the study of electromagnetism and electrochemistry.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Michael_Faraday"),
+    },
+    PersonInfo {
+        surname: "feistel",
+        name: "Horst Feistel",
+        first_name: Some("horst"),
+        description: "German-born American cryptographer who was one of the earliest non-government researchers to study the design and theory of block ciphers. Co-developer of DES and Lucifer. Feistel networks, a symmetric structure used in the construction of block ciphers are named after him.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Horst_Feistel"),
+    },
+    PersonInfo {
+        surname: "fermat",
+        name: "Pierre de Fermat",
+        first_name: Some("pierre"),
+        description: "Pierre de Fermat pioneered several aspects of modern mathematics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Pierre_de_Fermat"),
+    },
+    PersonInfo {
+        surname: "fermi",
+        name: "Enrico Fermi",
+        first_name: Some("enrico"),
+        description: "Enrico Fermi invented the first nuclear reactor.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Enrico_Fermi"),
+    },
+    PersonInfo {
+        surname: "feynman",
+        name: "Richard Feynman",
+        first_name: Some("richard"),
+        description: "Richard Feynman was a key contributor to quantum mechanics and particle physics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Richard_Feynman"),
+    },
+    PersonInfo {
+        surname: "franklin",
+        name: "Benjamin Franklin",
+        first_name: Some("benjamin"),
+        description: "Benjamin Franklin is famous for his experiments in electricity and the invention of the lightning rod.",
+        wikipedia_url: None,
+    },
+    PersonInfo {
+        surname: "gagarin",
+        name: "Yuri Alekseyevich Gagarin",
+        first_name: Some("yuri"),
+        description: "Soviet pilot and cosmonaut, best known as the first human to journey into outer space.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Yuri_Gagarin"),
+    },
+    PersonInfo {
+        surname: "galileo",
+        name: "Galileo",
+        first_name: None,
+        description: "Galileo was a founding father of modern astronomy, and faced politics and obscurantism to establish scientific truth.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Galileo_Galilei"),
+    },
+    PersonInfo {
+        surname: "galois",
+        name: "Évariste Galois",
+        first_name: Some("evariste"),
+        description: "French mathematician whose work laid the foundations of Galois theory and group theory, two major branches of abstract algebra, and the subfield of Galois connections, all while still in his late teens.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/%C3%89variste_Galois"),
+    },
+    PersonInfo {
+        surname: "ganguly",
+        name: "Kadambini Ganguly",
+        first_name: Some("kadambini"),
+        description: "Indian physician, known for being the first South Asian female physician, trained in western medicine, to graduate in South Asia.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Kadambini_Ganguly"),
+    },
+    PersonInfo {
+        surname: "gates",
+        name: "William Henry \"Bill\" Gates III",
+        first_name: Some("bill"),
+        description: "William Henry \"Bill\" Gates III is an American business magnate, philanthropist, investor, computer programmer, and inventor.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Bill_Gates"),
+    },
+    PersonInfo {
+        surname: "gauss",
+        name: "Johann Carl Friedrich Gauss",
+        first_name: Some("johann"),
+        description: "German mathematician who made significant contributions to many fields, including number theory, algebra, statistics, analysis, differential geometry, geodesy, geophysics, mechanics, electrostatics, magnetic fields, astronomy, matrix theory, and optics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Carl_Friedrich_Gauss"),
+    },
+    PersonInfo {
+        surname: "germain",
+        name: "Marie-Sophie Germain",
+        first_name: Some("marie-sophie"),
+        description: "French mathematician, physicist and philosopher. Known for her work on elasticity theory, number theory and philosophy.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Sophie_Germain"),
+    },
+    PersonInfo {
+        surname: "goldberg",
+        name: "Adele Goldberg",
+        first_name: Some("adele"),
+        description: "Adele Goldberg, was one of the designers and developers of the Smalltalk language.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Adele_Goldberg_(computer_scientist)"),
+    },
+    PersonInfo {
+        surname: "goldstine",
+        name: "Adele Goldstine",
+        first_name: Some("adele"),
+        description: "Adele Goldstine, born Adele Katz, wrote the complete technical description for the first electronic digital computer, ENIAC.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Adele_Goldstine"),
+    },
+    PersonInfo {
+        surname: "goldwasser",
+        name: "Shafi Goldwasser",
+        first_name: Some("shafi"),
+        description: "Shafi Goldwasser is a computer scientist known for creating theoretical foundations of modern cryptography. Winner of 2012 ACM Turing Award.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Shafi_Goldwasser"),
+    },
+    PersonInfo {
+        surname: "golick",
+        name: "James Golick",
+        first_name: Some("james"),
+        description: "James Golick, all around gangster.",
+        wikipedia_url: None,
+    },
+    PersonInfo {
+        surname: "goodall",
+        name: "Jane Goodall",
+        first_name: Some("jane"),
+        description: "British primatologist, ethologist, and anthropologist who is considered to be the world's foremost expert on chimpanzees",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Jane_Goodall"),
+    },
+    PersonInfo {
+        surname: "gould",
+        name: "Stephen Jay Gould",
+        first_name: Some("stephen"),
+        description: "Stephen Jay Gould was was an American paleontologist, evolutionary biologist, and historian of science. He is most famous for the theory of punctuated equilibrium",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Stephen_Jay_Gould"),
+    },
+    PersonInfo {
+        surname: "greider",
+        name: "Carolyn Widney Greider",
+        first_name: Some("carolyn"),
+        description: "American molecular biologist and joint winner of the 2009 Nobel Prize for Physiology or Medicine for the discovery of telomerase.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Carol_W._Greider"),
+    },
+    PersonInfo {
+        surname: "grothendieck",
+        name: "Alexander Grothendieck",
+        first_name: Some("alexander"),
+        description: "German-born French mathematician who became a leading figure in the creation of modern algebraic geometry.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Alexander_Grothendieck"),
+    },
+    PersonInfo {
+        surname: "haibt",
+        name: "Lois Haibt",
+        first_name: Some("lois"),
+        description: "American computer scientist, part of the team at IBM that developed FORTRAN",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Lois_Haibt"),
+    },
+    PersonInfo {
+        surname: "hamilton",
+        name: "Margaret Hamilton",
+        first_name: Some("margaret"),
+        description: "Director of the Software Engineering Division of the MIT Instrumentation Laboratory, which developed on-board flight software for the Apollo space program.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Margaret_Hamilton_(scientist)"),
+    },
+    PersonInfo {
+        surname: "haslett",
+        name: "Caroline Harriet Haslett",
+        first_name: Some("caroline"),
+        description: "English electrical engineer, electricity industry administrator and champion of women's rights. Co-author of British Standard 1363 that specifies AC power plugs and sockets used across the United Kingdom (which is widely considered as one of the safest designs).",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Caroline_Haslett"),
+    },
+    PersonInfo {
+        surname: "hawking",
+        name: "Stephen Hawking",
+        first_name: Some("stephen"),
+        description: "Stephen Hawking pioneered the field of cosmology by combining general relativity and quantum mechanics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Stephen_Hawking"),
+    },
+    PersonInfo {
+        surname: "hellman",
+        name: "Martin Edward Hellman",
+        first_name: Some("martin"),
+        description: "American cryptologist, best known for his invention of public-key cryptography in co-operation with Whitfield Diffie and Ralph Merkle.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Martin_Hellman"),
+    },
+    PersonInfo {
+        surname: "heisenberg",
+        name: "Werner Heisenberg",
+        first_name: Some("werner"),
+        description: "Werner Heisenberg was a founding father of quantum mechanics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Werner_Heisenberg"),
+    },
+    PersonInfo {
+        surname: "hermann",
+        name: "Grete Hermann",
+        first_name: Some("grete"),
+        description: "Grete Hermann was a German philosopher noted for her philosophical work on the foundations of quantum mechanics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Grete_Hermann"),
+    },
+    PersonInfo {
+        surname: "herschel",
+        name: "Caroline Lucretia Herschel",
+        first_name: Some("caroline"),
+        description: "German astronomer and discoverer of several comets.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Caroline_Herschel"),
+    },
+    PersonInfo {
+        surname: "hertz",
+        name: "Heinrich Rudolf Hertz",
+        first_name: Some("heinrich"),
+        description: "German physicist who first conclusively proved the existence of the electromagnetic waves.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Heinrich_Hertz"),
+    },
+    PersonInfo {
+        surname: "heyrovsky",
+        name: "Jaroslav Heyrovský",
+        first_name: Some("jaroslav"),
+        description: "Jaroslav Heyrovský was the inventor of the polarographic method, father of the electroanalytical method, and recipient of the Nobel Prize in 1959. His main field of work was polarography.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Jaroslav_Heyrovsk%C3%BD"),
+    },
+    PersonInfo {
+        surname: "hodgkin",
+        name: "Dorothy Hodgkin",
+        first_name: Some("dorothy"),
+        description: "Dorothy Hodgkin was a British biochemist, credited with the development of protein crystallography. She was awarded the Nobel Prize in Chemistry in 1964.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Dorothy_Hodgkin"),
+    },
+    PersonInfo {
+        surname: "hofstadter",
+        name: "Douglas R. Hofstadter",
+        first_name: Some("douglas"),
+        description: "Douglas R. Hofstadter is an American professor of cognitive science and author of the Pulitzer Prize and American Book Award-winning work Goedel, Escher, Bach: An Eternal Golden Braid in 1979. A mind-bending work which coined Hofstadter's Law: \"It always takes longer than you expect, even when you take into account Hofstadter's Law.\"",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Douglas_Hofstadter"),
+    },
+    PersonInfo {
+        surname: "hoover",
+        name: "Erna Schneider Hoover",
+        first_name: Some("erna"),
+        description: "Erna Schneider Hoover revolutionized modern communication by inventing a computerized telephone switching method.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Erna_Schneider_Hoover"),
+    },
+    PersonInfo {
+        surname: "hopper",
+        name: "Grace Hopper",
+        first_name: Some("grace"),
+        description: "Grace Hopper developed the first compiler for a computer programming language and is credited with popularizing the term \"debugging\" for fixing computer glitches.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Grace_Hopper"),
+    },
+    PersonInfo {
+        surname: "hugle",
+        name: "Frances Hugle",
+        first_name: Some("frances"),
+        description: "Frances Hugle, she was an American scientist, engineer, and inventor who contributed to the understanding of semiconductors, integrated circuitry, and the unique electrical principles of microscopic materials.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Frances_Hugle"),
+    },
+    PersonInfo {
+        surname: "hypatia",
+        name: "Hypatia",
+        first_name: None,
+        description: "Greek Alexandrine Neoplatonist philosopher in Egypt who was one of the earliest mothers of mathematics",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Hypatia"),
+    },
+    PersonInfo {
+        surname: "ishizaka",
+        name: "Teruko Ishizaka",
+        first_name: Some("teruko"),
+        description: "Japanese scientist and immunologist who co-discovered the antibody class Immunoglobulin E.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Teruko_Ishizaka"),
+    },
+    PersonInfo {
+        surname: "jackson",
+        name: "Mary Jackson",
+        first_name: Some("mary"),
+        description: "Mary Jackson, American mathematician and aerospace engineer who earned the highest title within NASA's engineering department",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Mary_Jackson_(engineer)"),
+    },
+    PersonInfo {
+        surname: "jang",
+        name: "Yeong-Sil Jang",
+        first_name: Some("yeong-sil"),
+        description: "Yeong-Sil Jang was a Korean scientist and astronomer during the Joseon Dynasty; he invented the first metal printing press and water gauge.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Jang_Yeong-sil"),
+    },
+    PersonInfo {
+        surname: "jemison",
+        name: "Mae Carol Jemison",
+        first_name: Some("mae"),
+        description: "is an American engineer, physician, and former NASA astronaut. She became the first black woman to travel in space when she served as a mission specialist aboard the Space Shuttle Endeavour",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Mae_Jemison"),
+    },
+    PersonInfo {
+        surname: "jennings",
+        name: "Betty Jennings",
+        first_name: Some("betty"),
+        description: "one of the original programmers of the ENIAC.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Jean_Bartik"),
+    },
+    PersonInfo {
+        surname: "jepsen",
+        name: "Mary Lou Jepsen",
+        first_name: Some("mary"),
+        description: "Mary Lou Jepsen, was the founder and chief technology officer of One Laptop Per Child (OLPC), and the founder of Pixel Qi.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Mary_Lou_Jepsen"),
+    },
+    PersonInfo {
+        surname: "johnson",
+        name: "Katherine Coleman Goble Johnson",
+        first_name: Some("katherine"),
+        description: "American physicist and mathematician contributed to the NASA.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Katherine_Johnson"),
+    },
+    PersonInfo {
+        surname: "joliot",
+        name: "Irène Joliot-Curie",
+        first_name: Some("irene"),
+        description: "French scientist who was awarded the Nobel Prize for Chemistry in 1935. Daughter of Marie and Pierre Curie.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Ir%C3%A8ne_Joliot-Curie"),
+    },
+    PersonInfo {
+        surname: "jones",
+        name: "Karen Spärck Jones",
+        first_name: Some("karen"),
+        description: "Karen Spärck Jones came up with the concept of inverse document frequency, which is used in most search engines today.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Karen_Sp%C3%A4rck_Jones"),
+    },
+    PersonInfo {
+        surname: "kalam",
+        name: "A. P. J. Abdul Kalam",
+        first_name: Some("abdul"),
+        description: "is an Indian scientist aka Missile Man of India for his work on the development of ballistic missile and launch vehicle technology",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/A._P._J._Abdul_Kalam"),
+    },
+    PersonInfo {
+        surname: "kapitsa",
+        name: "Sergey Petrovich Kapitsa",
+        first_name: Some("sergey"),
+        description: "Sergey Petrovich Kapitsa (Russian: Серге́й Петро́вич Капи́ца; 14 February 1928 – 14 August 2012) was a Russian physicist and demographer. He was best known as host of the popular and long-running Russian scientific TV show, Evident, but Incredible. His father was the Nobel laureate Soviet-era physicist Pyotr Kapitsa, and his brother was the geographer and Antarctic explorer Andrey Kapitsa.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Sergey_Kapitsa"),
+    },
+    PersonInfo {
+        surname: "kare",
+        name: "Susan Kare",
+        first_name: Some("susan"),
+        description: "Susan Kare, created the icons and many of the interface elements for the original Apple Macintosh in the 1980s, and was an original employee of NeXT, working as the Creative Director.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Susan_Kare"),
+    },
+    PersonInfo {
+        surname: "keldysh",
+        name: "Mstislav Keldysh",
+        first_name: Some("mstislav"),
+        description: "a Soviet scientist in the field of mathematics and mechanics, academician of the USSR Academy of Sciences (1946), President of the USSR Academy of Sciences (1961–1975), three times Hero of Socialist Labor (1956, 1961, 1971), fellow of the Royal Society of Edinburgh (1968).",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Mstislav_Keldysh"),
+    },
+    PersonInfo {
+        surname: "keller",
+        name: "Mary Kenneth Keller",
+        first_name: Some("mary"),
+        description: "Mary Kenneth Keller, Sister Mary Kenneth Keller became the first American woman to earn a PhD in Computer Science in 1965.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Mary_Kenneth_Keller"),
+    },
+    PersonInfo {
+        surname: "kepler",
+        name: "Johannes Kepler",
+        first_name: Some("johannes"),
+        description: "Johannes Kepler, German astronomer known for his three laws of planetary motion",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Johannes_Kepler"),
+    },
+    PersonInfo {
+        surname: "khayyam",
+        name: "Omar Khayyam",
+        first_name: Some("omar"),
+        description: "Persian mathematician, astronomer and poet. Known for his work on the classification and solution of cubic equations, for his contribution to the understanding of Euclid's fifth postulate and for computing the length of a year very accurately.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Omar_Khayyam"),
+    },
+    PersonInfo {
+        surname: "khorana",
+        name: "Har Gobind Khorana",
+        first_name: Some("har"),
+        description: "Indian-American biochemist who shared the 1968 Nobel Prize for Physiology",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Har_Gobind_Khorana"),
+    },
+    PersonInfo {
+        surname: "kilby",
+        name: "Jack Kilby",
+        first_name: Some("jack"),
+        description: "Jack Kilby invented silicon integrated circuits and gave Silicon Valley its name.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Jack_Kilby"),
+    },
+    PersonInfo {
+        surname: "kirch",
+        name: "Maria Kirch",
+        first_name: Some("maria"),
+        description: "German astronomer and first woman to discover a comet",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Maria_Margarethe_Kirch"),
+    },
+    PersonInfo {
+        surname: "knuth",
+        name: "Donald Knuth",
+        first_name: Some("donald"),
+        description: "American computer scientist, author of \"The Art of Computer Programming\" and creator of the TeX typesetting system.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Donald_Knuth"),
+    },
+    PersonInfo {
+        surname: "kowalevski",
+        name: "Sophie Kowalevski",
+        first_name: Some("sophie"),
+        description: "Russian mathematician responsible for important original contributions to analysis, differential equations and mechanics",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Sofia_Kovalevskaya"),
+    },
+    PersonInfo {
+        surname: "lalande",
+        name: "Marie-Jeanne de Lalande",
+        first_name: Some("marie-jeanne"),
+        description: "French astronomer, mathematician and cataloguer of stars",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Marie-Jeanne_de_Lalande"),
+    },
+    PersonInfo {
+        surname: "lamarr",
+        name: "Hedy Lamarr",
+        first_name: Some("hedy"),
+        description: "Actress and inventor. The principles of her work are now incorporated into modern Wi-Fi, CDMA and Bluetooth technology.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Hedy_Lamarr"),
+    },
+    PersonInfo {
+        surname: "lamport",
+        name: "Leslie B. Lamport",
+        first_name: Some("leslie"),
+        description: "American computer scientist. Lamport is best known for his seminal work in distributed systems and was the winner of the 2013 Turing Award.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Leslie_Lamport"),
+    },
+    PersonInfo {
+        surname: "leakey",
+        name: "Mary Leakey",
+        first_name: Some("mary"),
+        description: "British paleoanthropologist who discovered the first fossilized Proconsul skull",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Mary_Leakey"),
+    },
+    PersonInfo {
+        surname: "leavitt",
+        name: "Henrietta Swan Leavitt",
+        first_name: Some("henrietta"),
+        description: "she was an American astronomer who discovered the relation between the luminosity and the period of Cepheid variable stars.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Henrietta_Swan_Leavitt"),
+    },
+    PersonInfo {
+        surname: "lederberg",
+        name: "Esther Miriam Zimmer Lederberg",
+        first_name: Some("esther"),
+        description: "American microbiologist and a pioneer of bacterial genetics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Esther_Lederberg"),
+    },
+    PersonInfo {
+        surname: "lehmann",
+        name: "Inge Lehmann",
+        first_name: Some("inge"),
+        description: "Danish seismologist and geophysicist. Known for discovering in 1936 that the Earth has a solid inner core inside a molten outer core.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Inge_Lehmann"),
+    },
+    PersonInfo {
+        surname: "lewin",
+        name: "Daniel Lewin",
+        first_name: Some("daniel"),
+        description: "Mathematician, Akamai co-founder, soldier, 9/11 victim-- Developed optimization techniques for routing traffic on the internet. Died attempting to stop the 9-11 hijackers.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Daniel_Lewin"),
+    },
+    PersonInfo {
+        surname: "lichterman",
+        name: "Ruth Lichterman",
+        first_name: Some("ruth"),
+        description: "one of the original programmers of the ENIAC.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Ruth_Teitelbaum"),
+    },
+    PersonInfo {
+        surname: "liskov",
+        name: "Barbara Liskov",
+        first_name: Some("barbara"),
+        description: "co-developed the Liskov substitution principle. Liskov was also the winner of the Turing Prize in 2008.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Barbara_Liskov"),
+    },
+    PersonInfo {
+        surname: "lovelace",
+        name: "Ada Lovelace",
+        first_name: Some("ada"),
+        description: "Ada Lovelace invented the first algorithm. (thanks James Turnbull)",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Ada_Lovelace"),
+    },
+    PersonInfo {
+        surname: "lumiere",
+        name: "Auguste and Louis Lumière",
+        first_name: Some("auguste"),
+        description: "the first filmmakers in history",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Auguste_and_Louis_Lumi%C3%A8re"),
+    },
+    PersonInfo {
+        surname: "mahavira",
+        name: "Mahavira",
+        first_name: None,
+        description: "Ancient Indian mathematician during 9th century AD who discovered basic algebraic identities",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Mah%C4%81v%C4%ABra_(mathematician)"),
+    },
+    PersonInfo {
+        surname: "margulis",
+        name: "Lynn Margulis",
+        first_name: Some("lynn"),
+        description: "an American evolutionary theorist and biologist, science author, educator, and popularizer, and was the primary modern proponent for the significance of symbiosis in evolution.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Lynn_Margulis"),
+    },
+    PersonInfo {
+        surname: "matsumoto",
+        name: "Yukihiro Matsumoto",
+        first_name: Some("yukihiro"),
+        description: "Japanese computer scientist and software programmer best known as the chief designer of the Ruby programming language.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Yukihiro_Matsumoto"),
+    },
+    PersonInfo {
+        surname: "maxwell",
+        name: "James Clerk Maxwell",
+        first_name: Some("james"),
+        description: "Scottish physicist, best known for his formulation of electromagnetic theory.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/James_Clerk_Maxwell"),
+    },
+    PersonInfo {
+        surname: "mayer",
+        name: "Maria Mayer",
+        first_name: Some("maria"),
+        description: "American theoretical physicist and Nobel laureate in Physics for proposing the nuclear shell model of the atomic nucleus",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Maria_Mayer"),
+    },
+    PersonInfo {
+        surname: "mccarthy",
+        name: "John McCarthy",
+        first_name: Some("john"),
+        description: "John McCarthy invented LISP:",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/John_McCarthy_(computer_scientist)"),
+    },
+    PersonInfo {
+        surname: "mcclintock",
+        name: "Barbara McClintock",
+        first_name: Some("barbara"),
+        description: "a distinguished American cytogeneticist, 1983 Nobel Laureate in Physiology or Medicine for discovering transposons.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Barbara_McClintock"),
+    },
+    PersonInfo {
+        surname: "mclaren",
+        name: "Anne Laura Dorinthea McLaren",
+        first_name: Some("anne"),
+        description: "British developmental biologist whose work helped lead to human in-vitro fertilisation.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Anne_McLaren"),
+    },
+    PersonInfo {
+        surname: "mclean",
+        name: "Malcolm McLean",
+        first_name: Some("malcolm"),
+        description: "Malcolm McLean invented the modern shipping container:",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Malcom_McLean"),
+    },
+    PersonInfo {
+        surname: "mcnulty",
+        name: "Kay McNulty",
+        first_name: Some("kay"),
+        description: "one of the original programmers of the ENIAC.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Kathleen_Antonelli"),
+    },
+    PersonInfo {
+        surname: "mendel",
+        name: "Gregor Johann Mendel",
+        first_name: Some("gregor"),
+        description: "Czech scientist and founder of genetics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Gregor_Mendel"),
+    },
+    PersonInfo {
+        surname: "mendeleev",
+        name: "Dmitri Mendeleev",
+        first_name: Some("dmitri"),
+        description: "a chemist and inventor. He formulated the Periodic Law, created a farsighted version of the periodic table of elements, and used it to correct the properties of some already discovered elements and also to predict the properties of eight elements yet to be discovered.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Dmitri_Mendeleev"),
+    },
+    PersonInfo {
+        surname: "meitner",
+        name: "Lise Meitner",
+        first_name: Some("lise"),
+        description: "Austrian/Swedish physicist who was involved in the discovery of nuclear fission. The element meitnerium is named after her",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Lise_Meitner"),
+    },
+    PersonInfo {
+        surname: "meninsky",
+        name: "Carla Meninsky",
+        first_name: Some("carla"),
+        description: "Carla Meninsky, was the game designer and programmer for Atari 2600 games Dodge 'Em and Warlords.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Carla_Meninsky"),
+    },
+    PersonInfo {
+        surname: "merkle",
+        name: "Ralph C. Merkle",
+        first_name: Some("ralph"),
+        description: "American computer scientist, known for devising Merkle's puzzles - one of the very first schemes for public-key cryptography. Also, inventor of Merkle trees and co-inventor of the Merkle-Damgård construction for building collision-resistant cryptographic hash functions and the Merkle-Hellman knapsack cryptosystem.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Ralph_Merkle"),
+    },
+    PersonInfo {
+        surname: "mestorf",
+        name: "Johanna Mestorf",
+        first_name: Some("johanna"),
+        description: "German prehistoric archaeologist and first female museum director in Germany",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Johanna_Mestorf"),
+    },
+    PersonInfo {
+        surname: "mirzakhani",
+        name: "Maryam Mirzakhani",
+        first_name: Some("maryam"),
+        description: "an Iranian mathematician and the first woman to win the Fields Medal.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Maryam_Mirzakhani"),
+    },
+    PersonInfo {
+        surname: "montalcini",
+        name: "Rita Levi-Montalcini",
+        first_name: Some("rita"),
+        description: "Won Nobel Prize in Physiology or Medicine jointly with colleague Stanley Cohen for the discovery of nerve growth factor (",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Rita_Levi-Montalcini)"),
+    },
+    PersonInfo {
+        surname: "moore",
+        name: "Gordon Earle Moore",
+        first_name: Some("gordon"),
+        description: "American engineer, Silicon Valley founding father, author of Moore's law.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Gordon_Moore"),
+    },
+    PersonInfo {
+        surname: "morse",
+        name: "Samuel Morse",
+        first_name: Some("samuel"),
+        description: "contributed to the invention of a single-wire telegraph system based on European telegraphs and was a co-developer of the Morse code",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Samuel_Morse"),
+    },
+    PersonInfo {
+        surname: "murdock",
+        name: "Ian Murdock",
+        first_name: Some("ian"),
+        description: "founder of the Debian project",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Ian_Murdock"),
+    },
+    PersonInfo {
+        surname: "moser",
+        name: "May-Britt Moser",
+        first_name: Some("may-britt"),
+        description: "Nobel prize winner neuroscientist who contributed to the discovery of grid cells in the brain.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/May-Britt_Moser"),
+    },
+    PersonInfo {
+        surname: "napier",
+        name: "John Napier",
+        first_name: Some("john"),
+        description: "Scottish landowner known as an astronomer, mathematician and physicist. Best known for his discovery of logarithms.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/John_Napier"),
+    },
+    PersonInfo {
+        surname: "nash",
+        name: "John Forbes Nash",
+        first_name: Some("john"),
+        description: "John Forbes Nash, Jr. - American mathematician who made fundamental contributions to game theory, differential geometry, and the study of partial differential equations.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/John_Forbes_Nash_Jr"),
+    },
+    PersonInfo {
+        surname: "neumann",
+        name: "John von Neumann",
+        first_name: Some("john"),
+        description: "todays computer architectures are based on the von Neumann architecture.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Von_Neumann_architecture"),
+    },
+    PersonInfo {
+        surname: "newton",
+        name: "Isaac Newton",
+        first_name: Some("isaac"),
+        description: "Isaac Newton invented classic mechanics and modern optics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Isaac_Newton"),
+    },
+    PersonInfo {
+        surname: "nightingale",
+        name: "Florence Nightingale",
+        first_name: Some("florence"),
+        description: "Florence Nightingale, more prominently known as a nurse, was also the first female member of the Royal Statistical Society and a pioneer in statistical graphics",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Florence_Nightingale#Statistics_and_sanitary_reform"),
+    },
+    PersonInfo {
+        surname: "nobel",
+        name: "Alfred Nobel",
+        first_name: Some("alfred"),
+        description: "a Swedish chemist, engineer, innovator, and armaments manufacturer (inventor of dynamite)",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Alfred_Nobel"),
+    },
+    PersonInfo {
+        surname: "noether",
+        name: "Emmy Noether",
+        first_name: Some("emmy"),
+        description: "Emmy Noether, German mathematician. Noether's Theorem is named after her.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Emmy_Noether"),
+    },
+    PersonInfo {
+        surname: "northcutt",
+        name: "Poppy Northcutt",
+        first_name: Some("poppy"),
+        description: "Poppy Northcutt was the first woman to work as part of NASA’s Mission Control.",
+        wikipedia_url: None,
+    },
+    PersonInfo {
+        surname: "noyce",
+        name: "Robert Noyce",
+        first_name: Some("robert"),
+        description: "Robert Noyce invented silicon integrated circuits and gave Silicon Valley its name.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Robert_Noyce"),
+    },
+    PersonInfo {
+        surname: "panini",
+        name: "Panini",
+        first_name: None,
+        description: "Ancient Indian linguist and grammarian from 4th century CE who worked on the world's first formal system",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/P%C4%81%E1%B9%87ini#Comparison_with_modern_formal_systems"),
+    },
+    PersonInfo {
+        surname: "pare",
+        name: "Ambroise Pare",
+        first_name: Some("ambroise"),
+        description: "Ambroise Pare invented modern surgery.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Ambroise_Par%C3%A9"),
+    },
+    PersonInfo {
+        surname: "pascal",
+        name: "Blaise Pascal",
+        first_name: Some("blaise"),
+        description: "Blaise Pascal, French mathematician, physicist, and inventor",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Blaise_Pascal"),
+    },
+    PersonInfo {
+        surname: "pasteur",
+        name: "Louis Pasteur",
+        first_name: Some("louis"),
+        description: "Louis Pasteur discovered vaccination, fermentation and pasteurization.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Louis_Pasteur"),
+    },
+    PersonInfo {
+        surname: "payne",
+        name: "Cecilia Payne-Gaposchkin",
+        first_name: Some("cecilia"),
+        description: "Cecilia Payne-Gaposchkin was an astronomer and astrophysicist who, in 1925, proposed in her Ph.D. thesis an explanation for the composition of stars in terms of the relative abundances of hydrogen and helium.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Cecilia_Payne-Gaposchkin"),
+    },
+    PersonInfo {
+        surname: "perlman",
+        name: "Radia Perlman",
+        first_name: Some("radia"),
+        description: "Radia Perlman is a software designer and network engineer and most famous for her invention of the spanning-tree protocol (STP).",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Radia_Perlman"),
+    },
+    PersonInfo {
+        surname: "pike",
+        name: "Rob Pike",
+        first_name: Some("rob"),
+        description: "Rob Pike was a key contributor to Unix, Plan 9, the X graphic system, utf-8, and the Go programming language.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Rob_Pike"),
+    },
+    PersonInfo {
+        surname: "poincare",
+        name: "Henri Poincaré",
+        first_name: Some("henri"),
+        description: "Henri Poincaré made fundamental contributions in several fields of mathematics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Henri_Poincar%C3%A9"),
+    },
+    PersonInfo {
+        surname: "poitras",
+        name: "Laura Poitras",
+        first_name: Some("laura"),
+        description: "Laura Poitras is a director and producer whose work, made possible by open source crypto tools, advances the causes of truth and freedom of information by reporting disclosures by whistleblowers such as Edward Snowden.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Laura_Poitras"),
+    },
+    PersonInfo {
+        surname: "proskuriakova",
+        name: "Tat’yana Avenirovna Proskuriakova",
+        first_name: Some("tatyana"),
+        description: "Tat’yana Avenirovna Proskuriakova (Russian: Татья́на Авени́ровна Проскуряко́ва) (January 23 [O.S. January 10] 1909 – August 30, 1985) was a Russian-American Mayanist scholar and archaeologist who contributed significantly to the deciphering of Maya hieroglyphs, the writing system of the pre-Columbian Maya civilization of Mesoamerica.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Tatiana_Proskouriakoff"),
+    },
+    PersonInfo {
+        surname: "ptolemy",
+        name: "Claudius Ptolemy",
+        first_name: Some("claudius"),
+        description: "a Greco-Egyptian writer of Alexandria, known as a mathematician, astronomer, geographer, astrologer, and poet of a single epigram in the Greek Anthology",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Ptolemy"),
+    },
+    PersonInfo {
+        surname: "raman",
+        name: "C. V. Raman",
+        first_name: None,
+        description: "Indian physicist who won the Nobel Prize in 1930 for proposing the Raman effect.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/C._V._Raman"),
+    },
+    PersonInfo {
+        surname: "ramanujan",
+        name: "Srinivasa Ramanujan",
+        first_name: Some("srinivasa"),
+        description: "Indian mathematician and autodidact who made extraordinary contributions to mathematical analysis, number theory, infinite series, and continued fractions.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Srinivasa_Ramanujan"),
+    },
+    PersonInfo {
+        surname: "ride",
+        name: "Sally Kristen Ride",
+        first_name: Some("sally"),
+        description: "Sally Kristen Ride was an American physicist and astronaut. She was the first American woman in space, and the youngest American astronaut.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Sally_Ride"),
+    },
+    PersonInfo {
+        surname: "ritchie",
+        name: "Dennis Ritchie",
+        first_name: Some("dennis"),
+        description: "co-creator of UNIX and the C programming language.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Dennis_Ritchie"),
+    },
+    PersonInfo {
+        surname: "rhodes",
+        name: "Ida Rhodes",
+        first_name: Some("ida"),
+        description: "American pioneer in computer programming, designed the first computer used for Social Security.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Ida_Rhodes"),
+    },
+    PersonInfo {
+        surname: "robinson",
+        name: "Julia Hall Bowman Robinson",
+        first_name: Some("julia"),
+        description: "American mathematician renowned for her contributions to the fields of computability theory and computational complexity theory.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Julia_Robinson"),
+    },
+    PersonInfo {
+        surname: "roentgen",
+        name: "Wilhelm Conrad Röntgen",
+        first_name: Some("wilhelm"),
+        description: "German physicist who was awarded the first Nobel Prize in Physics in 1901 for the discovery of X-rays (Röntgen rays).",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Wilhelm_R%C3%B6ntgen"),
+    },
+    PersonInfo {
+        surname: "rosalind",
+        name: "Rosalind Franklin",
+        first_name: None,
+        description: "British biophysicist and X-ray crystallographer whose research was critical to the understanding of DNA",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Rosalind_Franklin"),
+    },
+    PersonInfo {
+        surname: "rubin",
+        name: "Vera Rubin",
+        first_name: Some("vera"),
+        description: "American astronomer who pioneered work on galaxy rotation rates.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Vera_Rubin"),
+    },
+    PersonInfo {
+        surname: "saha",
+        name: "Meghnad Saha",
+        first_name: Some("meghnad"),
+        description: "Indian astrophysicist best known for his development of the Saha equation, used to describe chemical and physical conditions in stars",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Meghnad_Saha"),
+    },
+    PersonInfo {
+        surname: "sammet",
+        name: "Jean E. Sammet",
+        first_name: Some("jean"),
+        description: "Jean E. Sammet developed FORMAC, the first widely used computer language for symbolic manipulation of mathematical formulas.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Jean_E._Sammet"),
+    },
+    PersonInfo {
+        surname: "sanderson",
+        name: "Mildred Sanderson",
+        first_name: Some("mildred"),
+        description: "American mathematician best known for Sanderson's theorem concerning modular invariants.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Mildred_Sanderson"),
+    },
+    PersonInfo {
+        surname: "satoshi",
+        name: "Satoshi Nakamoto",
+        first_name: None,
+        description: "Satoshi Nakamoto is the name used by the unknown person or group of people who developed bitcoin, authored the bitcoin white paper, and created and deployed bitcoin's original reference implementation.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Satoshi_Nakamoto"),
+    },
+    PersonInfo {
+        surname: "shamir",
+        name: "Adi Shamir",
+        first_name: Some("adi"),
+        description: "Israeli cryptographer whose numerous inventions and contributions to cryptography include the Ferge Fiat Shamir identification scheme, the Rivest Shamir Adleman (RSA) public-key cryptosystem, the Shamir's secret sharing scheme, the breaking of the Merkle-Hellman cryptosystem, the TWINKLE and TWIRL factoring devices and the discovery of differential cryptanalysis (with Eli Biham).",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Adi_Shamir"),
+    },
+    PersonInfo {
+        surname: "shannon",
+        name: "Claude Shannon",
+        first_name: Some("claude"),
+        description: "The father of information theory and founder of digital circuit design theory. (",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Claude_Shannon)"),
+    },
+    PersonInfo {
+        surname: "shaw",
+        name: "Carol Shaw",
+        first_name: Some("carol"),
+        description: "Originally an Atari employee, Carol Shaw is said to be the first female video game designer.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Carol_Shaw_(video_game_designer)"),
+    },
+    PersonInfo {
+        surname: "shirley",
+        name: "Dame Stephanie \"Steve\" Shirley",
+        first_name: Some("stephanie"),
+        description: "Founded a software company in 1962 employing women working from home.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Steve_Shirley"),
+    },
+    PersonInfo {
+        surname: "shockley",
+        name: "William Shockley",
+        first_name: Some("william"),
+        description: "William Shockley co-invented the transistor",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/William_Shockley"),
+    },
+    PersonInfo {
+        surname: "shtern",
+        name: "Lina Solomonovna Stern",
+        first_name: Some("lina"),
+        description: "Lina Solomonovna Stern (or Shtern; Russian: Лина Соломоновна Штерн; 26 August 1878 – 7 March 1968) was a Soviet biochemist, physiologist and humanist whose medical discoveries saved thousands of lives at the fronts of World War II. She is best known for her pioneering work on blood–brain barrier, which she described as hemato-encephalic barrier in 1921.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Lina_Stern"),
+    },
+    PersonInfo {
+        surname: "sinoussi",
+        name: "Françoise Barré-Sinoussi",
+        first_name: Some("francoise"),
+        description: "French virologist and Nobel Prize Laureate in Physiology or Medicine; her work was fundamental in identifying HIV as the cause of AIDS.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Fran%C3%A7oise_Barr%C3%A9-Sinoussi"),
+    },
+    PersonInfo {
+        surname: "snyder",
+        name: "Betty Snyder",
+        first_name: Some("betty"),
+        description: "one of the original programmers of the ENIAC.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Betty_Holberton"),
+    },
+    PersonInfo {
+        surname: "solomon",
+        name: "Cynthia Solomon",
+        first_name: Some("cynthia"),
+        description: "Pioneer in the fields of artificial intelligence, computer science and educational computing. Known for creation of Logo, an educational programming language.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Cynthia_Solomon"),
+    },
+    PersonInfo {
+        surname: "spence",
+        name: "Frances Spence",
+        first_name: Some("frances"),
+        description: "one of the original programmers of the ENIAC.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Frances_Spence"),
+    },
+    PersonInfo {
+        surname: "stonebraker",
+        name: "Michael Stonebraker",
+        first_name: Some("michael"),
+        description: "Michael Stonebraker is a database research pioneer and architect of Ingres, Postgres, VoltDB and SciDB. Winner of 2014 ACM Turing Award.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Michael_Stonebraker"),
+    },
+    PersonInfo {
+        surname: "sutherland",
+        name: "Ivan Edward Sutherland",
+        first_name: Some("ivan"),
+        description: "American computer scientist and Internet pioneer, widely regarded as the father of computer graphics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Ivan_Sutherland"),
+    },
+    PersonInfo {
+        surname: "swanson",
+        name: "Janese Swanson",
+        first_name: Some("janese"),
+        description: "Janese Swanson (with others) developed the first of the Carmen Sandiego games. She went on to found Girl Tech.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Janese_Swanson"),
+    },
+    PersonInfo {
+        surname: "swartz",
+        name: "Aaron Swartz",
+        first_name: Some("aaron"),
+        description: "Aaron Swartz was influential in creating RSS, Markdown, Creative Commons, Reddit, and much of the internet as we know it today. He was devoted to freedom of information on the web.",
+        wikipedia_url: None,
+    },
+    PersonInfo {
+        surname: "swirles",
+        name: "Bertha Swirles",
+        first_name: Some("bertha"),
+        description: "Bertha Swirles was a theoretical physicist who made a number of contributions to early quantum theory.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Bertha_Swirles"),
+    },
+    PersonInfo {
+        surname: "taussig",
+        name: "Helen Brooke Taussig",
+        first_name: Some("helen"),
+        description: "American cardiologist and founder of the field of paediatric cardiology.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Helen_B._Taussig"),
+    },
+    PersonInfo {
+        surname: "tereshkova",
+        name: "Valentina Tereshkova",
+        first_name: Some("valentina"),
+        description: "Valentina Tereshkova is a Russian engineer, cosmonaut and politician. She was the first woman to fly to space in 1963. In 2013, at the age of 76, she offered to go on a one-way mission to Mars.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Valentina_Tereshkova"),
+    },
+    PersonInfo {
+        surname: "tesla",
+        name: "Nikola Tesla",
+        first_name: Some("nikola"),
+        description: "Nikola Tesla invented the AC electric system and every gadget ever used by a James Bond villain.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Nikola_Tesla"),
+    },
+    PersonInfo {
+        surname: "tharp",
+        name: "Marie Tharp",
+        first_name: Some("marie"),
+        description: "American geologist and oceanic cartographer who co-created the first scientific map of the Atlantic Ocean floor. Her work led to the acceptance of the theories of plate tectonics and continental drift.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Marie_Tharp"),
+    },
+    PersonInfo {
+        surname: "thompson",
+        name: "Ken Thompson",
+        first_name: Some("ken"),
+        description: "co-creator of UNIX and the C programming language",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Ken_Thompson"),
+    },
+    PersonInfo {
+        surname: "torvalds",
+        name: "Linus Torvalds",
+        first_name: Some("linus"),
+        description: "Linus Torvalds invented Linux and Git.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Linus_Torvalds"),
+    },
+    PersonInfo {
+        surname: "tu",
+        name: "Youyou Tu",
+        first_name: Some("youyou"),
+        description: "Chinese pharmaceutical chemist and educator known for discovering artemisinin and dihydroartemisinin, used to treat malaria, which has saved millions of lives. Joint winner of the 2015 Nobel Prize in Physiology or Medicine.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Tu_Youyou"),
+    },
+    PersonInfo {
+        surname: "turing",
+        name: "Alan Turing",
+        first_name: Some("alan"),
+        description: "Alan Turing was a founding father of computer science.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Alan_Turing"),
+    },
+    PersonInfo {
+        surname: "varahamihira",
+        name: "Varahamihira",
+        first_name: None,
+        description: "Ancient Indian mathematician who discovered trigonometric formulae during 505-587 CE",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Var%C4%81hamihira#Contributions"),
+    },
+    PersonInfo {
+        surname: "vaughan",
+        name: "Dorothy Vaughan",
+        first_name: Some("dorothy"),
+        description: "Dorothy Vaughan was a NASA mathematician and computer programmer on the SCOUT launch vehicle program that put America's first satellites into space",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Dorothy_Vaughan"),
+    },
+    PersonInfo {
+        surname: "villani",
+        name: "Cédric Villani",
+        first_name: Some("cedric"),
+        description: "French mathematician, won Fields Medal, Fermat Prize and Poincaré Price for his work in differential geometry and statistical mechanics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/C%C3%A9dric_Villani"),
+    },
+    PersonInfo {
+        surname: "visvesvaraya",
+        name: "Sir Mokshagundam Visvesvaraya",
+        first_name: Some("mokshagundam"),
+        description: "is a notable Indian engineer. He is a recipient of the Indian Republic's highest honour, the Bharat Ratna, in 1955. On his birthday, 15 September is celebrated as Engineer's Day in India in his memory",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Visvesvaraya"),
+    },
+    PersonInfo {
+        surname: "volhard",
+        name: "Christiane Nüsslein-Volhard",
+        first_name: Some("christiane"),
+        description: "German biologist, won Nobel Prize in Physiology or Medicine in 1995 for research on the genetic control of embryonic development.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Christiane_N%C3%BCsslein-Volhard"),
+    },
+    PersonInfo {
+        surname: "wescoff",
+        name: "Marlyn Wescoff",
+        first_name: Some("marlyn"),
+        description: "one of the original programmers of the ENIAC.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Marlyn_Meltzer"),
+    },
+    PersonInfo {
+        surname: "wilbur",
+        name: "Sylvia B. Wilbur",
+        first_name: Some("sylvia"),
+        description: "British computer scientist who helped develop the ARPANET, was one of the first to exchange email in the UK and a leading researcher in computer-supported collaborative work.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Sylvia_Wilbur"),
+    },
+    PersonInfo {
+        surname: "wiles",
+        name: "Andrew Wiles",
+        first_name: Some("andrew"),
+        description: "Notable British mathematician who proved the enigmatic Fermat's Last Theorem",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Andrew_Wiles"),
+    },
+    PersonInfo {
+        surname: "williams",
+        name: "Roberta Williams",
+        first_name: Some("roberta"),
+        description: "Roberta Williams, did pioneering work in graphical adventure games for personal computers, particularly the King's Quest series.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Roberta_Williams"),
+    },
+    PersonInfo {
+        surname: "williamson",
+        name: "Malcolm John Williamson",
+        first_name: Some("malcolm"),
+        description: "British mathematician and cryptographer employed by the GCHQ. Developed in 1974 what is now known as Diffie-Hellman key exchange (Diffie and Hellman first published the scheme in 1976).",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Malcolm_J._Williamson"),
+    },
+    PersonInfo {
+        surname: "wilson",
+        name: "Sophie Wilson",
+        first_name: Some("sophie"),
+        description: "Sophie Wilson designed the first Acorn Micro-Computer and the instruction set for ARM processors.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Sophie_Wilson"),
+    },
+    PersonInfo {
+        surname: "wing",
+        name: "Jeannette Wing",
+        first_name: Some("jeannette"),
+        description: "co-developed the Liskov substitution principle.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Jeannette_Wing"),
+    },
+    PersonInfo {
+        surname: "wozniak",
+        name: "Steve Wozniak",
+        first_name: Some("steve"),
+        description: "Steve Wozniak invented the Apple I and Apple II.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Steve_Wozniak"),
+    },
+    PersonInfo {
+        surname: "wright",
+        name: "Orville and Wilbur Wright",
+        first_name: None,
+        description: "credited with inventing and building the world's first successful airplane and making the first controlled, powered and sustained heavier-than-air human flight",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Wright_brothers"),
+    },
+    PersonInfo {
+        surname: "wu",
+        name: "Chien-Shiung Wu",
+        first_name: Some("chien-shiung"),
+        description: "Chinese-American experimental physicist who made significant contributions to nuclear physics.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Chien-Shiung_Wu"),
+    },
+    PersonInfo {
+        surname: "yalow",
+        name: "Rosalyn Sussman Yalow",
+        first_name: Some("rosalyn"),
+        description: "Rosalyn Sussman Yalow was an American medical physicist, and a co-winner of the 1977 Nobel Prize in Physiology or Medicine for development of the radioimmunoassay technique.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Rosalyn_Sussman_Yalow"),
+    },
+    PersonInfo {
+        surname: "yonath",
+        name: "Ada Yonath",
+        first_name: Some("ada"),
+        description: "an Israeli crystallographer, the first woman from the Middle East to win a Nobel prize in the sciences.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Ada_Yonath"),
+    },
+    PersonInfo {
+        surname: "zhukovsky",
+        name: "Nikolay Yegorovich Zhukovsky",
+        first_name: Some("nikolay"),
+        description: "Nikolay Yegorovich Zhukovsky (Russian: Никола́й Его́рович Жуко́вский, January 17 1847 – March 17, 1921) was a Russian scientist, mathematician and engineer, and a founding father of modern aero- and hydrodynamics. Whereas contemporary scientists scoffed at the idea of human flight, Zhukovsky was the first to undertake the study of airflow. He is often called the Father of Russian Aviation.",
+        wikipedia_url: Some("https://en.wikipedia.org/wiki/Nikolay_Yegorovich_Zhukovsky"),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::RIGHT_WORDS;
+
+    #[test]
+    fn covers_every_default_right_word() {
+        let surnames: Vec<&str> = PEOPLE.iter().map(|person| person.surname).collect();
+        assert_eq!(surnames, RIGHT_WORDS);
+    }
+
+    #[test]
+    fn looks_up_case_insensitively() {
+        assert_eq!(scientist_info("Lovelace").unwrap().name, "Ada Lovelace");
+        assert_eq!(scientist_info("golick").unwrap().wikipedia_url, None);
+        assert_eq!(scientist_info("nobody"), None);
+    }
+}
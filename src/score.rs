@@ -0,0 +1,128 @@
+use crate::syllables::estimate_syllables;
+use crate::{Mnemonic, MnemonicError, MnemonicGenerator, Pick};
+
+/// The most frequent letters of English text, whose share of a name is the
+/// letter-frequency part of the score.
+const COMMON_LETTERS: &str = "etaoinshrdlu";
+
+impl MnemonicGenerator {
+    /// Estimates how easy a mnemonic is to remember, from `0.0` to `1.0`.
+    ///
+    /// The heuristic favors short names with few syllables, a high share of
+    /// the most frequent English letters, and alliteration. It measures letter
+    /// frequency, not word frequency: the word lists carry no usage counts, so
+    /// a rare word spelled with common letters scores as well as a common
+    /// one. The words are scored as they appear in the word lists, without
+    /// prefix, suffix, or checksum. Scores only compare names with each other
+    /// and carry no absolute meaning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::new();
+    /// let catchy = generator.parse("brave_bose").unwrap();
+    /// let clunky = generator.parse("condescending_chandrasekhar").unwrap();
+    /// assert!(generator.score(&catchy) > generator.score(&clunky));
+    /// # }
+    /// ```
+    pub fn score(&self, mnemonic: &Mnemonic) -> f32 {
+        score_words(mnemonic.left(), mnemonic.right())
+    }
+
+    /// Draws `n` candidates and returns the one with the highest
+    /// [`MnemonicGenerator::score`], using the default separator.
+    ///
+    /// Trades speed for quality in user-facing names. At least one candidate
+    /// is always drawn.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate`]
+    pub fn generate_best_of(&self, n: usize) -> Result<String, MnemonicError> {
+        let separator = self.default_separator();
+        let mut rng = crate::rng::default_rng();
+
+        let mut best = self.choose(&mut rng, separator)?;
+        let mut best_score = self.score_pair(best);
+        for _ in 1..n {
+            let candidate = self.choose(&mut rng, separator)?;
+            let score = self.score_pair(candidate);
+            if score > best_score {
+                (best, best_score) = (candidate, score);
+            }
+        }
+
+        let name = self.render(best, separator);
+        self.observe(|observer| observer.on_generated(&name));
+        Ok(name)
+    }
+
+    /// Scores the picked words.
+    fn score_pair(&self, pick: Pick) -> f32 {
+        score_words(
+            self.words.left.get(pick.left),
+            self.words.right.get(pick.right),
+        )
+    }
+}
+
+/// Combines the length, syllable, letter frequency, and alliteration scores.
+fn score_words(left: &str, right: &str) -> f32 {
+    let letters: Vec<char> = left
+        .chars()
+        .chain(right.chars())
+        .flat_map(char::to_lowercase)
+        .filter(|c| c.is_alphabetic())
+        .collect();
+    if letters.is_empty() {
+        return 0.0;
+    }
+
+    let extra_len = letters.len().saturating_sub(8) as f32;
+    let length = 1.0 / (1.0 + 0.15 * extra_len);
+
+    let extra_syllables = (estimate_syllables(left) + estimate_syllables(right)).saturating_sub(3);
+    let syllables = 1.0 / (1.0 + 0.5 * extra_syllables as f32);
+
+    let common = letters
+        .iter()
+        .filter(|&&c| COMMON_LETTERS.contains(c))
+        .count();
+    let letter_frequency = common as f32 / letters.len() as f32;
+
+    let initial = |word: &str| {
+        word.chars()
+            .next()
+            .map(|c| c.to_lowercase().collect::<String>())
+    };
+    let alliteration = if initial(left) == initial(right) {
+        1.0
+    } else {
+        0.0
+    };
+
+    0.35 * length + 0.3 * syllables + 0.2 * letter_frequency + 0.15 * alliteration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefers_short_alliterative_names() {
+        assert!(score_words("bold", "bohr") > score_words("bold", "turing"));
+        assert!(score_words("brave", "bose") > score_words("condescending", "chandrasekhar"));
+        let score = score_words("bold", "bohr");
+        assert!((0.0..=1.0).contains(&score));
+    }
+
+    #[test]
+    fn best_of_picks_the_highest_score() {
+        let generator = MnemonicGenerator::with_words(["bold"], ["bohr", "chandrasekhar"]);
+        assert_eq!(generator.generate_best_of(200).unwrap(), "bold_bohr");
+    }
+}
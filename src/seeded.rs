@@ -0,0 +1,317 @@
+use std::hash::Hasher;
+use std::io::{BufRead, Write};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+
+use crate::named::Fnv1aHasher;
+use crate::{MnemonicError, MnemonicGenerator};
+
+/// A generator that produces a reproducible sequence of names from a seed.
+///
+/// Two seeded generators with the same seed and configuration produce the
+/// same names in the same order, so a seed can be shared instead of the names
+/// themselves. Timestamp words still reflect the current time.
+///
+/// # Stability
+///
+/// The sequence is specified: seeds are expanded with SplitMix64, randomness
+/// comes from ChaCha12 (as implemented by `rand_chacha`), and indices are drawn
+/// with a fixed rejection method independent of rand's distributions. A seed
+/// and generator configuration therefore yield identical names on every
+/// operating system and architecture and across patch releases of this crate.
+/// A change to the sequence is a breaking change, made only in a minor release
+/// while the crate is below 1.0. Custom [`SelectionStrategy`](crate::SelectionStrategy)
+/// implementations are outside this guarantee.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{MnemonicGenerator, SeededGenerator};
+///
+/// let generator = || MnemonicGenerator::with_words(["happy", "brave"], ["turing", "hopper"]);
+/// let mut first = SeededGenerator::from_phrase(generator(), "project-atlantis");
+/// let mut second = SeededGenerator::from_phrase(generator(), "project-atlantis");
+/// for _ in 0..10 {
+///     assert_eq!(first.generate().unwrap(), second.generate().unwrap());
+/// }
+/// ```
+pub struct SeededGenerator {
+    generator: MnemonicGenerator,
+    rng: ChaCha12Rng,
+}
+
+/// The position of a [`SeededGenerator`] in its sequence.
+///
+/// Serializable with the `serde` feature, so a service can checkpoint it and
+/// resume the same sequence after a restart with [`SeededGenerator::restore`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeededState {
+    seed: [u8; 32],
+    stream: u64,
+    word_pos: u128,
+}
+
+impl SeededGenerator {
+    /// Wraps a generator, drawing names from a random number generator seeded
+    /// with `seed`.
+    pub fn new(generator: MnemonicGenerator, seed: u64) -> Self {
+        Self {
+            generator,
+            rng: ChaCha12Rng::from_seed(expand_seed(seed)),
+        }
+    }
+
+    /// Wraps a generator, seeded with the hash of a human-readable phrase.
+    pub fn from_phrase(generator: MnemonicGenerator, phrase: &str) -> Self {
+        Self::new(generator, phrase_seed(phrase))
+    }
+
+    /// Wraps a generator, seeded once with entropy from `getrandom`.
+    ///
+    /// The recommended constructor on `wasm32-unknown-unknown`: the only
+    /// access to the platform's randomness happens here and fails with an
+    /// error instead of a panic, and every later name is drawn from the
+    /// seeded sequence. Requires the `getrandom` feature, which the `wasm`
+    /// feature enables.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::Entropy` if no entropy is available
+    #[cfg(feature = "getrandom")]
+    pub fn from_os_entropy(generator: MnemonicGenerator) -> Result<Self, MnemonicError> {
+        Ok(Self {
+            generator,
+            rng: ChaCha12Rng::from_rng(&mut crate::entropy::os_rng()?),
+        })
+    }
+
+    /// Generates the next name of the sequence, using the default separator.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate`]
+    pub fn generate(&mut self) -> Result<String, MnemonicError> {
+        self.generator.generate_with_rng(&mut self.rng)
+    }
+
+    /// Returns the current position in the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::{MnemonicGenerator, SeededGenerator};
+    ///
+    /// let generator = || MnemonicGenerator::with_words(["happy", "brave"], ["turing", "hopper"]);
+    /// let mut seeded = SeededGenerator::new(generator(), 42);
+    /// seeded.generate().unwrap();
+    /// let checkpoint = seeded.state();
+    /// let next = seeded.generate().unwrap();
+    ///
+    /// let mut resumed = SeededGenerator::new(generator(), 0);
+    /// resumed.restore(checkpoint);
+    /// assert_eq!(resumed.generate().unwrap(), next);
+    /// ```
+    pub fn state(&self) -> SeededState {
+        SeededState {
+            seed: self.rng.get_seed(),
+            stream: self.rng.get_stream(),
+            word_pos: self.rng.get_word_pos(),
+        }
+    }
+
+    /// Moves to a position previously returned by [`SeededGenerator::state`].
+    ///
+    /// The following names match the original sequence only if the wrapped
+    /// generator has the same configuration as the one that was checkpointed.
+    pub fn restore(&mut self, state: SeededState) {
+        self.rng = ChaCha12Rng::from_seed(state.seed);
+        self.rng.set_stream(state.stream);
+        self.rng.set_word_pos(state.word_pos);
+    }
+
+    /// Returns the wrapped generator.
+    pub fn generator(&self) -> &MnemonicGenerator {
+        &self.generator
+    }
+
+    /// Writes the configuration and the position in the sequence to
+    /// `writer`, so that a restarted process can continue the sequence.
+    ///
+    /// The state follows the format of [`MnemonicGenerator::save`], of kind
+    /// `seeded`, with a final `rng` line holding the ChaCha12 seed as 64
+    /// hexadecimal digits, the stream, and the word position, as in
+    /// [`SeededState`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::save`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::{MnemonicGenerator, SeededGenerator};
+    ///
+    /// let generator = MnemonicGenerator::with_words(["happy", "brave"], ["turing", "hopper"]);
+    /// let mut seeded = SeededGenerator::new(generator, 42);
+    /// seeded.generate().unwrap();
+    ///
+    /// let mut saved = Vec::new();
+    /// seeded.save(&mut saved).unwrap();
+    /// let mut restarted = SeededGenerator::load(saved.as_slice()).unwrap();
+    /// assert_eq!(restarted.generate().unwrap(), seeded.generate().unwrap());
+    /// ```
+    pub fn save(&self, writer: &mut impl Write) -> Result<(), MnemonicError> {
+        self.generator.write_state(writer, "seeded")?;
+        let state = self.state();
+        writer.write_all(b"rng ")?;
+        for byte in state.seed {
+            write!(writer, "{:02x}", byte)?;
+        }
+        writeln!(writer, " {} {}", state.stream, state.word_pos)?;
+        Ok(())
+    }
+
+    /// Restores a generator written by [`SeededGenerator::save`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidState` if the state is malformed, or
+    /// the same errors as [`MnemonicGenerator::load`]
+    pub fn load(reader: impl BufRead) -> Result<Self, MnemonicError> {
+        let (generator, rest) = MnemonicGenerator::read_state(reader, "seeded")?;
+        let mut rest = rest.into_iter();
+        let directive = match rest.next() {
+            Some(directive) if directive.key() == "rng" => directive,
+            Some(directive) => return Err(directive.unknown()),
+            None => {
+                return Err(MnemonicError::InvalidState {
+                    line: 1,
+                    reason: "missing \"rng\"".to_string(),
+                })
+            }
+        };
+        if let Some(extra) = rest.next() {
+            return Err(extra.unknown());
+        }
+
+        let hex: String = directive.parse(0, 3)?;
+        let mut seed = [0; 32];
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(directive.invalid(format!("invalid seed {:?}", hex)));
+        }
+        for (byte, digits) in seed.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            *byte = std::str::from_utf8(digits)
+                .ok()
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                .ok_or_else(|| directive.invalid(format!("invalid seed {:?}", hex)))?;
+        }
+
+        let mut seeded = Self::new(generator, 0);
+        seeded.restore(SeededState {
+            seed,
+            stream: directive.parse(1, 3)?,
+            word_pos: directive.parse(2, 3)?,
+        });
+        Ok(seeded)
+    }
+}
+
+impl MnemonicGenerator {
+    /// Creates a generator with the default words whose names follow a
+    /// sequence determined by a shared phrase.
+    ///
+    /// Teams can agree on a keyword and independently regenerate the same
+    /// names. Use [`SeededGenerator::from_phrase`] to seed a customized
+    /// generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let mut first = MnemonicGenerator::seeded_from_str("project-atlantis");
+    /// let mut second = MnemonicGenerator::seeded_from_str("project-atlantis");
+    /// assert_eq!(first.generate().unwrap(), second.generate().unwrap());
+    /// ```
+    #[cfg(feature = "default-words")]
+    pub fn seeded_from_str(phrase: &str) -> SeededGenerator {
+        SeededGenerator::from_phrase(Self::new(), phrase)
+    }
+}
+
+/// Expands a 64-bit seed into a ChaCha key with SplitMix64.
+pub(crate) fn expand_seed(mut seed: u64) -> [u8; 32] {
+    let mut key = [0; 32];
+    for chunk in key.chunks_exact_mut(8) {
+        seed = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        chunk.copy_from_slice(&(z ^ (z >> 31)).to_le_bytes());
+    }
+    key
+}
+
+/// Hashes a phrase into a seed that never changes between releases.
+fn phrase_seed(phrase: &str) -> u64 {
+    let mut hasher = Fnv1aHasher::default();
+    hasher.write(phrase.as_bytes());
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phrases_select_sequences() {
+        let names = |phrase| {
+            let mut seeded = SeededGenerator::from_phrase(
+                MnemonicGenerator::with_words(["a", "b", "c"], ["x", "y", "z"]),
+                phrase,
+            );
+            (0..20)
+                .map(|_| seeded.generate().unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names("atlantis"), names("atlantis"));
+        assert_ne!(names("atlantis"), names("lemuria"));
+    }
+
+    #[test]
+    fn sequences_are_pinned() {
+        let generator = MnemonicGenerator::with_words(
+            ["amber", "brisk", "calm", "deft", "eager"],
+            ["ada", "bohr", "curie", "dirac", "euler", "fermi", "gauss"],
+        );
+        let mut seeded = SeededGenerator::new(generator, 42);
+        let names: Vec<String> = (0..5).map(|_| seeded.generate().unwrap()).collect();
+        assert_eq!(
+            names,
+            [
+                "amber_curie",
+                "deft_dirac",
+                "eager_bohr",
+                "calm_fermi",
+                "brisk_fermi"
+            ]
+        );
+    }
+
+    #[test]
+    fn restoring_a_state_resumes_the_sequence() {
+        let generator = || MnemonicGenerator::with_words(["a", "b", "c"], ["x", "y", "z"]);
+        let mut seeded = SeededGenerator::new(generator(), 7);
+        for _ in 0..5 {
+            seeded.generate().unwrap();
+        }
+        let state = seeded.state();
+        let expected: Vec<String> = (0..10).map(|_| seeded.generate().unwrap()).collect();
+
+        seeded.restore(state);
+        let replayed: Vec<String> = (0..10).map(|_| seeded.generate().unwrap()).collect();
+        assert_eq!(replayed, expected);
+    }
+}
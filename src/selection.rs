@@ -0,0 +1,137 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use rand::RngCore;
+
+use crate::{sample, MnemonicGenerator};
+
+/// Decides which pair of words is tried next.
+///
+/// Strategies only propose candidates: the generator still applies its length
+/// window and filters and asks for another pair when one is rejected. Strategies
+/// that keep state must use interior mutability, since generation takes `&self`
+/// and a generator may be shared across threads.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{MnemonicGenerator, Sequential};
+///
+/// let generator = MnemonicGenerator::with_words(["a", "b"], ["x", "y"])
+///     .with_selection(Sequential::new());
+/// let names: Vec<String> = (0..5).map(|_| generator.generate().unwrap()).collect();
+/// assert_eq!(names, ["a_x", "a_y", "b_x", "b_y", "a_x"]);
+/// ```
+pub trait SelectionStrategy: Send + Sync {
+    /// Returns the indices of the next candidate from non-empty lists of the
+    /// given lengths.
+    fn pick(&self, rng: &mut dyn RngCore, left_len: usize, right_len: usize) -> (usize, usize);
+}
+
+/// Draws both words independently and uniformly at random, the default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UniformRandom;
+
+impl SelectionStrategy for UniformRandom {
+    fn pick(&self, rng: &mut dyn RngCore, left_len: usize, right_len: usize) -> (usize, usize) {
+        (sample::index(rng, left_len), sample::index(rng, right_len))
+    }
+}
+
+/// Enumerates every pair in rank order, ordered by the left word and then the
+/// right word, and starts over after the last pair.
+///
+/// Useful for deterministic enumeration of the namespace. See
+/// [`MnemonicGenerator::rank`].
+#[derive(Debug, Default)]
+pub struct Sequential {
+    cursor: AtomicU64,
+}
+
+impl Sequential {
+    /// Starts at the first pair.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts at the pair of the given rank.
+    pub fn starting_at(rank: u64) -> Self {
+        Self {
+            cursor: AtomicU64::new(rank),
+        }
+    }
+}
+
+impl SelectionStrategy for Sequential {
+    fn pick(&self, _rng: &mut dyn RngCore, left_len: usize, right_len: usize) -> (usize, usize) {
+        let count = left_len as u64 * right_len as u64;
+        let rank = self.cursor.fetch_add(1, Ordering::Relaxed) % count;
+        (
+            (rank / right_len as u64) as usize,
+            (rank % right_len as u64) as usize,
+        )
+    }
+}
+
+/// Cycles through each list in order, independently on each side, so every
+/// word is used as often as every other word of its list.
+///
+/// All pairs are eventually produced only if the list sizes share no common
+/// factor.
+#[derive(Debug, Default)]
+pub struct RoundRobin {
+    cursor: AtomicU64,
+}
+
+impl RoundRobin {
+    /// Starts at the first word of each list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SelectionStrategy for RoundRobin {
+    fn pick(&self, _rng: &mut dyn RngCore, left_len: usize, right_len: usize) -> (usize, usize) {
+        let step = self.cursor.fetch_add(1, Ordering::Relaxed);
+        (
+            (step % left_len as u64) as usize,
+            (step % right_len as u64) as usize,
+        )
+    }
+}
+
+impl MnemonicGenerator {
+    /// Replaces the strategy that picks the next candidate pair.
+    ///
+    /// By default pairs are drawn uniformly at random, and with a length window
+    /// only among the pairs that fit it. Other strategies propose pairs
+    /// regardless of the window, and pairs outside it are skipped like any
+    /// other rejected candidate.
+    pub fn with_selection<S: SelectionStrategy + 'static>(mut self, strategy: S) -> Self {
+        self.selection = Some(Arc::new(strategy));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_robin_uses_words_evenly() {
+        let generator = MnemonicGenerator::with_words(["a", "b"], ["x", "y", "z"])
+            .with_selection(RoundRobin::new());
+        let names: Vec<String> = (0..6).map(|_| generator.generate().unwrap()).collect();
+        assert_eq!(names, ["a_x", "b_y", "a_z", "b_x", "a_y", "b_z"]);
+    }
+
+    #[test]
+    fn sequential_skips_rejected_pairs() {
+        let generator = MnemonicGenerator::with_words(["a", "bb"], ["x", "yy"])
+            .with_length_range(4..=4)
+            .with_selection(Sequential::starting_at(1));
+        assert_eq!(generator.generate().unwrap(), "a_yy");
+        assert_eq!(generator.generate().unwrap(), "bb_x");
+        assert_eq!(generator.generate().unwrap(), "a_yy");
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{MnemonicError, MnemonicGenerator};
+
+impl MnemonicGenerator {
+    /// Sets the separator used by `generate()`, validating it first.
+    ///
+    /// An explicit separator takes precedence over the one of the naming
+    /// profile. A profile configured afterwards checks it again, see
+    /// [`MnemonicGenerator::with_profile`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidSeparator` if the separator would make
+    /// generated names ambiguous, see [`MnemonicGenerator::validate_separator`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let words = || MnemonicGenerator::with_words(["hopeful"], ["turing"]);
+    /// let generator = words().with_separator("-").unwrap();
+    /// assert_eq!(generator.generate().unwrap(), "hopeful-turing");
+    ///
+    /// assert!(words().with_separator("").is_err());
+    /// assert!(words().with_separator("ing").is_err());
+    /// ```
+    pub fn with_separator(mut self, separator: &str) -> Result<Self, MnemonicError> {
+        self.validate_separator(separator)?;
+        self.separator = Some(separator.to_string());
+        Ok(self)
+    }
+
+    /// Checks that names joined by `separator` can always be split back into
+    /// their words.
+    ///
+    /// A separator is rejected if it is empty, if it occurs inside any word
+    /// (including across the boundary between the first word and the separator
+    /// itself), or if the configured naming profile does not allow its
+    /// characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidSeparator` describing the first problem found
+    pub fn validate_separator(&self, separator: &str) -> Result<(), MnemonicError> {
+        let invalid = |reason: String| MnemonicError::InvalidSeparator {
+            separator: separator.to_string(),
+            reason,
+        };
+
+        if separator.is_empty() {
+            return Err(invalid(
+                "an empty separator makes word boundaries ambiguous".to_string(),
+            ));
+        }
+
+        if let Some(profile) = &self.profile {
+            if let Some(c) = separator.chars().find(|&c| {
+                !profile.allows_char(c) || profile.apply(&c.to_string()) != c.to_string()
+            }) {
+                return Err(invalid(format!(
+                    "character {:?} violates the naming profile",
+                    c
+                )));
+            }
+        }
+
+        // The first occurrence of the separator in `word + separator` must be
+        // the separator itself for every word followed by one, otherwise
+        // splitting on it cuts the word.
+        let (first, second) = self.ordered(&self.words.left, &self.words.right);
+        let middle = self.middle.iter().flat_map(|middle| middle.words().iter());
+        if let Some(word) = first
+            .iter()
+            .chain(middle)
+            .find(|word| format!("{}{}", word, separator).find(separator) != Some(word.len()))
+        {
+            return Err(invalid(format!("it overlaps the word {:?}", word)));
+        }
+        if let Some(word) = second.iter().find(|word| word.contains(separator)) {
+            return Err(invalid(format!("it occurs inside the word {:?}", word)));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use crate::{MnemonicError, MnemonicGenerator, NamingProfile};
+
+    #[test]
+    fn rejects_separators_overlapping_words() {
+        let generator = MnemonicGenerator::with_words(["xa"], ["turing"]);
+        assert!(matches!(
+            generator.validate_separator("aa"),
+            Err(MnemonicError::InvalidSeparator { separator, .. }) if separator == "aa"
+        ));
+        assert!(generator.validate_separator("ur").is_err());
+        assert!(generator.validate_separator("--").is_ok());
+    }
+
+    #[test]
+    fn rejects_separators_violating_profile() {
+        let generator = MnemonicGenerator::new()
+            .with_profile(NamingProfile::rfc1123_hostname())
+            .unwrap();
+        assert!(generator.validate_separator("_").is_err());
+
+        let generator = generator.with_separator("-").unwrap();
+        assert!(generator.generate().unwrap().contains('-'));
+    }
+
+    #[test]
+    fn rechecks_separator_when_profile_is_set_later() {
+        let generator = MnemonicGenerator::new().with_separator("_").unwrap();
+        assert!(matches!(
+            generator.with_profile(NamingProfile::rfc1123_hostname()),
+            Err(MnemonicError::InvalidSeparator { separator, .. }) if separator == "_"
+        ));
+    }
+}
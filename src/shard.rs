@@ -0,0 +1,142 @@
+use rand::RngCore;
+
+use crate::{sample, MnemonicError, MnemonicGenerator};
+
+/// One of `count` disjoint parts of the namespace: the pairs whose rank, as
+/// returned by [`MnemonicGenerator::rank`], leaves remainder `id` when divided
+/// by `count`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Shard {
+    id: usize,
+    count: usize,
+    left_len: usize,
+    right_len: usize,
+}
+
+impl Shard {
+    /// Draws a uniformly distributed pair of indices from the shard.
+    ///
+    /// Returns `None` if the shard is empty.
+    pub(crate) fn pick<G: RngCore + ?Sized>(&self, rng: &mut G) -> Option<(usize, usize)> {
+        // Ranks are computed in u128, since the product of two list lengths
+        // can overflow a usize.
+        let (id, count) = (self.id as u128, self.count as u128);
+        let right_len = self.right_len as u128;
+        let total = self.left_len as u128 * right_len;
+        if id >= total {
+            return None;
+        }
+        let size = (total - id).div_ceil(count);
+        let rank = id + sample::index_u128(rng, size) * count;
+        Some(((rank / right_len) as usize, (rank % right_len) as usize))
+    }
+
+    /// Returns the shard's id and the number of shards.
+    pub(crate) fn id_and_count(&self) -> (usize, usize) {
+        (self.id, self.count)
+    }
+
+    /// Returns `true` if the pair belongs to the shard.
+    pub(crate) fn fits(&self, left_idx: usize, right_idx: usize) -> bool {
+        let rank = left_idx as u128 * self.right_len as u128 + right_idx as u128;
+        rank % self.count as u128 == self.id as u128
+    }
+}
+
+impl MnemonicGenerator {
+    /// Returns a copy of the generator that only produces the names of shard
+    /// `shard_id` out of `shard_count`.
+    ///
+    /// The shards partition the namespace deterministically by rank, so
+    /// generators sharded with the same lists and the same count, one per node
+    /// of a cluster, never produce the same pair of words, without any
+    /// coordination between the nodes. Each shard holds an equal share of the
+    /// pairs, give or take one, and names are drawn uniformly within it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidConfig` if `shard_count` is zero or
+    /// `shard_id` is not below it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "bohr"]);
+    /// let node_0 = generator.shard(0, 2).unwrap();
+    /// let node_1 = generator.shard(1, 2).unwrap();
+    /// for _ in 0..10 {
+    ///     let name = node_0.generate().unwrap();
+    ///     assert!(name == "hopeful_turing" || name == "brave_turing");
+    ///     assert!(node_1.generate().unwrap().ends_with("_bohr"));
+    /// }
+    /// ```
+    pub fn shard(&self, shard_id: usize, shard_count: usize) -> Result<Self, MnemonicError> {
+        if shard_id >= shard_count {
+            return Err(MnemonicError::InvalidConfig(format!(
+                "shard {} does not exist among {} shards",
+                shard_id, shard_count
+            )));
+        }
+
+        let mut sharded = self.clone();
+        sharded.shard = Some(Shard {
+            id: shard_id,
+            count: shard_count,
+            left_len: self.words.left.len(),
+            right_len: self.words.right.len(),
+        });
+        Ok(sharded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn shards_partition_the_namespace() {
+        let generator = MnemonicGenerator::with_words(["a", "b", "c"], ["x", "y", "z", "w"]);
+        let mut seen = HashSet::new();
+        for shard_id in 0..5 {
+            let shard = generator.shard(shard_id, 5).unwrap();
+            let count = shard.combination_count();
+            assert!(count == 2 || count == 3, "shard {} has {}", shard_id, count);
+            let names: HashSet<String> = (0..200).map(|_| shard.generate().unwrap()).collect();
+            assert_eq!(names.len() as u128, count);
+            assert!(names.iter().all(|name| seen.insert(name.clone())));
+        }
+        assert_eq!(seen.len(), 12);
+    }
+
+    #[test]
+    fn rejects_missing_shards() {
+        let generator = MnemonicGenerator::with_words(["a"], ["x"]);
+        assert!(matches!(
+            generator.shard(2, 2),
+            Err(MnemonicError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            generator.shard(1, 3).unwrap().generate(),
+            Err(MnemonicError::AllCandidatesRejected)
+        ));
+    }
+
+    #[test]
+    fn huge_namespaces_do_not_overflow() {
+        let shard = Shard {
+            id: 3,
+            count: 5,
+            left_len: usize::MAX,
+            right_len: usize::MAX,
+        };
+        let mut rng = crate::rng::default_rng();
+        for _ in 0..100 {
+            let (left_idx, right_idx) = shard.pick(&mut rng).unwrap();
+            assert!(shard.fits(left_idx, right_idx));
+        }
+    }
+}
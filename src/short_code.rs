@@ -0,0 +1,146 @@
+use std::hash::Hasher;
+
+use crate::mnemonic::offset_of;
+use crate::named::Fnv1aHasher;
+use crate::{radix, MnemonicError, MnemonicGenerator, SuffixAlphabet, WordSide};
+
+impl MnemonicGenerator {
+    /// Encodes `value` as a short code such as `turing_7f3k`, for order or
+    /// ticket references that are easy to read out to support.
+    ///
+    /// The payload is `value` in Crockford's base32, lowercase, using as few
+    /// characters as it needs. The word is a right-hand word chosen by a fixed
+    /// hash of `value`, so the same value always gets the same code and the
+    /// word doubles as a check on the payload. Word and payload are joined by
+    /// the default separator and normalized to the profile's case.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::EmptyList` if the right-hand list is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::new().with_separator("-").unwrap();
+    /// let code = generator.encode_short_code(1234).unwrap();
+    /// assert!(code.ends_with("-16j"));
+    /// assert_eq!(generator.decode_short_code(&code).unwrap(), 1234);
+    /// # }
+    /// ```
+    pub fn encode_short_code(&self, value: u64) -> Result<String, MnemonicError> {
+        let word = self.short_code_word(value)?;
+
+        let bits = u64::BITS - value.leading_zeros();
+        let alphabet = SuffixAlphabet::Crockford;
+        let payload: String = radix::to_digits(
+            u128::from(value),
+            alphabet.len() as u128,
+            radix::width(alphabet.len() as u128, bits).max(1),
+        )
+        .into_iter()
+        .map(|digit| alphabet.char_at(digit))
+        .collect();
+
+        Ok(self.normalize(&format!("{}{}", word, self.default_separator())) + &payload)
+    }
+
+    /// Decodes a short code produced by [`MnemonicGenerator::encode_short_code`].
+    ///
+    /// The payload is read ignoring case, and, as Crockford's base32 allows,
+    /// `i` and `l` are read as `1` and `o` as `0`, so codes that were read
+    /// aloud or copied by hand still decode.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidMnemonic` if the code is not a right-hand
+    /// word and a payload, `MnemonicError::ChecksumMismatch` if the word does
+    /// not belong to the payload, or `MnemonicError::EmptyList` if the
+    /// right-hand list is empty
+    pub fn decode_short_code(&self, code: &str) -> Result<u64, MnemonicError> {
+        let invalid = |part: &str, reason: &str| MnemonicError::InvalidMnemonic {
+            name: code.to_string(),
+            position: offset_of(code, part),
+            reason: reason.to_string(),
+        };
+
+        let (word, payload) = code
+            .rsplit_once(self.normalize(self.default_separator()).as_str())
+            .ok_or_else(|| invalid(code, "missing separator"))?;
+        self.find_word(WordSide::Right, word)
+            .ok_or_else(|| invalid(word, "unknown word"))?;
+        if payload.is_empty() {
+            return Err(invalid(payload, "missing payload"));
+        }
+
+        let alphabet = SuffixAlphabet::Crockford;
+        let digits = payload
+            .chars()
+            .map(|c| alphabet.position(c))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| invalid(payload, "invalid payload character"))?;
+        let value = radix::from_digits(digits, alphabet.len() as u128)
+            .and_then(|value| u64::try_from(value).ok())
+            .ok_or_else(|| invalid(payload, "payload is too large"))?;
+
+        if self.normalize(self.short_code_word(value)?) != word {
+            return Err(MnemonicError::ChecksumMismatch(code.to_string()));
+        }
+        Ok(value)
+    }
+
+    /// Returns the right-hand word belonging to `value`.
+    fn short_code_word(&self, value: u64) -> Result<&str, MnemonicError> {
+        if self.words.right.is_empty() {
+            return Err(MnemonicError::EmptyList {
+                list: WordSide::Right.into(),
+            });
+        }
+        let mut hasher = Fnv1aHasher::default();
+        hasher.write(&value.to_be_bytes());
+        let idx = hasher.finish() % self.words.right.len() as u64;
+        Ok(self.words.right.get(idx as usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_and_accepts_confusables() {
+        let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"]);
+        for value in [0, 31, 32, 1_000_000, u64::MAX] {
+            let code = generator.encode_short_code(value).unwrap();
+            assert_eq!(generator.decode_short_code(&code).unwrap(), value);
+        }
+        assert_eq!(generator.encode_short_code(32).unwrap(), "turing_10");
+        assert_eq!(generator.decode_short_code("turing_IO").unwrap(), 32);
+    }
+
+    #[test]
+    fn detects_mistyped_codes() {
+        let generator = MnemonicGenerator::with_words(["hopeful"], ["turing", "bohr", "curie"]);
+        let code = generator.encode_short_code(1234).unwrap();
+        let (word, _) = code.split_once('_').unwrap();
+        let other = ["turing", "bohr", "curie"]
+            .into_iter()
+            .find(|&other| other != word)
+            .unwrap();
+        assert!(matches!(
+            generator.decode_short_code(&format!("{}_16j", other)),
+            Err(MnemonicError::ChecksumMismatch(_))
+        ));
+        assert!(matches!(
+            generator.decode_short_code("turing_16u"),
+            Err(MnemonicError::InvalidMnemonic { position: 7, .. })
+        ));
+        assert!(matches!(
+            generator.decode_short_code("nobody_16j"),
+            Err(MnemonicError::InvalidMnemonic { position: 0, .. })
+        ));
+    }
+}
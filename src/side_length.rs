@@ -0,0 +1,124 @@
+use crate::word_list::WordList;
+use crate::MnemonicGenerator;
+
+/// Upper bounds on the length of the left and right word, each with the
+/// indices of the words that fit precomputed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct SideLengths {
+    left: Option<SideLimit>,
+    right: Option<SideLimit>,
+}
+
+/// The words of one list no longer than `max` characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SideLimit {
+    max: usize,
+    /// Indices of the fitting words, in ascending order.
+    fitting: Vec<usize>,
+}
+
+impl SideLimit {
+    fn new(max: usize, words: &WordList) -> Self {
+        Self {
+            max,
+            fitting: words
+                .iter()
+                .enumerate()
+                .filter(|(_, word)| word.chars().count() <= max)
+                .map(|(idx, _)| idx)
+                .collect(),
+        }
+    }
+
+    fn fits(&self, idx: usize) -> bool {
+        self.fitting.binary_search(&idx).is_ok()
+    }
+}
+
+impl SideLengths {
+    /// Returns the limits of the left and right word, if any.
+    pub(crate) fn limits(&self) -> (Option<usize>, Option<usize>) {
+        let max = |limit: &Option<SideLimit>| limit.as_ref().map(|limit| limit.max);
+        (max(&self.left), max(&self.right))
+    }
+
+    /// Returns the indices of the fitting words of each list, if limited.
+    pub(crate) fn indices(&self) -> (Option<&[usize]>, Option<&[usize]>) {
+        (
+            self.left.as_ref().map(|limit| limit.fitting.as_slice()),
+            self.right.as_ref().map(|limit| limit.fitting.as_slice()),
+        )
+    }
+
+    /// Returns `true` if both words are short enough.
+    pub(crate) fn fits(&self, left_idx: usize, right_idx: usize) -> bool {
+        self.left.as_ref().is_none_or(|limit| limit.fits(left_idx))
+            && self
+                .right
+                .as_ref()
+                .is_none_or(|limit| limit.fits(right_idx))
+    }
+}
+
+impl MnemonicGenerator {
+    /// Limits the left word to at most `max` characters.
+    ///
+    /// The fitting words are found once, here, and candidates are drawn from
+    /// them directly, so a tight limit does not slow generation down. Combine
+    /// with [`MnemonicGenerator::with_right_max_len`] to keep names compact
+    /// without curating the lists by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["bold", "adventurous"], ["bohr", "ramanujan"])
+    ///     .with_left_max_len(4)
+    ///     .with_right_max_len(4);
+    /// assert_eq!(generator.generate().unwrap(), "bold_bohr");
+    /// ```
+    pub fn with_left_max_len(mut self, max: usize) -> Self {
+        let mut lengths = self.side_lengths.take().unwrap_or_default();
+        lengths.left = Some(SideLimit::new(max, &self.words.left));
+        self.side_lengths = Some(lengths);
+        self.restrict_length_window()
+    }
+
+    /// Limits the right word to at most `max` characters.
+    ///
+    /// See [`MnemonicGenerator::with_left_max_len`].
+    pub fn with_right_max_len(mut self, max: usize) -> Self {
+        let mut lengths = self.side_lengths.take().unwrap_or_default();
+        lengths.right = Some(SideLimit::new(max, &self.words.right));
+        self.side_lengths = Some(lengths);
+        self.restrict_length_window()
+    }
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use crate::{MnemonicError, MnemonicGenerator};
+
+    #[test]
+    fn names_respect_both_limits() {
+        let generator = MnemonicGenerator::new()
+            .with_left_max_len(5)
+            .with_right_max_len(6);
+        for _ in 0..100 {
+            let name = generator.generate().unwrap();
+            let (left, right) = name.split_once('_').unwrap();
+            assert!(left.len() <= 5 && right.len() <= 6, "{} is too long", name);
+        }
+        assert!(generator.combination_count() < 108 * 237);
+    }
+
+    #[test]
+    fn impossible_limits_are_reported() {
+        let generator = MnemonicGenerator::new().with_left_max_len(1);
+        assert!(matches!(
+            generator.generate(),
+            Err(MnemonicError::AllCandidatesRejected)
+        ));
+    }
+}
@@ -0,0 +1,568 @@
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::{
+    Checksum, MnemonicError, MnemonicGenerator, NamingProfile, ProfanityFilter, SuffixAlphabet,
+    WordSide,
+};
+
+/// The first word of every saved state.
+const MAGIC: &str = "mnemonic-generator-state";
+
+/// The version of the format written by [`MnemonicGenerator::save`].
+const FORMAT_VERSION: u32 = 1;
+
+/// One line of a saved state: a key followed by its fields.
+#[derive(Debug)]
+pub(crate) struct Directive {
+    line: usize,
+    key: String,
+    fields: Vec<String>,
+}
+
+impl Directive {
+    /// Returns the key naming the directive.
+    pub(crate) fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the fields, unescaped.
+    pub(crate) fn fields(&self) -> &[String] {
+        &self.fields
+    }
+
+    /// Parses field `idx` of a directive that must have exactly `len` fields.
+    pub(crate) fn parse<T: FromStr>(&self, idx: usize, len: usize) -> Result<T, MnemonicError> {
+        if self.fields.len() != len {
+            return Err(self.invalid(format!(
+                "{:?} expects {} fields, found {}",
+                self.key,
+                len,
+                self.fields.len()
+            )));
+        }
+        self.fields[idx].parse().map_err(|_| {
+            self.invalid(format!(
+                "invalid {:?} value {:?}",
+                self.key, self.fields[idx]
+            ))
+        })
+    }
+
+    /// Returns an error pointing at this line.
+    pub(crate) fn invalid(&self, reason: String) -> MnemonicError {
+        MnemonicError::InvalidState {
+            line: self.line,
+            reason,
+        }
+    }
+
+    /// Returns an error for a key that does not belong in the state.
+    pub(crate) fn unknown(&self) -> MnemonicError {
+        self.invalid(format!("unknown key {:?}", self.key))
+    }
+}
+
+impl MnemonicGenerator {
+    /// Writes the generator's configuration to `writer`, so that
+    /// [`MnemonicGenerator::load`] can restore it, for example after a
+    /// restart.
+    ///
+    /// The state is UTF-8 text, one directive per line. The first line is
+    /// `mnemonic-generator-state 1 generator`: the format, its version, and
+    /// the kind of state, which is `draining` for
+    /// [`DrainingGenerator::save`](crate::DrainingGenerator::save) and
+    /// `seeded` for [`SeededGenerator::save`](crate::SeededGenerator::save).
+    /// Every other line is a key followed by fields, all separated by single
+    /// spaces. Spaces, `%`, and control characters within a field are
+    /// percent-encoded as the bytes of their UTF-8 encoding, as in `%20`.
+    ///
+    /// The `left` and `right` lines come first and hold the words of each list
+    /// in order. The options follow, each on its own line and only if
+    /// configured: `profile` (minimum and maximum length, `true` or `false` for
+    /// lowercase and for alphanumeric edges, separator, and allowed symbols),
+    /// `profanity-terms` and `profanity-combinations` (the filter's terms, and
+    /// its blocked pairs as left and right word in turn), `reversed` (no
+    /// fields), `separator`, `middle` (the middle words), `prefix`, `suffix`,
+    /// `checksum` (`word`, or `character` optionally followed by an alphabet
+    /// such as `unambiguous-crockford`), `timestamp` (the precision in
+    /// seconds), `length` (minimum and maximum), `left-max-len`,
+    /// `right-max-len`, `left-starting-with`, `right-starting-with`,
+    /// `max-syllables`, `pronounceable` (no fields), `left-allowed` and
+    /// `right-allowed` (the indices of the words that passed the word filters),
+    /// `shard` (id and count), and `regex`. Stateful generators add their state
+    /// after the options.
+    ///
+    /// Observers are not part of the state; attach them again after loading.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidConfig` if the generator has transforms
+    /// or a selection strategy, which cannot be saved, or `MnemonicError::Io`
+    /// if writing fails
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "bohr"])
+    ///     .with_prefix("prod-");
+    /// let mut saved = Vec::new();
+    /// generator.save(&mut saved).unwrap();
+    /// assert!(saved.starts_with(b"mnemonic-generator-state 1 generator\nleft hopeful brave\n"));
+    ///
+    /// let loaded = MnemonicGenerator::load(saved.as_slice()).unwrap();
+    /// assert_eq!(loaded, generator);
+    /// ```
+    pub fn save(&self, writer: &mut impl Write) -> Result<(), MnemonicError> {
+        self.write_state(writer, "generator")
+    }
+
+    /// Restores a generator written by [`MnemonicGenerator::save`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidState` if the state is malformed, was
+    /// written by a stateful generator, or needs a feature that is disabled,
+    /// or `MnemonicError::Io` if reading fails
+    pub fn load(reader: impl BufRead) -> Result<Self, MnemonicError> {
+        let (generator, rest) = Self::read_state(reader, "generator")?;
+        match rest.first() {
+            Some(directive) => Err(directive.unknown()),
+            None => Ok(generator),
+        }
+    }
+
+    /// Writes the header of a state of the given kind and the generator's
+    /// configuration.
+    pub(crate) fn write_state(
+        &self,
+        writer: &mut impl Write,
+        kind: &str,
+    ) -> Result<(), MnemonicError> {
+        if !self.transforms.is_empty() || self.selection.is_some() {
+            return Err(MnemonicError::InvalidConfig(
+                "transforms and selection strategies cannot be saved".to_string(),
+            ));
+        }
+
+        writeln!(writer, "{} {} {}", MAGIC, FORMAT_VERSION, kind)?;
+        write_directive(writer, "left", self.words.left.iter())?;
+        write_directive(writer, "right", self.words.right.iter())?;
+
+        if let Some(profile) = &self.profile {
+            let (min_len, max_len) = profile.length_bounds();
+            write_directive(
+                writer,
+                "profile",
+                [
+                    min_len.to_string(),
+                    max_len.to_string(),
+                    profile.is_lowercase().to_string(),
+                    profile.requires_alphanumeric_edges().to_string(),
+                    profile.separator().to_string(),
+                    profile.allowed_symbols().to_string(),
+                ],
+            )?;
+        }
+        if let Some(filter) = &self.profanity_filter {
+            write_directive(writer, "profanity-terms", filter.terms())?;
+            write_directive(
+                writer,
+                "profanity-combinations",
+                filter
+                    .blocked_combinations()
+                    .iter()
+                    .flat_map(|(left, right)| [left, right]),
+            )?;
+        }
+        if self.reversed {
+            write_directive(writer, "reversed", [""; 0])?;
+        }
+        if let Some(separator) = &self.separator {
+            write_directive(writer, "separator", [separator])?;
+        }
+        if let Some(middle) = &self.middle {
+            write_directive(writer, "middle", middle.words().iter())?;
+        }
+        if !self.prefix.is_empty() {
+            write_directive(writer, "prefix", [&self.prefix])?;
+        }
+        if !self.suffix.is_empty() {
+            write_directive(writer, "suffix", [&self.suffix])?;
+        }
+        if let Some(checksum) = self.checksum {
+            match checksum {
+                Checksum::Word => write_directive(writer, "checksum", ["word"])?,
+                Checksum::Character => write_directive(writer, "checksum", ["character"])?,
+                Checksum::CharacterIn(alphabet) => {
+                    write_directive(writer, "checksum", ["character", alphabet.name()])?
+                }
+            }
+        }
+        if let Some(timestamp) = &self.timestamp {
+            write_directive(
+                writer,
+                "timestamp",
+                [timestamp.precision().as_secs().to_string()],
+            )?;
+        }
+        if let Some(window) = &self.length_window {
+            let range = window.range();
+            write_directive(
+                writer,
+                "length",
+                [range.start().to_string(), range.end().to_string()],
+            )?;
+        }
+        if let Some(lengths) = &self.side_lengths {
+            let (left, right) = lengths.limits();
+            for (key, max) in [("left-max-len", left), ("right-max-len", right)] {
+                if let Some(max) = max {
+                    write_directive(writer, key, [max.to_string()])?;
+                }
+            }
+        }
+        if let Some(letters) = &self.leading_letters {
+            let (left, right) = letters.letters();
+            for (key, letter) in [("left-starting-with", left), ("right-starting-with", right)] {
+                if let Some(letter) = letter {
+                    write_directive(writer, key, [letter.to_string()])?;
+                }
+            }
+        }
+        if let Some(limit) = &self.syllable_limit {
+            write_directive(writer, "max-syllables", [limit.max().to_string()])?;
+        }
+        if self.pronounceable.is_some() {
+            write_directive(writer, "pronounceable", [""; 0])?;
+        }
+        if let Some(allowed) = &self.allowed_words {
+            let (left, right) = allowed.indices();
+            for (key, indices) in [("left-allowed", left), ("right-allowed", right)] {
+                if let Some(indices) = indices {
+                    write_directive(writer, key, indices.iter().map(usize::to_string))?;
+                }
+            }
+        }
+        if let Some(shard) = &self.shard {
+            let (id, count) = shard.id_and_count();
+            write_directive(writer, "shard", [id.to_string(), count.to_string()])?;
+        }
+        #[cfg(feature = "regex")]
+        if let Some(regex) = &self.regex {
+            write_directive(writer, "regex", [regex.as_str()])?;
+        }
+        Ok(())
+    }
+
+    /// Reads a state of the given kind, returning the generator it configures
+    /// and the directives following the configuration.
+    pub(crate) fn read_state(
+        reader: impl BufRead,
+        kind: &str,
+    ) -> Result<(Self, Vec<Directive>), MnemonicError> {
+        let mut lines = reader.lines();
+        let header = lines.next().transpose()?.unwrap_or_default();
+        let invalid_header = |reason: String| MnemonicError::InvalidState { line: 1, reason };
+        match header.split(' ').collect::<Vec<_>>()[..] {
+            [MAGIC, version, found] => {
+                if version != FORMAT_VERSION.to_string() {
+                    return Err(invalid_header(format!(
+                        "unsupported format version {:?}",
+                        version
+                    )));
+                }
+                if found != kind {
+                    return Err(invalid_header(format!(
+                        "expected a {} state, found a {} state",
+                        kind, found
+                    )));
+                }
+            }
+            _ => return Err(invalid_header("missing header".to_string())),
+        }
+
+        let mut directives = Vec::new();
+        for (idx, line) in lines.enumerate() {
+            let line = line?;
+            if !line.is_empty() {
+                directives.push(parse_directive(idx + 2, &line)?);
+            }
+        }
+        let mut directives = directives.into_iter();
+
+        let mut list = |key: &str| match directives.next() {
+            Some(directive) if directive.key == key => Ok(directive.fields),
+            Some(directive) => Err(directive.invalid(format!("expected {:?}", key))),
+            None => Err(MnemonicError::InvalidState {
+                line: 1,
+                reason: format!("missing {:?}", key),
+            }),
+        };
+        let (left, right) = (list("left")?, list("right")?);
+        let mut generator = Self::with_words(left, right);
+
+        let mut rest = Vec::new();
+        for directive in directives {
+            generator = match directive.key() {
+                "profile" => {
+                    let bool_field = |idx| directive.parse::<bool>(idx, 6);
+                    let profile =
+                        NamingProfile::new(directive.parse(0, 6)?, directive.parse(1, 6)?)
+                            .with_lowercase(bool_field(2)?)
+                            .with_alphanumeric_edges(bool_field(3)?)
+                            .with_separator(&directive.fields[4])
+                            .with_allowed_symbols(&directive.fields[5]);
+                    generator
+                        .with_profile(profile)
+                        .map_err(|err| directive.invalid(err.to_string()))?
+                }
+                "profanity-terms" => generator
+                    .with_profanity_filter(ProfanityFilter::empty().with_terms(&directive.fields)),
+                "profanity-combinations" => {
+                    let filter = generator.profanity_filter.take().ok_or_else(|| {
+                        directive.invalid("missing \"profanity-terms\"".to_string())
+                    })?;
+                    if directive.fields.len() % 2 != 0 {
+                        return Err(directive.invalid("expected pairs of words".to_string()));
+                    }
+                    let filter = directive.fields.chunks(2).fold(filter, |filter, pair| {
+                        filter.with_blocked_combination(&pair[0], &pair[1])
+                    });
+                    generator.with_profanity_filter(filter)
+                }
+                "reversed" => {
+                    if !directive.fields.is_empty() {
+                        return Err(directive.invalid("\"reversed\" takes no fields".to_string()));
+                    }
+                    generator
+                        .with_reversed_order()
+                        .map_err(|err| directive.invalid(err.to_string()))?
+                }
+                "separator" => {
+                    generator.separator = Some(directive.parse(0, 1)?);
+                    generator
+                }
+                "middle" => generator
+                    .with_middle_words(directive.fields.iter().cloned())
+                    .map_err(|err| directive.invalid(err.to_string()))?,
+                "prefix" => generator.with_prefix(&directive.parse::<String>(0, 1)?),
+                "suffix" => generator.with_suffix(&directive.parse::<String>(0, 1)?),
+                "checksum" => match directive.fields.as_slice() {
+                    [kind] if kind == "word" => generator.with_checksum(Checksum::Word),
+                    [kind] if kind == "character" => generator.with_checksum(Checksum::Character),
+                    [kind, alphabet] if kind == "character" => {
+                        let alphabet = SuffixAlphabet::from_name(alphabet).ok_or_else(|| {
+                            directive.invalid(format!("unknown alphabet {:?}", alphabet))
+                        })?;
+                        generator.with_checksum(Checksum::CharacterIn(alphabet))
+                    }
+                    _ => {
+                        return Err(directive
+                            .invalid(format!("unknown checksum {:?}", directive.fields.join(" "))))
+                    }
+                },
+                "timestamp" => generator
+                    .with_timestamp(Duration::from_secs(directive.parse(0, 1)?))
+                    .map_err(|err| directive.invalid(err.to_string()))?,
+                "length" => {
+                    generator.with_length_range(directive.parse(0, 2)?..=directive.parse(1, 2)?)
+                }
+                "left-max-len" => generator.with_left_max_len(directive.parse(0, 1)?),
+                "right-max-len" => generator.with_right_max_len(directive.parse(0, 1)?),
+                "left-starting-with" => generator.with_left_starting_with(directive.parse(0, 1)?),
+                "right-starting-with" => generator.with_right_starting_with(directive.parse(0, 1)?),
+                "max-syllables" => generator.with_max_syllables(directive.parse(0, 1)?),
+                "pronounceable" => {
+                    if !directive.fields.is_empty() {
+                        return Err(
+                            directive.invalid("\"pronounceable\" takes no fields".to_string())
+                        );
+                    }
+                    generator.with_pronounceable_words()
+                }
+                "left-allowed" | "right-allowed" => {
+                    let (side, len) = match directive.key.as_str() {
+                        "left-allowed" => (WordSide::Left, generator.words.left.len()),
+                        _ => (WordSide::Right, generator.words.right.len()),
+                    };
+                    let indices = directive
+                        .fields
+                        .iter()
+                        .map(|field| match field.parse::<usize>() {
+                            Ok(idx) if idx < len => Ok(idx),
+                            _ => Err(directive.invalid(format!("invalid word index {:?}", field))),
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    generator
+                        .allowed_words
+                        .get_or_insert_default()
+                        .set(side, indices);
+                    generator.restrict_length_window()
+                }
+                "shard" => generator
+                    .shard(directive.parse(0, 2)?, directive.parse(1, 2)?)
+                    .map_err(|err| directive.invalid(err.to_string()))?,
+                #[cfg(feature = "regex")]
+                "regex" => {
+                    generator.regex =
+                        Some(regex_lite::Regex::new(&directive.parse::<String>(0, 1)?)?);
+                    generator
+                }
+                #[cfg(not(feature = "regex"))]
+                "regex" => {
+                    return Err(directive
+                        .invalid("regex filters require the \"regex\" feature".to_string()))
+                }
+                _ => {
+                    rest.push(directive);
+                    generator
+                }
+            };
+        }
+        Ok((generator, rest))
+    }
+}
+
+/// Writes a directive, percent-encoding its fields.
+fn write_directive<I>(writer: &mut impl Write, key: &str, fields: I) -> Result<(), MnemonicError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    writer.write_all(key.as_bytes())?;
+    for field in fields {
+        write!(writer, " {}", escape(field.as_ref()))?;
+    }
+    writeln!(writer)?;
+    Ok(())
+}
+
+/// Splits a line into a directive, decoding its fields.
+fn parse_directive(line: usize, text: &str) -> Result<Directive, MnemonicError> {
+    let mut parts = text.split(' ');
+    let key = parts.next().unwrap_or_default().to_string();
+    let fields = parts
+        .map(|field| {
+            unescape(field).ok_or_else(|| MnemonicError::InvalidState {
+                line,
+                reason: format!("invalid percent-encoding in {:?}", field),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Directive { line, key, fields })
+}
+
+/// Percent-encodes spaces, `%`, and control characters.
+fn escape(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+    for c in field.chars() {
+        if c == '%' || c.is_whitespace() || c.is_control() {
+            let mut bytes = [0; 4];
+            for byte in c.encode_utf8(&mut bytes).bytes() {
+                escaped.push_str(&format!("%{:02X}", byte));
+            }
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+/// Decodes a field written by [`escape`].
+fn unescape(field: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(field.len());
+    let mut rest = field.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_option() {
+        let generator = MnemonicGenerator::with_words(
+            ["hopeful", "brave", "bold"],
+            ["turing", "bohr", "my name"],
+        )
+        .with_profile(NamingProfile::kubernetes_label().with_length(5, 30))
+        .unwrap()
+        .with_profanity_filter(
+            ProfanityFilter::empty()
+                .with_term("bad")
+                .with_blocked_combination("bold", "bohr"),
+        )
+        .with_reversed_order()
+        .unwrap()
+        .with_middle_words(["crimson", "teal"])
+        .unwrap()
+        .with_prefix("eu 100%-")
+        .with_checksum(Checksum::CharacterIn(SuffixAlphabet::UnambiguousCrockford))
+        .with_timestamp(Duration::from_secs(3600))
+        .unwrap()
+        .with_length_range(5..=40)
+        .with_left_max_len(7)
+        .with_right_starting_with('t')
+        .with_max_syllables(6)
+        .with_pronounceable_words()
+        .with_left_word_filter(|word: &str| word != "bold")
+        .shard(1, 2)
+        .unwrap()
+        .with_separator("-")
+        .unwrap();
+
+        let mut saved = Vec::new();
+        generator.save(&mut saved).unwrap();
+        assert!(String::from_utf8_lossy(&saved).contains("\nprefix eu%20100%25-\n"));
+        assert_eq!(
+            MnemonicGenerator::load(saved.as_slice()).unwrap(),
+            generator
+        );
+    }
+
+    #[test]
+    fn reports_where_loading_failed() {
+        let load = |state: &str| match MnemonicGenerator::load(state.as_bytes()) {
+            Err(MnemonicError::InvalidState { line, .. }) => line,
+            other => panic!("{:?} loaded as {:?}", state, other.map(|_| ())),
+        };
+        let words = "left a\nright x\n";
+        assert_eq!(load(""), 1);
+        assert_eq!(load("mnemonic-generator-state 2 generator\n"), 1);
+        assert_eq!(load("mnemonic-generator-state 1 draining\n"), 1);
+        assert_eq!(load("mnemonic-generator-state 1 generator\nright x\n"), 2);
+        let state = format!("mnemonic-generator-state 1 generator\n{}length 3\n", words);
+        assert_eq!(load(&state), 4);
+        let state = format!(
+            "mnemonic-generator-state 1 generator\n{}prefix %zz\n",
+            words
+        );
+        assert_eq!(load(&state), 4);
+        let state = format!(
+            "mnemonic-generator-state 1 generator\n{}\nissued 0\n",
+            words
+        );
+        assert_eq!(load(&state), 5);
+
+        let generator = MnemonicGenerator::with_words(["a"], ["x"])
+            .with_transform(|name: String| name.to_uppercase());
+        assert!(matches!(
+            generator.save(&mut Vec::new()),
+            Err(MnemonicError::InvalidConfig(_))
+        ));
+    }
+}
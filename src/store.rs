@@ -0,0 +1,237 @@
+use std::collections::HashSet;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use crate::{MnemonicError, MnemonicGenerator, NameRegistry};
+
+/// A record of issued names persisted to an append-only file.
+///
+/// Each issued name is written on its own line and flushed immediately, so the
+/// set of used names survives process restarts. Backslashes, line feeds, and
+/// carriage returns in names are escaped as `\\`, `\n`, and `\r`. The file is
+/// read back into memory when the store is opened.
+///
+/// # Examples
+///
+/// ```no_run
+/// use mnemonic_generator::{MnemonicGenerator, UsedNameStore};
+///
+/// let mut store = UsedNameStore::open("issued-names.txt").expect("Failed to open store");
+/// let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "hopper"]);
+/// let name = generator
+///     .generate_unique_persistent(&mut store, 100)
+///     .expect("Failed to generate mnemonic");
+/// ```
+#[derive(Debug)]
+pub struct UsedNameStore {
+    path: PathBuf,
+    file: File,
+    names: HashSet<String>,
+}
+
+impl UsedNameStore {
+    /// Opens the store at `path`, creating the file if it does not exist.
+    ///
+    /// A final line without a line feed, left behind by a write that was cut
+    /// short, is removed from the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error raised while opening, reading, or repairing the
+    /// file, including `io::ErrorKind::InvalidData` for a line that is not
+    /// valid UTF-8
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&path)?;
+
+        let mut names = HashSet::new();
+        let mut reader = BufReader::new(&file);
+        let mut line = Vec::new();
+        let mut complete_len = 0;
+        while reader.read_until(b'\n', &mut line)? > 0 {
+            let Some(escaped) = line.strip_suffix(b"\n") else {
+                file.set_len(complete_len)?;
+                break;
+            };
+            complete_len += line.len() as u64;
+            let escaped = std::str::from_utf8(escaped)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            if !escaped.is_empty() {
+                names.insert(unescape(escaped));
+            }
+            line.clear();
+        }
+
+        Ok(Self { path, file, names })
+    }
+
+    /// Returns the path of the backing file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns `true` if the name has already been issued.
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.contains(name)
+    }
+
+    /// Returns the number of issued names.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns `true` if no names have been issued.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Records a name as issued, appending it to the backing file.
+    ///
+    /// Recording a name that is already present is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error raised while writing to the file
+    pub fn record(&mut self, name: &str) -> io::Result<()> {
+        if self.names.contains(name) {
+            return Ok(());
+        }
+
+        writeln!(self.file, "{}", escape(name))?;
+        self.file.flush()?;
+        self.names.insert(name.to_string());
+        Ok(())
+    }
+}
+
+impl NameRegistry for UsedNameStore {
+    fn is_taken(&self, name: &str) -> bool {
+        self.contains(name)
+    }
+}
+
+impl MnemonicGenerator {
+    /// Generates a name that has never been issued through `store`, and records it.
+    ///
+    /// Candidates are re-rolled up to `max_retries` times, as with
+    /// [`MnemonicGenerator::generate_available`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::Io` if the name cannot be persisted, or any error
+    /// returned by [`MnemonicGenerator::generate_available`]
+    pub fn generate_unique_persistent(
+        &self,
+        store: &mut UsedNameStore,
+        max_retries: usize,
+    ) -> Result<String, MnemonicError> {
+        let name = self.generate_available(store, max_retries)?;
+        store.record(&name)?;
+        Ok(name)
+    }
+}
+
+/// Escapes the characters that would break a name across lines.
+fn escape(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Reverses [`escape`], keeping unknown escapes as they are.
+fn unescape(escaped: &str) -> String {
+    let mut name = String::with_capacity(escaped.len());
+    let mut chars = escaped.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            name.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => name.push('\n'),
+            Some('r') => name.push('\r'),
+            Some('\\') => name.push('\\'),
+            Some(other) => {
+                name.push('\\');
+                name.push(other);
+            }
+            None => name.push('\\'),
+        }
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "mnemonic-generator-{}-{}.txt",
+            name,
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn uniqueness_survives_reopening() {
+        let path = temp_path("store");
+        let _ = std::fs::remove_file(&path);
+        let generator = MnemonicGenerator::with_words(["amazing", "legend"], ["jordan"]);
+
+        let first = {
+            let mut store = UsedNameStore::open(&path).unwrap();
+            generator
+                .generate_unique_persistent(&mut store, 100)
+                .unwrap()
+        };
+
+        let mut store = UsedNameStore::open(&path).unwrap();
+        assert!(store.contains(&first));
+        let second = generator
+            .generate_unique_persistent(&mut store, 100)
+            .unwrap();
+        assert_ne!(first, second);
+        assert!(matches!(
+            generator.generate_unique_persistent(&mut store, 10),
+            Err(MnemonicError::NoAvailableName { .. })
+        ));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn escapes_line_breaks_and_drops_torn_lines() {
+        let path = temp_path("escape");
+        let _ = std::fs::remove_file(&path);
+        {
+            let mut store = UsedNameStore::open(&path).unwrap();
+            store.record("two\nlines").unwrap();
+            store.record("back\\slash\r").unwrap();
+        }
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"torn_na").unwrap();
+
+        let mut store = UsedNameStore::open(&path).unwrap();
+        assert_eq!(store.len(), 2);
+        assert!(store.contains("two\nlines") && store.contains("back\\slash\r"));
+        store.record("whole_name").unwrap();
+        let store = UsedNameStore::open(&path).unwrap();
+        assert_eq!(store.len(), 3);
+        assert!(store.contains("whole_name") && !store.contains("torn_na"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,136 @@
+//! Asynchronous streams of names, enabled by the `futures` feature.
+
+use std::collections::HashSet;
+
+use futures_util::stream::{self, Stream};
+
+use crate::{AsyncNameRegistry, Mnemonic, MnemonicError, MnemonicGenerator};
+
+impl MnemonicGenerator {
+    /// Returns an endless stream of mnemonics using the default separator.
+    ///
+    /// Names are generated only when the consumer polls for the next one, so
+    /// a slow consumer throttles generation. If generation fails, the error is
+    /// yielded and the stream ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_util::{FutureExt, StreamExt};
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"]);
+    /// let names: Vec<_> = generator.stream().take(2).collect().now_or_never().unwrap();
+    /// assert_eq!(names[1].as_ref().unwrap().as_str(), "hopeful_turing");
+    /// ```
+    pub fn stream(&self) -> impl Stream<Item = Result<Mnemonic, MnemonicError>> + '_ {
+        stream::unfold(false, move |failed| async move {
+            if failed {
+                return None;
+            }
+            match self.next_mnemonic() {
+                Ok(mnemonic) => {
+                    self.observe(|observer| observer.on_generated(mnemonic.as_str()));
+                    Some((Ok(mnemonic), false))
+                }
+                Err(err) => Some((Err(err), true)),
+            }
+        })
+    }
+
+    /// Returns an endless stream of distinct mnemonics that `registry` does not
+    /// consider taken.
+    ///
+    /// Every name is checked with the registry and re-rolled up to
+    /// `max_retries` times like
+    /// [`MnemonicGenerator::generate_available_async`]. The stream also
+    /// remembers every name it has yielded and never yields one twice, so its
+    /// memory grows with the number of names consumed. If no available name is
+    /// found, or generation fails, the error is yielded and the stream ends.
+    pub fn stream_available<'a, R: AsyncNameRegistry + ?Sized>(
+        &'a self,
+        registry: &'a R,
+        max_retries: usize,
+    ) -> impl Stream<Item = Result<Mnemonic, MnemonicError>> + 'a {
+        stream::unfold(Some(HashSet::new()), move |yielded| async move {
+            let mut yielded: HashSet<String> = yielded?;
+            let attempts = max_retries.saturating_add(1);
+            for _ in 0..attempts {
+                let mnemonic = match self.next_mnemonic() {
+                    Ok(mnemonic) => mnemonic,
+                    Err(err) => return Some((Err(err), None)),
+                };
+                if yielded.contains(mnemonic.as_str()) || registry.is_taken(mnemonic.as_str()).await
+                {
+                    trace_event!(
+                        debug,
+                        name = mnemonic.as_str(),
+                        "name already taken, retrying"
+                    );
+                    self.observe(|observer| observer.on_collision(mnemonic.as_str()));
+                    continue;
+                }
+                self.observe(|observer| observer.on_generated(mnemonic.as_str()));
+                yielded.insert(mnemonic.to_string());
+                return Some((Ok(mnemonic), Some(yielded)));
+            }
+
+            trace_event!(warn, attempts, "no available name found");
+            self.observe(|observer| observer.on_exhausted(attempts));
+            Some((Err(MnemonicError::NoAvailableName { attempts }), None))
+        })
+    }
+
+    /// Generates a mnemonic using the default separator, keeping its words,
+    /// without reporting it to the observer.
+    fn next_mnemonic(&self) -> Result<Mnemonic, MnemonicError> {
+        let separator = self.default_separator();
+        let pick = self.choose(&mut crate::rng::default_rng(), separator)?;
+        Ok(self.mnemonic(pick, separator))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::{FutureExt, StreamExt};
+
+    use super::*;
+
+    struct Taken(&'static str);
+
+    impl AsyncNameRegistry for Taken {
+        async fn is_taken(&self, name: &str) -> bool {
+            name == self.0
+        }
+    }
+
+    #[test]
+    fn stream_ends_after_an_error() {
+        let generator = MnemonicGenerator::with_words(Vec::<String>::new(), ["turing"]);
+        let items: Vec<_> = generator.stream().take(3).collect().now_or_never().unwrap();
+        assert_eq!(items.len(), 1);
+        assert!(matches!(items[0], Err(MnemonicError::EmptyList { .. })));
+    }
+
+    #[test]
+    fn available_stream_yields_each_free_name_once() {
+        let generator = MnemonicGenerator::with_words(["amazing", "legend", "happy"], ["jordan"]);
+        let registry = Taken("amazing_jordan");
+        let items: Vec<_> = generator
+            .stream_available(&registry, 1000)
+            .collect()
+            .now_or_never()
+            .unwrap();
+
+        let mut names: Vec<String> = items[..2]
+            .iter()
+            .map(|item| item.as_ref().unwrap().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["happy_jordan", "legend_jordan"]);
+        assert!(matches!(
+            items[2],
+            Err(MnemonicError::NoAvailableName { attempts: 1001 })
+        ));
+    }
+}
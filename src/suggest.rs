@@ -0,0 +1,122 @@
+use crate::distance::levenshtein;
+use crate::{Mnemonic, MnemonicGenerator, Pick};
+
+impl MnemonicGenerator {
+    /// Returns up to `limit` names this generator can produce that are
+    /// closest to `name` by edit distance, closest first.
+    ///
+    /// Meant for friendly error messages after [`MnemonicGenerator::parse`]
+    /// rejects a hand-typed name ("did you mean `hopeful_turing`?"). The
+    /// prefix, suffix, timestamp, and checksum of `name` are set aside and the
+    /// two words are compared as a whole, so a missing separator or swapped
+    /// letters are tolerated. Names more than a third of their length away
+    /// are not suggested. Every combination of words is compared, so the cost
+    /// grows with the product of the list sizes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::new();
+    /// assert!(generator.parse("hopefull_turnig").is_err());
+    ///
+    /// let suggestions = generator.suggest("hopefull_turnig", 3);
+    /// assert_eq!(suggestions[0].as_str(), "hopeful_turing");
+    /// # }
+    /// ```
+    pub fn suggest(&self, name: &str, limit: usize) -> Vec<Mnemonic> {
+        let separator = self.normalize(self.default_separator());
+        let prefix = self.normalize(&self.prefix);
+        let suffix = self.normalize(&self.suffix);
+
+        let words = name.strip_prefix(prefix.as_str()).unwrap_or(name);
+        let words = words.strip_suffix(suffix.as_str()).unwrap_or(words);
+        let (timestamp, words) = match self.split_timestamp(words, &separator) {
+            Some((_, rest)) => words.split_at(words.len() - rest.len()),
+            None => ("", words),
+        };
+        let words = match self.checksum {
+            Some(_) => words
+                .rsplit_once(separator.as_str())
+                .map_or(words, |(words, _)| words),
+            None => words,
+        };
+
+        let left: Vec<String> = self.words.left.iter().map(|w| self.normalize(w)).collect();
+        let right: Vec<String> = self.words.right.iter().map(|w| self.normalize(w)).collect();
+        let middle: Vec<String> = self
+            .middle
+            .iter()
+            .flat_map(|middle| middle.words().iter())
+            .map(|w| self.normalize(w))
+            .collect();
+        let join = |pick: Pick| {
+            let (first, second) = self.ordered(&left[pick.left], &right[pick.right]);
+            match middle.get(pick.middle) {
+                Some(middle) => format!("{}{}{}{}{}", first, separator, middle, separator, second),
+                None => format!("{}{}{}", first, separator, second),
+            }
+        };
+        let max_distance = (words.chars().count() / 3).max(1);
+
+        let mut candidates = Vec::new();
+        for (rank, pick) in self.picks().enumerate() {
+            let distance = levenshtein(words, &join(pick));
+            if distance <= max_distance {
+                candidates.push((distance, rank, pick));
+            }
+        }
+        candidates.sort_unstable_by_key(|&(distance, rank, _)| (distance, rank));
+
+        let default_separator = self.default_separator();
+        candidates
+            .into_iter()
+            .filter(|&(_, _, pick)| self.is_reachable(pick, default_separator))
+            .take(limit)
+            .map(|(_, _, pick)| {
+                let mut suggestion = format!("{}{}{}", prefix, timestamp, join(pick));
+                if let Some(checksum) = self.checksum_part(pick.left, pick.right) {
+                    suggestion.push_str(&separator);
+                    suggestion.push_str(&self.normalize(checksum));
+                }
+                suggestion.push_str(&suffix);
+                Mnemonic::new(
+                    suggestion,
+                    self.words.left.get(pick.left),
+                    self.words.right.get(pick.right),
+                )
+                .with_middle(self.middle_part(pick.middle))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Checksum, MnemonicGenerator};
+
+    #[test]
+    fn suggests_closest_names_first() {
+        let generator =
+            MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "hopper", "curie"])
+                .with_prefix("prod-");
+        let suggestions: Vec<String> = generator
+            .suggest("prod-hopefulturing", 2)
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(suggestions, ["prod-hopeful_turing", "prod-hopeful_curie"]);
+        assert!(generator.suggest("nothing_alike", 5).is_empty());
+    }
+
+    #[test]
+    fn suggestions_carry_the_right_checksum() {
+        let generator = MnemonicGenerator::with_words(["bold"], ["bohr", "bose"])
+            .with_checksum(Checksum::Character);
+        let suggestion = &generator.suggest("bold_bohrr_x", 1)[0];
+        assert!(generator.verify(suggestion.as_str()).is_ok());
+    }
+}
@@ -0,0 +1,114 @@
+use crate::word_list::WordList;
+use crate::MnemonicGenerator;
+
+/// Characters treated as vowels when estimating syllables and
+/// pronounceability.
+pub(crate) const VOWELS: &str = "aeiouyàáâäåèéêëìíîïòóôöùúûüý";
+
+/// An upper bound on the combined syllable count of a pair of words, with the
+/// estimate for every word precomputed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SyllableLimit {
+    max: usize,
+    left: Vec<usize>,
+    right: Vec<usize>,
+}
+
+impl SyllableLimit {
+    /// Estimates the syllables of every word in both lists.
+    pub(crate) fn new(max: usize, left_words: &WordList, right_words: &WordList) -> Self {
+        Self {
+            max,
+            left: left_words.iter().map(estimate_syllables).collect(),
+            right: right_words.iter().map(estimate_syllables).collect(),
+        }
+    }
+
+    /// Returns the maximum number of syllables.
+    pub(crate) fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Returns `true` if the words at the given indices are short enough together.
+    pub(crate) fn fits(&self, left_idx: usize, right_idx: usize) -> bool {
+        self.left[left_idx] + self.right[right_idx] <= self.max
+    }
+}
+
+/// Estimates the number of spoken syllables in an English-like word.
+///
+/// Counts groups of consecutive vowels, ignoring a silent trailing `e` unless
+/// it follows an `l` as in "noble". Every non-empty word has at least one
+/// syllable.
+pub(crate) fn estimate_syllables(word: &str) -> usize {
+    let chars: Vec<char> = word.chars().flat_map(char::to_lowercase).collect();
+    let is_vowel = |c: &char| VOWELS.contains(*c);
+
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for c in &chars {
+        let vowel = is_vowel(c);
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+
+    if let [.., before, before_e, 'e'] = chars.as_slice() {
+        if !is_vowel(before_e) && (*before_e != 'l' || is_vowel(before)) {
+            count -= 1;
+        }
+    }
+
+    count.max(usize::from(!chars.is_empty()))
+}
+
+impl MnemonicGenerator {
+    /// Limits generated names to at most `max` syllables in total, as estimated
+    /// by a simple heuristic over the spelling of each word.
+    ///
+    /// Useful for call-sign style names that are read aloud. Candidates over the
+    /// limit are re-rolled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["bold", "adoring"], ["bohr", "ramanujan"])
+    ///     .with_max_syllables(3);
+    /// assert_eq!(generator.generate().unwrap(), "bold_bohr");
+    /// ```
+    pub fn with_max_syllables(mut self, max: usize) -> Self {
+        self.syllable_limit = Some(SyllableLimit::new(max, &self.words.left, &self.words.right));
+        self
+    }
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimates_common_words() {
+        assert_eq!(estimate_syllables("bohr"), 1);
+        assert_eq!(estimate_syllables("brave"), 1);
+        assert_eq!(estimate_syllables("noble"), 2);
+        assert_eq!(estimate_syllables("turing"), 2);
+        assert_eq!(estimate_syllables("ramanujan"), 4);
+        assert_eq!(estimate_syllables("Müller"), 2);
+    }
+
+    #[test]
+    fn generator_respects_limit() {
+        let generator = MnemonicGenerator::new().with_max_syllables(4);
+        let limit = SyllableLimit::new(4, &generator.words.left, &generator.words.right);
+        for _ in 0..100 {
+            let pick = generator
+                .choose(&mut crate::rng::default_rng(), "_")
+                .unwrap();
+            assert!(limit.fits(pick.left, pick.right));
+        }
+        assert!(generator.combination_count() < 108 * 237);
+    }
+}
@@ -0,0 +1,235 @@
+//! Helpers for asserting that a generator configuration is unbiased.
+//!
+//! These sample a generator many times and run a chi-squared goodness-of-fit
+//! test over the word frequencies on each side. The expected frequencies take
+//! the configured filters into account, so a generator is considered unbiased
+//! when every name it can produce is equally likely.
+//!
+//! A correct generator fails at the [`SIGNIFICANCE`] level by chance. Tests
+//! that must never flake pass a seeded generator to the `_with_rng` variants.
+//!
+//! # Examples
+//!
+//! ```
+//! use mnemonic_generator::{testing, MnemonicGenerator};
+//! use rand::rngs::StdRng;
+//! use rand::SeedableRng;
+//!
+//! let generator = MnemonicGenerator::with_words(["amazing", "legend"], ["jordan", "bird"]);
+//! let mut rng = StdRng::seed_from_u64(7);
+//! let report = testing::check_uniformity_with_rng(&generator, 10_000, &mut rng).unwrap();
+//! assert!(report.is_uniform(), "{:?}", report);
+//! ```
+
+use rand::RngCore;
+
+use crate::{MnemonicError, MnemonicGenerator};
+
+/// Significance level used by [`check_uniformity`]: a correct generator fails
+/// roughly once in a thousand runs.
+pub const SIGNIFICANCE: f64 = 0.001;
+
+/// Standard normal quantile matching [`SIGNIFICANCE`].
+const Z_SCORE: f64 = 3.090_232;
+
+/// The outcome of a chi-squared goodness-of-fit test over one word list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChiSquared {
+    /// The chi-squared statistic of the observed frequencies.
+    pub statistic: f64,
+    /// The number of degrees of freedom of the test.
+    pub degrees_of_freedom: usize,
+    /// The statistic above which uniformity is rejected at [`SIGNIFICANCE`].
+    pub critical_value: f64,
+}
+
+impl ChiSquared {
+    /// Returns `true` if the observed frequencies are consistent with the expected ones.
+    pub fn is_uniform(&self) -> bool {
+        self.statistic <= self.critical_value
+    }
+}
+
+/// Uniformity results for both sides of a generator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UniformityReport {
+    /// The number of names sampled.
+    pub samples: usize,
+    /// The test over left word frequencies.
+    pub left: ChiSquared,
+    /// The test over right word frequencies.
+    pub right: ChiSquared,
+}
+
+impl UniformityReport {
+    /// Returns `true` if both sides pass the test.
+    pub fn is_uniform(&self) -> bool {
+        self.left.is_uniform() && self.right.is_uniform()
+    }
+}
+
+/// Samples the generator and counts how often each word was chosen.
+///
+/// Returns the counts for the left and right lists, indexed like the lists.
+///
+/// # Errors
+///
+/// Returns any error raised by generation
+pub fn sample_word_frequencies(
+    generator: &MnemonicGenerator,
+    samples: usize,
+) -> Result<(Vec<u64>, Vec<u64>), MnemonicError> {
+    sample_word_frequencies_with_rng(generator, samples, &mut crate::rng::default_rng())
+}
+
+/// Like [`sample_word_frequencies`], drawing from a caller-supplied random
+/// number generator.
+///
+/// # Errors
+///
+/// Returns any error raised by generation
+pub fn sample_word_frequencies_with_rng<G: RngCore + ?Sized>(
+    generator: &MnemonicGenerator,
+    samples: usize,
+    rng: &mut G,
+) -> Result<(Vec<u64>, Vec<u64>), MnemonicError> {
+    let mut left_counts = vec![0; generator.words.left.len()];
+    let mut right_counts = vec![0; generator.words.right.len()];
+    let separator = generator.default_separator();
+
+    for _ in 0..samples {
+        let pick = generator.choose(rng, separator)?;
+        left_counts[pick.left] += 1;
+        right_counts[pick.right] += 1;
+    }
+
+    Ok((left_counts, right_counts))
+}
+
+/// Samples the generator and tests both word lists for uniformity.
+///
+/// # Errors
+///
+/// Returns any error raised by generation
+pub fn check_uniformity(
+    generator: &MnemonicGenerator,
+    samples: usize,
+) -> Result<UniformityReport, MnemonicError> {
+    check_uniformity_with_rng(generator, samples, &mut crate::rng::default_rng())
+}
+
+/// Like [`check_uniformity`], drawing from a caller-supplied random number
+/// generator so that the outcome is reproducible.
+///
+/// # Errors
+///
+/// Returns any error raised by generation
+pub fn check_uniformity_with_rng<G: RngCore + ?Sized>(
+    generator: &MnemonicGenerator,
+    samples: usize,
+    rng: &mut G,
+) -> Result<UniformityReport, MnemonicError> {
+    let (left_weights, right_weights) = reachable_weights(generator);
+    let (left_counts, right_counts) = sample_word_frequencies_with_rng(generator, samples, rng)?;
+
+    Ok(UniformityReport {
+        samples,
+        left: chi_squared(&left_counts, &left_weights, samples),
+        right: chi_squared(&right_counts, &right_weights, samples),
+    })
+}
+
+/// Panics with a diagnostic message if the generator fails the uniformity test.
+///
+/// # Panics
+///
+/// Panics if generation fails or either word list is not uniformly sampled.
+pub fn assert_uniform(generator: &MnemonicGenerator, samples: usize) {
+    let report = check_uniformity(generator, samples).expect("generation failed while sampling");
+    assert!(
+        report.is_uniform(),
+        "generator is not uniform over {} samples: {:?}",
+        samples,
+        report
+    );
+}
+
+/// Counts, for every word, the number of reachable names it takes part in.
+fn reachable_weights(generator: &MnemonicGenerator) -> (Vec<u64>, Vec<u64>) {
+    let mut left_weights = vec![0; generator.words.left.len()];
+    let mut right_weights = vec![0; generator.words.right.len()];
+
+    for pick in generator.reachable_picks(generator.default_separator()) {
+        left_weights[pick.left] += 1;
+        right_weights[pick.right] += 1;
+    }
+
+    (left_weights, right_weights)
+}
+
+/// Runs the test for observed counts against expected proportions.
+fn chi_squared(observed: &[u64], weights: &[u64], samples: usize) -> ChiSquared {
+    let total_weight: u64 = weights.iter().sum();
+    let mut statistic = 0.0;
+    let mut categories = 0;
+
+    for (&count, &weight) in observed.iter().zip(weights) {
+        if weight == 0 {
+            continue;
+        }
+        let expected = samples as f64 * weight as f64 / total_weight as f64;
+        statistic += (count as f64 - expected).powi(2) / expected;
+        categories += 1;
+    }
+
+    let degrees_of_freedom = categories.max(1) - 1;
+    ChiSquared {
+        statistic,
+        degrees_of_freedom,
+        critical_value: critical_value(degrees_of_freedom),
+    }
+}
+
+/// Approximates the upper quantile of the chi-squared distribution using the
+/// Wilson–Hilferty transformation.
+fn critical_value(degrees_of_freedom: usize) -> f64 {
+    if degrees_of_freedom == 0 {
+        return 0.0;
+    }
+    let k = degrees_of_freedom as f64;
+    let term = 2.0 / (9.0 * k);
+    k * (1.0 - term + Z_SCORE * term.sqrt()).powi(3)
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::ProfanityFilter;
+
+    #[test]
+    fn default_generator_is_uniform() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let report =
+            check_uniformity_with_rng(&MnemonicGenerator::new(), 50_000, &mut rng).unwrap();
+        assert!(report.is_uniform(), "{:?}", report);
+    }
+
+    #[test]
+    fn filtered_generator_is_uniform_over_reachable_pairs() {
+        let generator = MnemonicGenerator::with_words(["boring", "happy"], ["wozniak", "turing"])
+            .with_profanity_filter(ProfanityFilter::new());
+        let mut rng = StdRng::seed_from_u64(5);
+        let report = check_uniformity_with_rng(&generator, 20_000, &mut rng).unwrap();
+        assert!(report.is_uniform(), "{:?}", report);
+    }
+
+    #[test]
+    fn detects_skewed_counts() {
+        let result = chi_squared(&[900, 100], &[1, 1], 1_000);
+        assert!(!result.is_uniform());
+        assert_eq!(result.degrees_of_freedom, 1);
+    }
+}
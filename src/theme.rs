@@ -0,0 +1,93 @@
+//! Alternative right-hand word lists.
+//!
+//! Each theme is compiled in only when its cargo feature is enabled.
+
+#[cfg(feature = "animals")]
+pub(crate) mod animals;
+#[cfg(feature = "colors")]
+pub(crate) mod colors;
+#[cfg(feature = "theme-mythology")]
+pub(crate) mod mythology;
+#[cfg(feature = "theme-planets")]
+pub(crate) mod planets;
+
+#[cfg(feature = "adjectives")]
+use crate::word_list::WordLists;
+#[cfg(feature = "adjectives")]
+use crate::{words, MnemonicGenerator};
+
+/// A built-in alternative to the default list of scientist surnames.
+///
+/// Every theme is paired with the default adjectives on the left-hand side.
+/// Variants are only available when the matching `theme-*` feature is enabled,
+/// or, for animals and colors, the lighter `animals` or `colors` feature, which
+/// enables only the adjectives instead of all of `default-words`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Theme {
+    /// Animals, enabled by the `animals` or `theme-animals` feature.
+    #[cfg(feature = "animals")]
+    Animals,
+    /// Colors, enabled by the `colors` or `theme-colors` feature.
+    #[cfg(feature = "colors")]
+    Colors,
+    /// Planets, dwarf planets, and moons, enabled by the `theme-planets` feature.
+    #[cfg(feature = "theme-planets")]
+    Planets,
+    /// Figures from world mythology, enabled by the `theme-mythology` feature.
+    #[cfg(feature = "theme-mythology")]
+    Mythology,
+}
+
+impl Theme {
+    /// Returns the theme's word list.
+    #[cfg(feature = "adjectives")]
+    pub(crate) fn words(self) -> &'static [&'static str] {
+        match self {
+            #[cfg(feature = "animals")]
+            Self::Animals => animals::WORDS,
+            #[cfg(feature = "colors")]
+            Self::Colors => colors::WORDS,
+            #[cfg(feature = "theme-planets")]
+            Self::Planets => planets::WORDS,
+            #[cfg(feature = "theme-mythology")]
+            Self::Mythology => mythology::WORDS,
+        }
+    }
+}
+
+#[cfg(feature = "adjectives")]
+impl MnemonicGenerator {
+    /// Creates a generator pairing the default adjectives with a themed list.
+    ///
+    /// Requires the `adjectives` feature, which `default-words` and every
+    /// `theme-*` feature enable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "animals")]
+    /// # {
+    /// use mnemonic_generator::{MnemonicGenerator, Theme};
+    ///
+    /// let generator = MnemonicGenerator::with_theme(Theme::Animals);
+    /// let mnemonic = generator.generate().expect("Failed to generate mnemonic");
+    /// # }
+    /// ```
+    pub fn with_theme(theme: Theme) -> Self {
+        Self::from_lists(WordLists::from_static(words::LEFT_WORDS, theme.words()))
+    }
+}
+
+#[cfg(all(test, feature = "animals"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn animals_theme_uses_animal_words() {
+        let generator = MnemonicGenerator::with_theme(Theme::Animals);
+        let (left, right) = generator.generate_parts().unwrap();
+        assert!(words::LEFT_WORDS.contains(&left));
+        assert!(animals::WORDS.contains(&right));
+    }
+}
@@ -0,0 +1,91 @@
+//! Animal names, enabled by the `animals` feature.
+
+pub(crate) static WORDS: &[&str] = &[
+    "albatross",
+    "alpaca",
+    "antelope",
+    "armadillo",
+    "axolotl",
+    "badger",
+    "beaver",
+    "bison",
+    "bobcat",
+    "buffalo",
+    "camel",
+    "capybara",
+    "caribou",
+    "cheetah",
+    "chinchilla",
+    "cougar",
+    "coyote",
+    "crane",
+    "dingo",
+    "dolphin",
+    "dormouse",
+    "eagle",
+    "echidna",
+    "elephant",
+    "falcon",
+    "ferret",
+    "flamingo",
+    "fox",
+    "gazelle",
+    "gecko",
+    "gibbon",
+    "giraffe",
+    "gopher",
+    "hedgehog",
+    "heron",
+    "hippo",
+    "ibex",
+    "iguana",
+    "jackal",
+    "jaguar",
+    "kangaroo",
+    "kestrel",
+    "koala",
+    "lemur",
+    "leopard",
+    "llama",
+    "lynx",
+    "manatee",
+    "marmot",
+    "meerkat",
+    "mongoose",
+    "moose",
+    "narwhal",
+    "ocelot",
+    "octopus",
+    "orca",
+    "otter",
+    "owl",
+    "panda",
+    "pangolin",
+    "panther",
+    "pelican",
+    "penguin",
+    "puffin",
+    "quokka",
+    "rabbit",
+    "raccoon",
+    "raven",
+    "reindeer",
+    "salamander",
+    "seal",
+    "sloth",
+    "sparrow",
+    "squirrel",
+    "stork",
+    "swan",
+    "tapir",
+    "tiger",
+    "toucan",
+    "turtle",
+    "walrus",
+    "weasel",
+    "whale",
+    "wolf",
+    "wombat",
+    "yak",
+    "zebra",
+];
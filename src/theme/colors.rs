@@ -0,0 +1,68 @@
+//! Color names, enabled by the `colors` feature.
+
+pub(crate) static WORDS: &[&str] = &[
+    "amber",
+    "amethyst",
+    "apricot",
+    "aqua",
+    "azure",
+    "beige",
+    "black",
+    "blue",
+    "bronze",
+    "burgundy",
+    "carmine",
+    "cerulean",
+    "charcoal",
+    "chartreuse",
+    "cobalt",
+    "copper",
+    "coral",
+    "cream",
+    "crimson",
+    "cyan",
+    "emerald",
+    "fuchsia",
+    "gold",
+    "gray",
+    "green",
+    "indigo",
+    "ivory",
+    "jade",
+    "lavender",
+    "lemon",
+    "lilac",
+    "lime",
+    "magenta",
+    "maroon",
+    "mauve",
+    "mint",
+    "navy",
+    "ochre",
+    "olive",
+    "orange",
+    "orchid",
+    "peach",
+    "periwinkle",
+    "pink",
+    "plum",
+    "purple",
+    "red",
+    "ruby",
+    "rust",
+    "saffron",
+    "salmon",
+    "sapphire",
+    "scarlet",
+    "sepia",
+    "sienna",
+    "silver",
+    "tan",
+    "teal",
+    "turquoise",
+    "umber",
+    "vermilion",
+    "violet",
+    "white",
+    "yellow",
+];
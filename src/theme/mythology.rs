@@ -0,0 +1,64 @@
+//! Figures from world mythology, enabled by the `theme-mythology` feature.
+
+pub(crate) static WORDS: &[&str] = &[
+    "achilles",
+    "amaterasu",
+    "anubis",
+    "aphrodite",
+    "apollo",
+    "ares",
+    "artemis",
+    "athena",
+    "atlas",
+    "baldur",
+    "bastet",
+    "brigid",
+    "cerberus",
+    "cupid",
+    "demeter",
+    "diana",
+    "dionysus",
+    "freya",
+    "frigg",
+    "gaia",
+    "hades",
+    "hanuman",
+    "hector",
+    "hel",
+    "helios",
+    "hera",
+    "hercules",
+    "hermes",
+    "horus",
+    "icarus",
+    "isis",
+    "janus",
+    "juno",
+    "jupiter",
+    "kali",
+    "loki",
+    "maui",
+    "medusa",
+    "minerva",
+    "morrigan",
+    "neptune",
+    "nike",
+    "odin",
+    "orion",
+    "osiris",
+    "pan",
+    "pegasus",
+    "persephone",
+    "perseus",
+    "poseidon",
+    "prometheus",
+    "quetzalcoatl",
+    "ra",
+    "selene",
+    "sif",
+    "thor",
+    "tyr",
+    "ulysses",
+    "vulcan",
+    "zeus",
+];
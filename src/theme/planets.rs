@@ -0,0 +1,45 @@
+//! Planets, dwarf planets, and moons, enabled by the `theme-planets` feature.
+
+pub(crate) static WORDS: &[&str] = &[
+    "ariel",
+    "callisto",
+    "ceres",
+    "charon",
+    "deimos",
+    "dione",
+    "earth",
+    "enceladus",
+    "eris",
+    "europa",
+    "ganymede",
+    "haumea",
+    "hyperion",
+    "iapetus",
+    "io",
+    "jupiter",
+    "makemake",
+    "mars",
+    "mercury",
+    "mimas",
+    "miranda",
+    "moon",
+    "neptune",
+    "nereid",
+    "oberon",
+    "pallas",
+    "phobos",
+    "phoebe",
+    "pluto",
+    "proteus",
+    "rhea",
+    "saturn",
+    "sedna",
+    "tethys",
+    "titan",
+    "titania",
+    "triton",
+    "umbriel",
+    "uranus",
+    "venus",
+    "vesta",
+];
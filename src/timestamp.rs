@@ -0,0 +1,257 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::mnemonic::offset_of;
+use crate::word_list::WordList;
+use crate::{radix, MnemonicError, MnemonicGenerator, WordSide};
+
+/// Leading words that encode the creation time of a name.
+///
+/// Timestamps are written in base `n` using the right-hand words sorted
+/// alphabetically, so names sort by time when compared as strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TimestampWords {
+    precision_secs: u64,
+    width: usize,
+    /// Index into the right-hand list for every digit, in alphabetical order.
+    digits: Vec<usize>,
+}
+
+impl TimestampWords {
+    /// Prepares an encoding with the given precision over the right-hand words.
+    ///
+    /// Enough words are used to represent every 32-bit Unix timestamp, that is
+    /// every instant until the year 2106.
+    ///
+    /// Returns `None` if the list has fewer than two words, too few to write
+    /// digits with.
+    pub(crate) fn new(precision: Duration, right_words: &WordList) -> Option<Self> {
+        if right_words.len() < 2 {
+            return None;
+        }
+        let precision_secs = precision.as_secs().max(1);
+        let mut digits: Vec<usize> = (0..right_words.len()).collect();
+        digits.sort_by_key(|&idx| right_words.get(idx));
+
+        let base = digits.len() as u128;
+        let max = u128::from(u32::MAX) / u128::from(precision_secs);
+        let bits = u128::BITS - max.leading_zeros();
+        Some(Self {
+            precision_secs,
+            width: radix::width(base, bits),
+            digits,
+        })
+    }
+
+    /// Returns the precision of the encoded times.
+    pub(crate) fn precision(&self) -> Duration {
+        Duration::from_secs(self.precision_secs)
+    }
+
+    /// Returns the right-hand word indices encoding `time`.
+    fn encode(&self, time: SystemTime) -> Vec<usize> {
+        let secs = time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let value = u128::from(secs / self.precision_secs);
+        radix::to_digits(value, self.digits.len() as u128, self.width)
+            .into_iter()
+            .map(|digit| self.digits[digit])
+            .collect()
+    }
+
+    /// Returns the time encoded by the given right-hand word indices.
+    fn decode(&self, indices: impl IntoIterator<Item = usize>) -> Option<SystemTime> {
+        let digits = indices
+            .into_iter()
+            .map(|idx| self.digits.iter().position(|&digit| digit == idx));
+        let value = radix::from_digits(
+            digits.collect::<Option<Vec<_>>>()?,
+            self.digits.len() as u128,
+        )?;
+        let secs = u64::try_from(value)
+            .ok()?
+            .checked_mul(self.precision_secs)?;
+        UNIX_EPOCH.checked_add(Duration::from_secs(secs))
+    }
+}
+
+impl MnemonicGenerator {
+    /// Starts every generated name with words encoding the current time,
+    /// rounded down to `precision`.
+    ///
+    /// Timestamp words come from the right-hand list in alphabetical order,
+    /// so names generated later sort after earlier ones as long as no word is
+    /// a prefix of another. The time can be recovered with
+    /// [`MnemonicGenerator::decode_timestamp`]. Precision is counted in whole
+    /// seconds; coarser precision needs fewer words. Timestamp words are not
+    /// counted by the length window.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidConfig` if the right-hand list has fewer
+    /// than two words, too few to encode a time
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "default-words")]
+    /// # {
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::new()
+    ///     .with_timestamp(Duration::from_secs(3600))
+    ///     .unwrap();
+    /// let name = generator.generate().unwrap();
+    ///
+    /// let created = generator.decode_timestamp(&name).unwrap();
+    /// assert!(SystemTime::now().duration_since(created).unwrap() < Duration::from_secs(3601));
+    /// # }
+    /// ```
+    pub fn with_timestamp(mut self, precision: Duration) -> Result<Self, MnemonicError> {
+        let timestamp = TimestampWords::new(precision, &self.words.right).ok_or_else(|| {
+            MnemonicError::InvalidConfig(
+                "timestamps need at least two right-hand words".to_string(),
+            )
+        })?;
+        self.timestamp = Some(timestamp);
+        Ok(self)
+    }
+
+    /// Recovers the creation time encoded in a name generated with
+    /// [`MnemonicGenerator::with_timestamp`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidConfig` if the generator has no
+    /// timestamp mode, or `MnemonicError::InvalidMnemonic` if the name does
+    /// not parse
+    pub fn decode_timestamp(&self, name: &str) -> Result<SystemTime, MnemonicError> {
+        let invalid = |part: &str, reason: &str| MnemonicError::InvalidMnemonic {
+            name: name.to_string(),
+            position: offset_of(name, part),
+            reason: reason.to_string(),
+        };
+        let timestamp = self.timestamp.as_ref().ok_or_else(|| {
+            MnemonicError::InvalidConfig("timestamps are not enabled".to_string())
+        })?;
+
+        self.parse(name)?;
+        let words = name
+            .strip_prefix(self.normalize(&self.prefix).as_str())
+            .ok_or_else(|| invalid(name, "missing prefix"))?;
+        let (indices, _) = self
+            .split_timestamp(words, &self.normalize(self.default_separator()))
+            .ok_or_else(|| invalid(words, "missing timestamp"))?;
+        timestamp
+            .decode(indices)
+            .ok_or_else(|| invalid(words, "timestamp out of range"))
+    }
+
+    /// Returns the number of words timestamps add to a name, or zero if
+    /// timestamps are disabled.
+    pub(crate) fn timestamp_width(&self) -> usize {
+        self.timestamp
+            .as_ref()
+            .map_or(0, |timestamp| timestamp.width)
+    }
+
+    /// Returns the words encoding the current time, followed by the separator,
+    /// if timestamps are enabled.
+    pub(crate) fn timestamp_part(&self, separator: &str) -> Option<String> {
+        let timestamp = self.timestamp.as_ref()?;
+        let mut part = String::new();
+        for idx in timestamp.encode(SystemTime::now()) {
+            part.push_str(self.words.right.get(idx));
+            part.push_str(separator);
+        }
+        Some(part)
+    }
+
+    /// Splits the leading timestamp words off `words`, returning their
+    /// right-hand indices and the rest of the name.
+    ///
+    /// Returns the input unchanged if timestamps are disabled, and `None` if
+    /// the timestamp words are missing or unknown.
+    pub(crate) fn split_timestamp<'a>(
+        &self,
+        words: &'a str,
+        separator: &str,
+    ) -> Option<(Vec<usize>, &'a str)> {
+        let Some(timestamp) = &self.timestamp else {
+            return Some((Vec::new(), words));
+        };
+
+        let mut parts = words.splitn(timestamp.width + 1, separator);
+        let indices = parts
+            .by_ref()
+            .take(timestamp.width)
+            .map(|part| self.find_word(WordSide::Right, part).map(|(idx, _)| idx))
+            .collect::<Option<Vec<_>>>()?;
+        Some((indices, parts.next()?))
+    }
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_sort_by_time() {
+        let timestamp = TimestampWords::new(
+            Duration::from_secs(60),
+            &WordList::Static(crate::words::RIGHT_WORDS),
+        )
+        .unwrap();
+        assert_eq!(timestamp.width, 4);
+
+        let render = |secs| {
+            let words: Vec<&str> = timestamp
+                .encode(UNIX_EPOCH + Duration::from_secs(secs))
+                .into_iter()
+                .map(|idx| crate::words::RIGHT_WORDS[idx])
+                .collect();
+            words.join("_")
+        };
+        let times = [
+            0,
+            59,
+            60,
+            3_600,
+            1_700_000_000,
+            1_700_000_060,
+            4_000_000_000,
+        ];
+        for pair in times.windows(2) {
+            assert!(
+                render(pair[0]) <= render(pair[1]),
+                "{} > {}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+
+    #[test]
+    fn parses_timestamped_names() {
+        let generator = MnemonicGenerator::with_words(["hopeful"], ["bohr", "curie", "turing"])
+            .with_timestamp(Duration::from_secs(1))
+            .unwrap()
+            .with_prefix("prod-");
+        let before = SystemTime::now() - Duration::from_secs(1);
+        let name = generator.generate().unwrap();
+
+        assert!(name.starts_with("prod-"));
+        assert!(generator.parse(&name).is_ok());
+        assert!(generator.decode_timestamp(&name).unwrap() >= before);
+        assert!(generator.parse("prod-hopeful_turing").is_err());
+
+        assert!(matches!(
+            MnemonicGenerator::with_words(["hopeful"], ["turing"])
+                .with_timestamp(Duration::from_secs(1)),
+            Err(MnemonicError::InvalidConfig(_))
+        ));
+    }
+}
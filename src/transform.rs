@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::MnemonicGenerator;
+
+/// A post-processing step applied to every generated name.
+///
+/// Any `Fn(String) -> String` closure that is `Send + Sync` implements this
+/// trait, so most transforms can be written inline. Implement it on a type
+/// when the transform carries configuration of its own.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::Transform;
+///
+/// struct Truncate(usize);
+///
+/// impl Transform for Truncate {
+///     fn apply(&self, name: String) -> String {
+///         name.chars().take(self.0).collect()
+///     }
+/// }
+///
+/// assert_eq!(Truncate(7).apply("hopeful_turing".to_string()), "hopeful");
+/// ```
+pub trait Transform: Send + Sync {
+    /// Transforms a generated name.
+    fn apply(&self, name: String) -> String;
+}
+
+impl<F> Transform for F
+where
+    F: Fn(String) -> String + Send + Sync,
+{
+    fn apply(&self, name: String) -> String {
+        self(name)
+    }
+}
+
+impl MnemonicGenerator {
+    /// Registers a transform that runs on every generated name.
+    ///
+    /// Transforms run in registration order, after the words are joined and
+    /// normalized to the profile's case. Filters and the naming profile are
+    /// checked against the transformed name, so a transform cannot produce a
+    /// name that the generator's own filters would reject.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"])
+    ///     .with_transform(|name: String| name.to_uppercase())
+    ///     .with_transform(|name: String| format!("team-{}", name));
+    /// assert_eq!(generator.generate().unwrap(), "team-HOPEFUL_TURING");
+    /// ```
+    pub fn with_transform<T: Transform + 'static>(mut self, transform: T) -> Self {
+        self.transforms.push(Arc::new(transform));
+        self
+    }
+
+    /// Runs every registered transform over the name, in order.
+    pub(crate) fn apply_transforms(&self, name: String) -> String {
+        self.transforms
+            .iter()
+            .fold(name, |name, transform| transform.apply(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{MnemonicGenerator, NamingProfile};
+
+    #[test]
+    fn profile_validates_transformed_names() {
+        let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing"])
+            .with_profile(NamingProfile::github_repo().with_length(1, 12))
+            .unwrap()
+            .with_transform(|name: String| name.replace("hopeful", "hopefully"));
+        for _ in 0..20 {
+            assert_eq!(generator.generate().unwrap(), "brave-turing");
+        }
+    }
+}
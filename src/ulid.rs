@@ -0,0 +1,236 @@
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{Mnemonic, MnemonicError, MnemonicGenerator, SuffixAlphabet};
+
+/// Number of characters of the time prefix.
+const PREFIX_LEN: usize = 6;
+
+/// Number of low bits of the millisecond timestamp the prefix drops. A ULID
+/// encodes 48 bits in ten characters, the first of which holds only three, so
+/// its first six characters carry the top 28 bits.
+const DROPPED_BITS: u32 = 20;
+
+/// A name made of a sortable time prefix and a mnemonic, such as
+/// `01HVBC_hopeful_turing`.
+///
+/// The prefix is the first six characters of a [ULID] created at the same
+/// time: the creation time in Crockford's base32, uppercase, with a precision
+/// of 2<sup>20</sup> milliseconds, about 17 minutes. Names created later sort
+/// after earlier ones when compared as strings, so they double as roughly
+/// ordered identifiers.
+///
+/// [ULID]: https://github.com/ulid/spec
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// use mnemonic_generator::MnemonicGenerator;
+///
+/// let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"]);
+/// let created = UNIX_EPOCH + Duration::from_millis(1_713_000_000_000);
+/// let name = generator.generate_ulid_at(created).unwrap();
+/// assert_eq!(name.as_str(), "01HVBC_hopeful_turing");
+///
+/// let parsed = generator.parse_ulid("01HVBC_hopeful_turing").unwrap();
+/// assert_eq!(parsed.mnemonic().right(), "turing");
+/// assert!(created.duration_since(parsed.timestamp()).unwrap() < Duration::from_secs(1049));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UlidMnemonic {
+    name: String,
+    timestamp: SystemTime,
+    mnemonic: Mnemonic,
+}
+
+impl UlidMnemonic {
+    /// Returns the full name.
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the creation time encoded in the prefix, rounded down to its
+    /// precision.
+    pub fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    /// Returns the mnemonic following the prefix.
+    pub fn mnemonic(&self) -> &Mnemonic {
+        &self.mnemonic
+    }
+}
+
+impl fmt::Display for UlidMnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+impl AsRef<str> for UlidMnemonic {
+    fn as_ref(&self) -> &str {
+        &self.name
+    }
+}
+
+impl From<UlidMnemonic> for String {
+    fn from(name: UlidMnemonic) -> Self {
+        name.name
+    }
+}
+
+impl MnemonicGenerator {
+    /// Generates a mnemonic prefixed with the current time, joined by the
+    /// default separator.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate`]
+    pub fn generate_ulid(&self) -> Result<UlidMnemonic, MnemonicError> {
+        self.generate_ulid_at(SystemTime::now())
+    }
+
+    /// Generates a mnemonic prefixed with `time`, joined by the default
+    /// separator.
+    ///
+    /// The prefix goes before the generator's own prefix and is normalized to
+    /// the profile's case. See [`UlidMnemonic`] for the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate`]
+    pub fn generate_ulid_at(&self, time: SystemTime) -> Result<UlidMnemonic, MnemonicError> {
+        let separator = self.default_separator();
+        let pick = self.choose(&mut crate::rng::default_rng(), separator)?;
+        let mnemonic = self.mnemonic(pick, separator);
+        self.observe(|observer| observer.on_generated(mnemonic.as_str()));
+
+        let ticks = time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis()
+            >> DROPPED_BITS;
+        let name = self.normalize(&format!("{}{}", encode_prefix(ticks as u32), separator))
+            + mnemonic.as_str();
+        Ok(UlidMnemonic {
+            name,
+            timestamp: decode_ticks(ticks as u32),
+            mnemonic,
+        })
+    }
+
+    /// Parses a name generated by [`MnemonicGenerator::generate_ulid`] back
+    /// into its creation time and mnemonic.
+    ///
+    /// The time prefix is matched ignoring case.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidMnemonic` if the time prefix or the
+    /// separator after it is missing or invalid, or the same errors as
+    /// [`MnemonicGenerator::parse`] for the rest of the name, with positions
+    /// counted from the start of the full name
+    pub fn parse_ulid(&self, name: &str) -> Result<UlidMnemonic, MnemonicError> {
+        let invalid = |position: usize, reason: &str| MnemonicError::InvalidMnemonic {
+            name: name.to_string(),
+            position,
+            reason: reason.to_string(),
+        };
+
+        let (prefix, rest) = name
+            .split_at_checked(PREFIX_LEN)
+            .ok_or_else(|| invalid(0, "missing time prefix"))?;
+        let ticks = decode_prefix(prefix).ok_or_else(|| invalid(0, "invalid time prefix"))?;
+        let separator = self.normalize(self.default_separator());
+        let words = rest
+            .strip_prefix(separator.as_str())
+            .ok_or_else(|| invalid(PREFIX_LEN, "missing separator after the time prefix"))?;
+
+        let offset = name.len() - words.len();
+        let mnemonic = self.parse(words).map_err(|err| match err {
+            MnemonicError::InvalidMnemonic {
+                position, reason, ..
+            } => invalid(offset + position, &reason),
+            err => err,
+        })?;
+        Ok(UlidMnemonic {
+            name: name.to_string(),
+            timestamp: decode_ticks(ticks),
+            mnemonic,
+        })
+    }
+}
+
+/// Encodes the top bits of a millisecond timestamp as an uppercase prefix.
+fn encode_prefix(ticks: u32) -> String {
+    let alphabet = SuffixAlphabet::Crockford;
+    (0..PREFIX_LEN)
+        .rev()
+        .map(|digit| {
+            let idx = (ticks >> (5 * digit)) as usize & 31;
+            alphabet.char_at(idx).to_ascii_uppercase()
+        })
+        .collect()
+}
+
+/// Decodes a prefix written by [`encode_prefix`], ignoring case.
+fn decode_prefix(prefix: &str) -> Option<u32> {
+    let alphabet = SuffixAlphabet::Crockford.chars();
+    prefix.chars().try_fold(0u32, |ticks, c| {
+        let digit = alphabet.find(c.to_ascii_lowercase())?;
+        ticks.checked_mul(32)?.checked_add(digit as u32)
+    })
+}
+
+/// Returns the earliest time with the given top bits.
+fn decode_ticks(ticks: u32) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(u64::from(ticks) << DROPPED_BITS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_sort_by_time() {
+        let generator = MnemonicGenerator::with_words(["zany", "brave"], ["turing", "bohr"]);
+        let earlier = generator
+            .generate_ulid_at(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+            .unwrap();
+        let later = generator
+            .generate_ulid_at(UNIX_EPOCH + Duration::from_secs(1_700_003_600))
+            .unwrap();
+        assert!(earlier.as_str() < later.as_str());
+        assert_eq!(generator.parse_ulid(later.as_str()).unwrap(), later);
+    }
+
+    #[test]
+    fn reports_invalid_prefixes_and_words() {
+        let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"]);
+        assert_eq!(
+            generator
+                .parse_ulid("01hvbc_hopeful_turing")
+                .unwrap()
+                .mnemonic()
+                .as_str(),
+            "hopeful_turing"
+        );
+        for (name, position) in [
+            ("01HV", 0),
+            ("01HVBU_hopeful_turing", 0),
+            ("01HVBC-hopeful_turing", 6),
+            ("01HVBC_hopeful_nobody", 15),
+        ] {
+            assert!(
+                matches!(
+                    generator.parse_ulid(name),
+                    Err(MnemonicError::InvalidMnemonic { position: at, .. }) if at == position
+                ),
+                "{}",
+                name
+            );
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::ops::{Add, BitOr};
+
+use crate::word_list::{WordList, WordLists};
+use crate::MnemonicGenerator;
+
+impl MnemonicGenerator {
+    /// Combines the word lists of several generators into a new generator.
+    ///
+    /// The left-hand lists are concatenated in order, and so are the right-hand
+    /// lists, dropping words that repeat an earlier one ignoring case. Only
+    /// the words are combined: the result has default options, so configure
+    /// filters, separators, and the like on it afterwards. An empty slice
+    /// gives a generator with empty lists.
+    ///
+    /// The same union is available as `a | b` and `a + b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let scientists = MnemonicGenerator::with_words(["hopeful"], ["turing", "hopper"]);
+    /// let animals = MnemonicGenerator::with_words(["Hopeful", "brave"], ["otter"]);
+    /// let combined = MnemonicGenerator::union(&[&scientists, &animals]);
+    /// assert_eq!(combined.left_words().collect::<Vec<_>>(), ["hopeful", "brave"]);
+    /// assert_eq!(combined.len_right(), 3);
+    /// assert_eq!(&scientists | &animals, combined);
+    /// ```
+    pub fn union(generators: &[&MnemonicGenerator]) -> Self {
+        Self::from_lists(WordLists::new(
+            WordList::merged(generators.iter().map(|generator| &generator.words.left)),
+            WordList::merged(generators.iter().map(|generator| &generator.words.right)),
+        ))
+    }
+}
+
+impl BitOr for &MnemonicGenerator {
+    type Output = MnemonicGenerator;
+
+    /// Returns [`MnemonicGenerator::union`] of both generators.
+    fn bitor(self, rhs: Self) -> MnemonicGenerator {
+        MnemonicGenerator::union(&[self, rhs])
+    }
+}
+
+impl BitOr for MnemonicGenerator {
+    type Output = MnemonicGenerator;
+
+    /// Returns [`MnemonicGenerator::union`] of both generators.
+    fn bitor(self, rhs: Self) -> MnemonicGenerator {
+        MnemonicGenerator::union(&[&self, &rhs])
+    }
+}
+
+impl Add for &MnemonicGenerator {
+    type Output = MnemonicGenerator;
+
+    /// Returns [`MnemonicGenerator::union`] of both generators.
+    fn add(self, rhs: Self) -> MnemonicGenerator {
+        MnemonicGenerator::union(&[self, rhs])
+    }
+}
+
+impl Add for MnemonicGenerator {
+    type Output = MnemonicGenerator;
+
+    /// Returns [`MnemonicGenerator::union`] of both generators.
+    fn add(self, rhs: Self) -> MnemonicGenerator {
+        MnemonicGenerator::union(&[&self, &rhs])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_drops_duplicates_and_options() {
+        let first = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing"])
+            .with_prefix("eu-")
+            .with_left_max_len(5);
+        let second = MnemonicGenerator::with_words(["BRAVE", "eager"], ["turing", "otter"]);
+        let combined = first + second;
+        assert_eq!(
+            combined.left_words().collect::<Vec<_>>(),
+            ["hopeful", "brave", "eager"]
+        );
+        assert_eq!(
+            combined.right_words().collect::<Vec<_>>(),
+            ["turing", "otter"]
+        );
+        assert_eq!(combined.combination_count(), 6);
+        assert!(!combined.generate().unwrap().starts_with("eu-"));
+        assert!(combined.contains_left("eager"));
+    }
+}
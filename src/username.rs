@@ -0,0 +1,218 @@
+use crate::{sample, MnemonicError, MnemonicGenerator, SuffixAlphabet, MAX_FILTER_ATTEMPTS};
+
+/// The shape of the names produced by [`MnemonicGenerator::username_with`].
+///
+/// The default produces lowercase names such as `brave_noether42`: the words
+/// joined by `_`, followed by two digits, at most 20 characters long.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{MnemonicGenerator, UsernameOptions};
+///
+/// let options = UsernameOptions::new()
+///     .with_digits(4)
+///     .with_max_len(16)
+///     .with_allowed_symbols("");
+/// let generator = MnemonicGenerator::with_words(["brave"], ["noether"]);
+/// let username = generator.username_with(&options).unwrap();
+/// assert!(username.starts_with("bravenoether"));
+/// assert_eq!(username.len(), 16);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsernameOptions {
+    digits: usize,
+    alphabet: SuffixAlphabet,
+    max_len: usize,
+    lowercase: bool,
+    allowed_symbols: String,
+}
+
+impl UsernameOptions {
+    /// Creates the default options.
+    pub fn new() -> Self {
+        Self {
+            digits: 2,
+            alphabet: SuffixAlphabet::Digits,
+            max_len: 20,
+            lowercase: true,
+            allowed_symbols: "_".to_string(),
+        }
+    }
+
+    /// Sets the number of random digits appended to the words.
+    pub fn with_digits(mut self, digits: usize) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Sets the characters the digits are drawn from, for example
+    /// [`SuffixAlphabet::UnambiguousDigits`] for names that are read aloud.
+    pub fn with_alphabet(mut self, alphabet: SuffixAlphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+
+    /// Sets the maximum length, counted in characters and including the digits.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Lowercases the words. Names are otherwise left in the case of the lists.
+    pub fn with_lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    /// Sets the non-alphanumeric characters allowed in a name.
+    ///
+    /// The words are joined by `_` if it is allowed, otherwise by the first
+    /// allowed symbol, or directly if none are.
+    pub fn with_allowed_symbols(mut self, symbols: &str) -> Self {
+        self.allowed_symbols = symbols.to_string();
+        self
+    }
+
+    /// Returns the separator placed between the words.
+    fn separator(&self) -> &str {
+        if self.allowed_symbols.contains('_') {
+            return "_";
+        }
+        match self.allowed_symbols.chars().next() {
+            Some(c) => &self.allowed_symbols[..c.len_utf8()],
+            None => "",
+        }
+    }
+
+    /// Returns `true` if the character may appear in a name.
+    fn allows_char(&self, c: char) -> bool {
+        c.is_ascii_alphanumeric() || self.allowed_symbols.contains(c)
+    }
+}
+
+impl Default for UsernameOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MnemonicGenerator {
+    /// Generates a username suggestion such as `brave_noether42` using the
+    /// default [`UsernameOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::username_with`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["Brave"], ["Noether"]);
+    /// let username = generator.username().unwrap();
+    /// assert!(username.starts_with("brave_noether"));
+    /// assert!(username[13..].bytes().all(|b| b.is_ascii_digit()));
+    /// ```
+    pub fn username(&self) -> Result<String, MnemonicError> {
+        self.username_with(&UsernameOptions::new())
+    }
+
+    /// Generates a username suggestion shaped by `options`.
+    ///
+    /// Pairs whose words contain characters outside the allowed set, or that
+    /// exceed the maximum length once the digits are appended, are re-rolled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MnemonicError::InvalidConfig` if the digits alone do not fit
+    /// the maximum length, `MnemonicError::AllCandidatesRejected` if no fitting
+    /// pair was found, or the same errors as [`MnemonicGenerator::generate`]
+    pub fn username_with(&self, options: &UsernameOptions) -> Result<String, MnemonicError> {
+        if options.digits >= options.max_len {
+            return Err(MnemonicError::InvalidConfig(format!(
+                "{} digits leave no room for words within {} characters",
+                options.digits, options.max_len
+            )));
+        }
+
+        let separator = options.separator();
+        let mut rng = crate::rng::default_rng();
+        for _ in 0..MAX_FILTER_ATTEMPTS {
+            let pick = self.choose(&mut rng, separator)?;
+            let mut username = self.render(pick, separator);
+            if options.lowercase {
+                username = username.to_lowercase();
+            }
+            if username.chars().count() + options.digits > options.max_len
+                || !username.chars().all(|c| options.allows_char(c))
+            {
+                trace_event!(
+                    trace,
+                    left_idx = pick.left,
+                    right_idx = pick.right,
+                    "unfit for a username, retrying"
+                );
+                self.observe(|observer| observer.on_retry());
+                continue;
+            }
+
+            for _ in 0..options.digits {
+                let idx = sample::index(&mut rng, options.alphabet.len());
+                username.push_str(options.alphabet.char_at(idx));
+            }
+            self.observe(|observer| observer.on_generated(&username));
+            return Ok(username);
+        }
+
+        self.observe(|observer| observer.on_exhausted(MAX_FILTER_ATTEMPTS));
+        Err(MnemonicError::AllCandidatesRejected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rerolls_names_that_do_not_fit() {
+        let generator = MnemonicGenerator::with_words(["brave", "courageous", "o'brien"], ["bohr"]);
+        let options = UsernameOptions::new().with_max_len(15).with_digits(3);
+        for _ in 0..50 {
+            let username = generator.username_with(&options).unwrap();
+            assert!(username.starts_with("brave_bohr"), "{}", username);
+            assert_eq!(username.len(), 13);
+        }
+    }
+
+    #[test]
+    fn draws_digits_from_the_alphabet() {
+        let generator = MnemonicGenerator::with_words(["brave"], ["bohr"]);
+        let options = UsernameOptions::new()
+            .with_digits(8)
+            .with_alphabet(SuffixAlphabet::UnambiguousCrockford);
+        for _ in 0..20 {
+            let username = generator.username_with(&options).unwrap();
+            assert!(username["brave_bohr".len()..]
+                .chars()
+                .all(|c| SuffixAlphabet::UnambiguousCrockford.chars().contains(c)));
+        }
+    }
+
+    #[test]
+    fn digits_must_leave_room_for_words() {
+        let generator = MnemonicGenerator::with_words(["brave"], ["noether"]);
+        let options = UsernameOptions::new().with_max_len(4).with_digits(4);
+        assert!(matches!(
+            generator.username_with(&options),
+            Err(MnemonicError::InvalidConfig(_))
+        ));
+
+        let options = UsernameOptions::new().with_max_len(8);
+        assert!(matches!(
+            generator.username_with(&options),
+            Err(MnemonicError::AllCandidatesRejected)
+        ));
+    }
+}
@@ -0,0 +1,99 @@
+use std::sync::{Arc, OnceLock};
+
+use crate::word_list::WordLists;
+use crate::{words, MnemonicGenerator};
+
+/// A frozen revision of the built-in word lists.
+///
+/// Words are never added to, removed from, or reordered within a published
+/// version, so a generator pinned to one produces the same namespace in every
+/// release: names, ranks, checksums, and encoded values stay decodable by
+/// deployments running older versions of the crate. New words only ever
+/// appear in a new version.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{MnemonicGenerator, WordlistVersion};
+///
+/// let generator = MnemonicGenerator::new_versioned(WordlistVersion::V1);
+/// assert_eq!(generator.unrank(0).unwrap().as_str(), "admiring_agnesi");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WordlistVersion {
+    /// The 108 adjectives and 237 surnames of the original release.
+    V1,
+}
+
+impl WordlistVersion {
+    /// The version used by [`MnemonicGenerator::new`], which may move forward
+    /// in later releases.
+    pub const LATEST: Self = Self::V1;
+
+    /// Returns a handle to the lists of this version.
+    ///
+    /// The lists are initialized once and shared by every generator using them.
+    pub(crate) fn lists(self) -> Arc<WordLists> {
+        match self {
+            Self::V1 => {
+                static V1: OnceLock<Arc<WordLists>> = OnceLock::new();
+                Arc::clone(
+                    V1.get_or_init(|| {
+                        WordLists::from_static(words::LEFT_WORDS, words::RIGHT_WORDS)
+                    }),
+                )
+            }
+        }
+    }
+}
+
+impl Default for WordlistVersion {
+    fn default() -> Self {
+        Self::LATEST
+    }
+}
+
+impl MnemonicGenerator {
+    /// Creates a generator using a fixed version of the built-in word lists.
+    ///
+    /// Unlike [`MnemonicGenerator::new`], which follows the latest lists, the
+    /// namespace of a pinned generator never changes between releases.
+    pub fn new_versioned(version: WordlistVersion) -> Self {
+        Self::from_lists(version.lists())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::Hasher;
+
+    use super::*;
+    use crate::named::Fnv1aHasher;
+
+    /// Hashes every word of a version with FNV-1a so that any edit is caught.
+    fn fingerprint(version: WordlistVersion) -> u64 {
+        let lists = version.lists();
+        let mut hasher = Fnv1aHasher::default();
+        for word in lists.left.iter().chain(lists.right.iter()) {
+            hasher.write(word.as_bytes());
+            hasher.write(&[0]);
+        }
+        hasher.finish()
+    }
+
+    #[test]
+    fn v1_is_frozen() {
+        let lists = WordlistVersion::V1.lists();
+        assert_eq!((lists.left.len(), lists.right.len()), (108, 237));
+        assert_eq!(fingerprint(WordlistVersion::V1), 0x4f10_9935_497c_9430);
+    }
+
+    #[test]
+    fn new_follows_latest() {
+        assert!(Arc::ptr_eq(
+            &MnemonicGenerator::new().words,
+            &MnemonicGenerator::new_versioned(WordlistVersion::LATEST).words
+        ));
+    }
+}
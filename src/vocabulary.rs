@@ -0,0 +1,85 @@
+use crate::MnemonicGenerator;
+
+impl MnemonicGenerator {
+    /// Iterates over the left-hand words in list order.
+    ///
+    /// Meant for tooling that displays, audits, or exports the active
+    /// vocabulary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["amazing", "legend"], ["jordan"]);
+    /// let words: Vec<&str> = generator.left_words().collect();
+    /// assert_eq!(words, ["amazing", "legend"]);
+    /// assert_eq!(generator.len_right(), 1);
+    /// ```
+    pub fn left_words(&self) -> impl ExactSizeIterator<Item = &str> + DoubleEndedIterator + '_ {
+        self.words.left.iter()
+    }
+
+    /// Iterates over the right-hand words in list order.
+    ///
+    /// See [`MnemonicGenerator::left_words`].
+    pub fn right_words(&self) -> impl ExactSizeIterator<Item = &str> + DoubleEndedIterator + '_ {
+        self.words.right.iter()
+    }
+
+    /// Returns `true` if `word` is one of the left-hand words.
+    ///
+    /// Matching is exact and case-sensitive. Lookups use an index built when
+    /// the generator was constructed, so they take logarithmic time rather
+    /// than scanning the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["amazing", "legend"], ["jordan"]);
+    /// assert!(generator.contains_left("legend"));
+    /// assert!(!generator.contains_left("jordan"));
+    /// assert!(generator.contains_right("jordan"));
+    /// ```
+    pub fn contains_left(&self, word: &str) -> bool {
+        self.words.contains_left(word)
+    }
+
+    /// Returns `true` if `word` is one of the right-hand words.
+    ///
+    /// See [`MnemonicGenerator::contains_left`].
+    pub fn contains_right(&self, word: &str) -> bool {
+        self.words.contains_right(word)
+    }
+
+    /// Returns the number of left-hand words.
+    pub fn len_left(&self) -> usize {
+        self.words.left.len()
+    }
+
+    /// Returns the number of right-hand words.
+    pub fn len_right(&self) -> usize {
+        self.words.right.len()
+    }
+}
+
+#[cfg(all(test, feature = "default-words"))]
+mod tests {
+    use crate::MnemonicGenerator;
+
+    #[test]
+    fn exposes_the_default_vocabulary() {
+        let generator = MnemonicGenerator::new();
+        assert_eq!(generator.left_words().len(), generator.len_left());
+        assert_eq!(generator.right_words().len(), generator.len_right());
+        assert!(generator.right_words().any(|word| word == "turing"));
+        assert!(generator.contains_right("turing") && generator.contains_left("hopeful"));
+        assert!(!generator.contains_right("Turing"));
+        assert_eq!(
+            generator.combination_count(),
+            generator.len_left() as u128 * generator.len_right() as u128
+        );
+    }
+}
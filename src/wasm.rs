@@ -0,0 +1,61 @@
+//! WebAssembly bindings, enabled by the `wasm` feature.
+//!
+//! Randomness is drawn from the browser's `crypto.getRandomValues` through
+//! `getrandom`'s `wasm_js` backend, so generation works on
+//! `wasm32-unknown-unknown`. Every name is seeded directly from `getrandom`,
+//! so a runtime without randomness raises a JavaScript error instead of
+//! aborting.
+
+use wasm_bindgen::prelude::*;
+
+use crate::entropy::os_rng;
+use crate::MnemonicGenerator;
+
+/// JavaScript-facing wrapper around [`MnemonicGenerator`].
+///
+/// Exported to JavaScript as `MnemonicGenerator`.
+#[wasm_bindgen(js_name = MnemonicGenerator)]
+pub struct WasmMnemonicGenerator {
+    inner: MnemonicGenerator,
+}
+
+#[wasm_bindgen(js_class = MnemonicGenerator)]
+impl WasmMnemonicGenerator {
+    /// Creates a generator with the default word lists.
+    #[cfg(any(feature = "default-words", feature = "mini-words"))]
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: MnemonicGenerator::new(),
+        }
+    }
+
+    /// Creates a generator with custom word lists.
+    #[wasm_bindgen(js_name = withWords)]
+    pub fn with_words(left_words: Vec<String>, right_words: Vec<String>) -> Self {
+        Self {
+            inner: MnemonicGenerator::with_words(left_words, right_words),
+        }
+    }
+
+    /// Generates a mnemonic using the default separator.
+    pub fn generate(&self) -> Result<String, JsError> {
+        self.inner.generate_with_os_entropy().map_err(JsError::from)
+    }
+
+    /// Generates a mnemonic using a custom separator.
+    #[wasm_bindgen(js_name = generateWithSeparator)]
+    pub fn generate_with_separator(&self, separator: &str) -> Result<String, JsError> {
+        let mut rng = os_rng().map_err(JsError::from)?;
+        self.inner
+            .generate_from(&mut rng, separator)
+            .map_err(JsError::from)
+    }
+}
+
+#[cfg(any(feature = "default-words", feature = "mini-words"))]
+impl Default for WasmMnemonicGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,231 @@
+use std::ops::RangeInclusive;
+
+use crate::word_list::WordList;
+use crate::{MnemonicGenerator, ProfanityFilter, WordSide};
+
+/// A test deciding which words of a list may appear in generated names.
+///
+/// Filters are stacked on a generator with
+/// [`MnemonicGenerator::with_word_filter`] and its per-side variants. Each
+/// filter is run once per word when it is attached, and the generator keeps
+/// only the indices of the words that passed, so filters add no cost to
+/// generation.
+///
+/// Any `Fn(&str) -> bool` closure that is `Send + Sync` implements this
+/// trait. The crate provides [`WordLength`], `WordPattern` with the `regex`
+/// feature, and implements it for [`ProfanityFilter`], which allows the words
+/// it does not consider offensive.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::WordFilter;
+///
+/// struct NoDoubleLetters;
+///
+/// impl WordFilter for NoDoubleLetters {
+///     fn allows(&self, word: &str) -> bool {
+///         !word.as_bytes().windows(2).any(|pair| pair[0] == pair[1])
+///     }
+/// }
+///
+/// assert!(NoDoubleLetters.allows("turing"));
+/// assert!(!NoDoubleLetters.allows("hopper"));
+/// ```
+pub trait WordFilter: Send + Sync {
+    /// Returns `true` if `word` may appear in generated names.
+    fn allows(&self, word: &str) -> bool;
+}
+
+impl<F> WordFilter for F
+where
+    F: Fn(&str) -> bool + Send + Sync,
+{
+    fn allows(&self, word: &str) -> bool {
+        self(word)
+    }
+}
+
+impl WordFilter for ProfanityFilter {
+    fn allows(&self, word: &str) -> bool {
+        !self.is_offensive(word)
+    }
+}
+
+/// A [`WordFilter`] allowing words whose length in characters lies within a
+/// range.
+///
+/// # Examples
+///
+/// ```
+/// use mnemonic_generator::{WordFilter, WordLength};
+///
+/// let filter = WordLength::new(4..=6);
+/// assert!(filter.allows("bohr"));
+/// assert!(!filter.allows("lovelace"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordLength {
+    range: RangeInclusive<usize>,
+}
+
+impl WordLength {
+    /// Creates a filter allowing words of `range` characters.
+    pub fn new(range: RangeInclusive<usize>) -> Self {
+        Self { range }
+    }
+}
+
+impl WordFilter for WordLength {
+    fn allows(&self, word: &str) -> bool {
+        self.range.contains(&word.chars().count())
+    }
+}
+
+/// The indices of the words of each list that passed the attached word
+/// filters, or `None` for a side without filters.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct AllowedWords {
+    /// Indices of the allowed left words, in ascending order.
+    left: Option<Vec<usize>>,
+    /// Indices of the allowed right words, in ascending order.
+    right: Option<Vec<usize>>,
+}
+
+impl AllowedWords {
+    /// Returns the allowed indices of the left and right list, if filtered.
+    pub(crate) fn indices(&self) -> (Option<&[usize]>, Option<&[usize]>) {
+        (self.left.as_deref(), self.right.as_deref())
+    }
+
+    /// Replaces the allowed indices of one side, which must be below the
+    /// length of its list.
+    pub(crate) fn set(&mut self, side: WordSide, mut indices: Vec<usize>) {
+        indices.sort_unstable();
+        indices.dedup();
+        match side {
+            WordSide::Left => self.left = Some(indices),
+            WordSide::Right => self.right = Some(indices),
+        }
+    }
+
+    /// Keeps only the allowed words of one side that also pass `filter`.
+    fn narrow(&mut self, side: WordSide, words: &WordList, filter: &dyn WordFilter) {
+        let allowed = match side {
+            WordSide::Left => &mut self.left,
+            WordSide::Right => &mut self.right,
+        };
+        let indices = match allowed.take() {
+            Some(indices) => indices
+                .into_iter()
+                .filter(|&idx| filter.allows(words.get(idx)))
+                .collect(),
+            None => (0..words.len())
+                .filter(|&idx| filter.allows(words.get(idx)))
+                .collect(),
+        };
+        *allowed = Some(indices);
+    }
+
+    /// Returns `true` if both words passed their side's filters.
+    pub(crate) fn fits(&self, left_idx: usize, right_idx: usize) -> bool {
+        let allows = |allowed: &Option<Vec<usize>>, idx| {
+            allowed
+                .as_ref()
+                .is_none_or(|indices| indices.binary_search(&idx).is_ok())
+        };
+        allows(&self.left, left_idx) && allows(&self.right, right_idx)
+    }
+}
+
+impl MnemonicGenerator {
+    /// Only uses the words of both lists that `filter` allows.
+    ///
+    /// Filters stack in the order they are attached: each one is run once,
+    /// here, over the words that passed the previous ones, and candidates are
+    /// then drawn directly from the words left. If a list has no word left,
+    /// generation fails with `MnemonicError::AllCandidatesRejected`.
+    ///
+    /// Word filters inspect single words. Use
+    /// [`MnemonicGenerator::with_profanity_filter`] or
+    /// [`MnemonicGenerator::with_length_range`] to check the rendered name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::{MnemonicGenerator, ProfanityFilter, WordLength};
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful", "bold", "wild"], ["turing", "bohr"])
+    ///     .with_word_filter(WordLength::new(1..=4))
+    ///     .with_word_filter(ProfanityFilter::empty().with_term("wild"))
+    ///     .with_word_filter(|word: &str| word.starts_with('b'));
+    /// assert_eq!(generator.generate().unwrap(), "bold_bohr");
+    /// ```
+    pub fn with_word_filter<F: WordFilter>(self, filter: F) -> Self {
+        self.narrow_words(WordSide::Left, &filter)
+            .narrow_words(WordSide::Right, &filter)
+    }
+
+    /// Only uses the left-hand words that `filter` allows.
+    ///
+    /// See [`MnemonicGenerator::with_word_filter`].
+    pub fn with_left_word_filter<F: WordFilter>(self, filter: F) -> Self {
+        self.narrow_words(WordSide::Left, &filter)
+    }
+
+    /// Only uses the right-hand words that `filter` allows.
+    ///
+    /// See [`MnemonicGenerator::with_word_filter`].
+    pub fn with_right_word_filter<F: WordFilter>(self, filter: F) -> Self {
+        self.narrow_words(WordSide::Right, &filter)
+    }
+
+    /// Keeps only the allowed words of one side that also pass `filter`.
+    fn narrow_words(mut self, side: WordSide, filter: &dyn WordFilter) -> Self {
+        let words = match side {
+            WordSide::Left => &self.words.left,
+            WordSide::Right => &self.words.right,
+        };
+        self.allowed_words
+            .get_or_insert_default()
+            .narrow(side, words, filter);
+        self.restrict_length_window()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::MnemonicError;
+
+    #[test]
+    fn filters_run_once_in_order() {
+        let calls = AtomicUsize::new(0);
+        let generator = MnemonicGenerator::with_words(["a", "bb", "ccc"], ["x", "yy", "zzz"])
+            .with_word_filter(WordLength::new(2..=3))
+            .with_left_word_filter(|word: &str| {
+                calls.fetch_add(1, Ordering::Relaxed);
+                word != "ccc"
+            });
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+
+        for _ in 0..50 {
+            let name = generator.generate().unwrap();
+            assert!(name == "bb_yy" || name == "bb_zzz", "{}", name);
+        }
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+        assert_eq!(generator.combination_count(), 2);
+    }
+
+    #[test]
+    fn rejecting_every_word_fails_generation() {
+        let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"])
+            .with_right_word_filter(ProfanityFilter::empty().with_term("turing"));
+        assert!(matches!(
+            generator.generate(),
+            Err(MnemonicError::AllCandidatesRejected)
+        ));
+    }
+}
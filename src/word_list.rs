@@ -0,0 +1,294 @@
+use std::collections::HashSet;
+use std::sync::{Arc, OnceLock};
+
+#[cfg(feature = "default-words")]
+use crate::WordlistVersion;
+use crate::{MnemonicError, WordSide};
+
+/// Storage for one side of a generator's vocabulary.
+///
+/// The built-in lists are borrowed from static data so that constructing a
+/// default generator does not allocate, while custom lists are owned.
+#[derive(Debug, Clone)]
+pub(crate) enum WordList {
+    Static(&'static [&'static str]),
+    Owned(Vec<String>),
+}
+
+impl WordList {
+    /// Returns the number of words in the list.
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Static(words) => words.len(),
+            Self::Owned(words) => words.len(),
+        }
+    }
+
+    /// Returns `true` if the list contains no words.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the word at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub(crate) fn get(&self, idx: usize) -> &str {
+        match self {
+            Self::Static(words) => words[idx],
+            Self::Owned(words) => &words[idx],
+        }
+    }
+
+    /// Iterates over the words in order.
+    pub(crate) fn iter(&self) -> impl ExactSizeIterator<Item = &str> + DoubleEndedIterator + '_ {
+        (0..self.len()).map(move |idx| self.get(idx))
+    }
+
+    /// Builds an owned list, rejecting empty words and words containing
+    /// whitespace and dropping case-insensitive duplicates.
+    ///
+    /// The first spelling of a duplicated word is kept, and the order of the
+    /// remaining words is preserved.
+    pub(crate) fn validated<I>(words: I) -> Result<Self, MnemonicError>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut seen = HashSet::new();
+        let mut unique = Vec::new();
+        for word in words {
+            let word = word.into();
+            if word.is_empty() {
+                return Err(MnemonicError::EmptyWord);
+            }
+            if word.chars().any(char::is_whitespace) {
+                return Err(MnemonicError::WhitespaceInWord(word));
+            }
+            if seen.insert(word.to_lowercase()) {
+                unique.push(word);
+            }
+        }
+        Ok(Self::Owned(unique))
+    }
+
+    /// Concatenates lists in order, dropping case-insensitive duplicates.
+    ///
+    /// Like [`WordList::validated`], the first spelling of a duplicated word is
+    /// kept, but words are not otherwise checked.
+    pub(crate) fn merged<'a>(lists: impl IntoIterator<Item = &'a WordList>) -> Self {
+        let mut seen = HashSet::new();
+        let words = lists
+            .into_iter()
+            .flat_map(WordList::iter)
+            .filter(|word| seen.insert(word.to_lowercase()))
+            .map(str::to_string)
+            .collect();
+        Self::Owned(words)
+    }
+}
+
+impl PartialEq for WordList {
+    /// Lists are equal if they hold the same words, however they are stored.
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for WordList {}
+
+/// The positions of a list's words in sorted order, for lookups by binary
+/// search. Equal words keep their order, so lookups find the first one.
+#[derive(Debug)]
+struct SortedIndex(Box<[usize]>);
+
+impl SortedIndex {
+    /// Sorts the positions of the words of `list`.
+    fn new(list: &WordList) -> Self {
+        let mut positions: Vec<usize> = (0..list.len()).collect();
+        positions.sort_by_key(|&idx| list.get(idx));
+        Self(positions.into_boxed_slice())
+    }
+
+    /// Returns the first position of `word` in `list`, which the index was
+    /// built from.
+    fn position(&self, list: &WordList, word: &str) -> Option<usize> {
+        let start = self.0.partition_point(|&idx| list.get(idx) < word);
+        self.0
+            .get(start)
+            .copied()
+            .filter(|&idx| list.get(idx) == word)
+    }
+}
+
+/// The lowercased words of a list in sorted order, with their positions, for
+/// looking up names rendered by a lowercasing profile.
+#[derive(Debug)]
+struct FoldedIndex(Box<[(Box<str>, usize)]>);
+
+impl FoldedIndex {
+    /// Lowercases and sorts the words of `list`.
+    fn new(list: &WordList) -> Self {
+        let mut entries: Vec<(Box<str>, usize)> = list
+            .iter()
+            .map(|word| word.to_lowercase().into_boxed_str())
+            .zip(0..)
+            .collect();
+        entries.sort_unstable();
+        Self(entries.into_boxed_slice())
+    }
+
+    /// Returns the first position of a word that lowercases to `word`.
+    fn position(&self, word: &str) -> Option<usize> {
+        let start = self.0.partition_point(|(folded, _)| &**folded < word);
+        self.0
+            .get(start)
+            .filter(|(folded, _)| &**folded == word)
+            .map(|&(_, idx)| idx)
+    }
+}
+
+/// The pair of word lists a generator draws from.
+#[derive(Debug)]
+pub(crate) struct WordLists {
+    pub(crate) left: WordList,
+    pub(crate) right: WordList,
+    left_index: SortedIndex,
+    right_index: SortedIndex,
+    /// Lowercased indices of the left and right lists, built on first use.
+    folded: OnceLock<[FoldedIndex; 2]>,
+}
+
+impl PartialEq for WordLists {
+    /// Lists are equal if they hold the same words; the indices follow.
+    fn eq(&self, other: &Self) -> bool {
+        self.left == other.left && self.right == other.right
+    }
+}
+
+impl Eq for WordLists {}
+
+impl WordLists {
+    /// Pairs two lists, indexing them for lookups.
+    pub(crate) fn new(left: WordList, right: WordList) -> Arc<Self> {
+        Arc::new(Self {
+            left_index: SortedIndex::new(&left),
+            right_index: SortedIndex::new(&right),
+            left,
+            right,
+            folded: OnceLock::new(),
+        })
+    }
+
+    /// Returns the list of one side.
+    pub(crate) fn side(&self, side: WordSide) -> &WordList {
+        match side {
+            WordSide::Left => &self.left,
+            WordSide::Right => &self.right,
+        }
+    }
+
+    /// Returns the first position of `word` in the list of `side`, matched
+    /// exactly.
+    pub(crate) fn position(&self, side: WordSide, word: &str) -> Option<usize> {
+        match side {
+            WordSide::Left => self.left_index.position(&self.left, word),
+            WordSide::Right => self.right_index.position(&self.right, word),
+        }
+    }
+
+    /// Returns the first position of a word of the list of `side` that
+    /// lowercases to `word`.
+    pub(crate) fn folded_position(&self, side: WordSide, word: &str) -> Option<usize> {
+        let [left, right] = self
+            .folded
+            .get_or_init(|| [FoldedIndex::new(&self.left), FoldedIndex::new(&self.right)]);
+        match side {
+            WordSide::Left => left.position(word),
+            WordSide::Right => right.position(word),
+        }
+    }
+
+    /// Returns `true` if the left-hand list contains `word` exactly.
+    pub(crate) fn contains_left(&self, word: &str) -> bool {
+        self.position(WordSide::Left, word).is_some()
+    }
+
+    /// Returns `true` if the right-hand list contains `word` exactly.
+    pub(crate) fn contains_right(&self, word: &str) -> bool {
+        self.position(WordSide::Right, word).is_some()
+    }
+
+    /// Returns a handle to the latest version of the built-in lists.
+    #[cfg(feature = "default-words")]
+    pub(crate) fn defaults() -> Arc<Self> {
+        WordlistVersion::LATEST.lists()
+    }
+
+    /// Returns a handle to the minimal built-in lists.
+    #[cfg(all(feature = "mini-words", not(feature = "default-words")))]
+    pub(crate) fn defaults() -> Arc<Self> {
+        static MINI: OnceLock<Arc<WordLists>> = OnceLock::new();
+        Arc::clone(MINI.get_or_init(|| {
+            Self::from_static(
+                crate::mini_words::LEFT_WORDS,
+                crate::mini_words::RIGHT_WORDS,
+            )
+        }))
+    }
+
+    /// Wraps static word lists without copying them.
+    pub(crate) fn from_static(
+        left: &'static [&'static str],
+        right: &'static [&'static str],
+    ) -> Arc<Self> {
+        Self::new(WordList::Static(left), WordList::Static(right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validated_dedupes_case_insensitively() {
+        let list = WordList::validated(["Turing", "hopper", "turing", "TURING"]).unwrap();
+        assert_eq!(list.iter().collect::<Vec<_>>(), ["Turing", "hopper"]);
+    }
+
+    #[test]
+    fn index_finds_exact_words() {
+        let lists = WordLists::new(
+            WordList::validated(["zany", "Hopeful", "brave"]).unwrap(),
+            WordList::Static(&["turing"]),
+        );
+        assert!(lists.contains_left("Hopeful") && lists.contains_left("zany"));
+        assert!(!lists.contains_left("hopeful") && !lists.contains_left("turing"));
+        assert!(lists.contains_right("turing"));
+    }
+
+    #[test]
+    fn folded_index_finds_the_first_spelling() {
+        let lists = WordLists::new(
+            WordList::Static(&["Zany", "brave", "BRAVE", "Hopeful"]),
+            WordList::Static(&["turing"]),
+        );
+        assert_eq!(lists.folded_position(WordSide::Left, "brave"), Some(1));
+        assert_eq!(lists.folded_position(WordSide::Left, "zany"), Some(0));
+        assert_eq!(lists.position(WordSide::Left, "BRAVE"), Some(2));
+        assert_eq!(lists.folded_position(WordSide::Left, "Hopeful"), None);
+    }
+
+    #[test]
+    fn validated_rejects_empty_and_whitespace() {
+        assert!(matches!(
+            WordList::validated(["turing", ""]),
+            Err(MnemonicError::EmptyWord)
+        ));
+        assert!(matches!(
+            WordList::validated(["grace hopper"]),
+            Err(MnemonicError::WhitespaceInWord(word)) if word == "grace hopper"
+        ));
+    }
+}
@@ -0,0 +1,7 @@
+//! Built-in word lists, kept in the `mnemonic-generator-words` crate so that
+//! the procedural macros can share them.
+
+#[cfg(feature = "adjectives")]
+pub(crate) use mnemonic_generator_words::LEFT_WORDS;
+#[cfg(feature = "scientists")]
+pub(crate) use mnemonic_generator_words::RIGHT_WORDS;
@@ -0,0 +1,158 @@
+use std::fmt;
+
+use crate::{MnemonicError, MnemonicGenerator, Pick};
+
+/// A generated mnemonic that is formatted on demand.
+///
+/// Returned by [`MnemonicGenerator::generate_display`]. The words are chosen
+/// up front; formatting writes them with the default separator straight into
+/// the formatter. Every formatting produces the same name, except that
+/// timestamp words reflect the time of formatting.
+#[derive(Clone, Copy)]
+pub struct MnemonicDisplay<'a> {
+    generator: &'a MnemonicGenerator,
+    pick: Pick,
+}
+
+impl fmt::Debug for MnemonicDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MnemonicDisplay")
+            .field(&self.to_string())
+            .finish()
+    }
+}
+
+impl fmt::Display for MnemonicDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = self.generator.default_separator();
+        self.generator.write_name(f, self.pick, separator)
+    }
+}
+
+impl MnemonicGenerator {
+    /// Generates a mnemonic using the default separator and appends it to `out`.
+    ///
+    /// Nothing is allocated unless a naming profile, transforms, timestamps, or
+    /// an observer are configured, or a filter has to inspect candidates, which
+    /// makes this suitable for hot paths such as loggers that reuse one buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate`], or
+    /// `MnemonicError::Fmt` if writing to `out` fails
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"]);
+    /// let mut line = String::from("worker ");
+    /// generator.generate_into(&mut line).unwrap();
+    /// assert_eq!(line, "worker hopeful_turing");
+    /// ```
+    pub fn generate_into(&self, out: &mut impl fmt::Write) -> Result<(), MnemonicError> {
+        let separator = self.default_separator();
+        let pick = self.choose(&mut crate::rng::default_rng(), separator)?;
+        self.write_name(out, pick, separator)?;
+        self.observe(|observer| observer.on_generated(&self.render(pick, separator)));
+        Ok(())
+    }
+
+    /// Chooses a mnemonic and returns it as a value implementing `Display`.
+    ///
+    /// Avoids the intermediate `String` of [`MnemonicGenerator::generate`] when
+    /// the name is only ever written to a formatter.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MnemonicGenerator::generate`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mnemonic_generator::MnemonicGenerator;
+    ///
+    /// let generator = MnemonicGenerator::with_words(["hopeful"], ["turing"]);
+    /// let name = generator.generate_display().unwrap();
+    /// assert_eq!(format!("worker {}", name), "worker hopeful_turing");
+    /// ```
+    pub fn generate_display(&self) -> Result<MnemonicDisplay<'_>, MnemonicError> {
+        let separator = self.default_separator();
+        let pick = self.choose(&mut crate::rng::default_rng(), separator)?;
+        self.observe(|observer| observer.on_generated(&self.render(pick, separator)));
+        Ok(MnemonicDisplay {
+            generator: self,
+            pick,
+        })
+    }
+
+    /// Writes the same name as `render` without building an intermediate
+    /// `String` when the name needs no case normalization or transforms.
+    pub(crate) fn write_name<W: fmt::Write + ?Sized>(
+        &self,
+        out: &mut W,
+        pick: Pick,
+        separator: &str,
+    ) -> fmt::Result {
+        if self.profile.is_some() || !self.transforms.is_empty() {
+            return out.write_str(&self.render(pick, separator));
+        }
+
+        out.write_str(&self.prefix)?;
+        if let Some(timestamp) = self.timestamp_part(separator) {
+            out.write_str(&timestamp)?;
+        }
+        let (first, second) = self.ordered(
+            self.words.left.get(pick.left),
+            self.words.right.get(pick.right),
+        );
+        out.write_str(first)?;
+        if let Some(middle) = self.middle_part(pick.middle) {
+            out.write_str(separator)?;
+            out.write_str(middle)?;
+        }
+        out.write_str(separator)?;
+        out.write_str(second)?;
+        if let Some(checksum) = self.checksum_part(pick.left, pick.right) {
+            out.write_str(separator)?;
+            out.write_str(checksum)?;
+        }
+        out.write_str(&self.suffix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Checksum, NamingProfile};
+
+    #[test]
+    fn writes_the_same_names_as_render() {
+        let generator = || {
+            MnemonicGenerator::with_words(["Hopeful"], ["Turing"])
+                .with_prefix("prod-")
+                .with_checksum(Checksum::Character)
+        };
+        let profiled = generator()
+            .with_profile(NamingProfile::rfc1123_hostname())
+            .unwrap();
+        for generator in [generator(), profiled] {
+            let mut out = String::new();
+            generator
+                .write_name(&mut out, Pick::pair(0, 0), "-")
+                .unwrap();
+            assert_eq!(out, generator.render(Pick::pair(0, 0), "-"));
+        }
+    }
+
+    #[test]
+    fn display_is_stable() {
+        let generator = MnemonicGenerator::with_words(["hopeful", "brave"], ["turing", "bohr"])
+            .with_profile(NamingProfile::rfc1123_hostname())
+            .unwrap();
+        let name = generator.generate_display().unwrap();
+        assert_eq!(name.to_string(), name.to_string());
+        assert!(generator.is_valid_name(&name.to_string()));
+    }
+}